@@ -0,0 +1,73 @@
+/*
+ * Copyright 2021 Travis Veazey
+ *
+ * Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+ * https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+ * https://opensource.org/licenses/MIT>, at your option. This file may not be
+ * copied, modified, or distributed except according to those terms.
+ */
+
+/*
+ * C bindings for fast_poisson, available with the `ffi` feature.
+ *
+ * Define FAST_POISSON_SINGLE_PRECISION when the library is built with the
+ * `single_precision` feature.
+ */
+
+#ifndef FAST_POISSON_H
+#define FAST_POISSON_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#ifdef FAST_POISSON_SINGLE_PRECISION
+typedef float fast_poisson_float;
+#else
+typedef double fast_poisson_float;
+#endif
+
+typedef struct fast_poisson_sampler fast_poisson_sampler;
+
+typedef struct fast_poisson_point2 {
+    fast_poisson_float x;
+    fast_poisson_float y;
+} fast_poisson_point2;
+
+typedef struct fast_poisson_point3 {
+    fast_poisson_float x;
+    fast_poisson_float y;
+    fast_poisson_float z;
+} fast_poisson_point3;
+
+/* Create a sampler in 1 to 8 dimensions; returns NULL otherwise. */
+fast_poisson_sampler *fast_poisson_new(size_t dimensions);
+
+/* Free a sampler; NULL is ignored. */
+void fast_poisson_free(fast_poisson_sampler *sampler);
+
+/* Setters return false, leaving the sampler unchanged, for invalid values. */
+bool fast_poisson_set_dimensions(fast_poisson_sampler *sampler, size_t dimensions);
+bool fast_poisson_set_radius(fast_poisson_sampler *sampler, fast_poisson_float radius);
+void fast_poisson_set_seed(fast_poisson_sampler *sampler, uint64_t seed);
+void fast_poisson_set_samples(fast_poisson_sampler *sampler, uint32_t samples);
+
+/* Generate the distribution, returning its number of points. */
+size_t fast_poisson_generate(fast_poisson_sampler *sampler);
+
+/*
+ * Copy up to `capacity` points into `buffer`, which must have room for
+ * `capacity * dimensions` floats, returning the number of points copied.
+ */
+size_t fast_poisson_fill(fast_poisson_sampler *sampler, fast_poisson_float *buffer,
+                         size_t capacity);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FAST_POISSON_H */
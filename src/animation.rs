@@ -0,0 +1,293 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Distributions that evolve smoothly over time
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{seed, Float, Point, Poisson, Rand};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use std::iter::FusedIterator;
+
+#[cfg(test)]
+mod tests;
+
+/// How much of its velocity a wandering point keeps from one frame to the next
+const DAMPING: Float = 0.9;
+
+/// Fraction of the radius points move each frame, by default
+const DEFAULT_SPEED: Float = 0.05;
+
+/// A point that moves from frame to frame
+#[derive(Debug, Clone, Copy)]
+struct Moving<const N: usize> {
+    /// Stable identifier of the point
+    id: u64,
+    /// Where the point is now
+    position: Point<N>,
+    /// How far the point moved in the last frame
+    velocity: Point<N>,
+}
+
+/// An endless sequence of frames of a distribution that changes smoothly over time
+///
+/// Each frame, every point moves a small step, either wandering randomly or following a
+/// [flow](Self::with_flow). A step that would bring a point within the radius of another is not
+/// taken, so the minimum spacing is maintained in every frame. Points that leave the space
+/// disappear, and new points appear wherever gaps have opened up. Every point carries a stable
+/// identifier from frame to frame, so that it can be tracked, interpolated, or faded in and out.
+///
+/// The first frame is the distribution itself, with the same identifiers as
+/// [`iter_with_ids`](Poisson::iter_with_ids).
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// let mut animation = Poisson2D::new().with_seed(0xBADBEEF).animate();
+///
+/// let first = animation.next().unwrap();
+/// let second = animation.next().unwrap();
+///
+/// // Most points persist from one frame to the next
+/// let persisted = second.iter().filter(|(id, _)| first.iter().any(|(i, _)| i == id));
+/// assert!(persisted.count() > first.len() * 9 / 10);
+/// ```
+///
+/// See [`Poisson::animate`].
+pub struct Animation<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution being animated
+    distribution: Poisson<N, U, R>,
+    /// The RNG
+    rng: R,
+    /// Key from which identifiers and the seed of each frame are derived
+    key: u64,
+    /// Number of points generated so far, from which the next identifier is derived
+    generated: u64,
+    /// Number of frames returned so far
+    frame: u64,
+    /// Distance each point moves at most per frame
+    speed: Float,
+    /// Velocity of the flow at each point, if points follow one rather than wander
+    flow: Option<fn(Point<N>) -> Point<N>>,
+    /// The points of the current frame
+    points: Vec<Moving<N>>,
+    /// Index of the current points, by identifier
+    index: DefaultIndex<N>,
+}
+
+impl<const N: usize, U, R> Animation<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Animate the specified distribution
+    pub(crate) fn new(distribution: Poisson<N, U, R>) -> Self {
+        let key = distribution.seed.unwrap_or_else(rand::random);
+        let radius = distribution.radius;
+
+        Self {
+            rng: R::seed_from_u64(seed::derive(key, u64::MAX)),
+            key,
+            generated: 0,
+            frame: 0,
+            speed: DEFAULT_SPEED * radius,
+            flow: None,
+            points: Vec::new(),
+            index: SpatialIndex::with_spacing(radius),
+            distribution,
+        }
+    }
+
+    /// Specify the furthest distance each point moves per frame
+    ///
+    /// The default is 5% of the radius. Smaller steps give smoother motion, and fewer steps that
+    /// can't be taken because they would crowd another point.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let animation = Poisson2D::new().animate().with_speed(0.001);
+    /// ```
+    #[must_use]
+    pub fn with_speed(mut self, speed: Float) -> Self {
+        self.set_speed(speed);
+        self
+    }
+
+    /// Specify the furthest distance each point moves per frame
+    ///
+    /// See [`with_speed`][Self::with_speed] for more details.
+    pub fn set_speed(&mut self, speed: Float) {
+        self.speed = speed;
+    }
+
+    /// Specify a flow that points follow, rather than wandering randomly
+    ///
+    /// `flow` gives the velocity at each point, as the step to take each frame, which is shortened
+    /// to the [speed](Self::with_speed) if it is any longer. Points flowing out of the space
+    /// disappear, and new points appear in their wake.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // Drift to the right
+    /// let animation = Poisson2D::new().animate().with_flow(|_| [0.01, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn with_flow(mut self, flow: fn(Point<N>) -> Point<N>) -> Self {
+        self.set_flow(flow);
+        self
+    }
+
+    /// Specify a flow that points follow, rather than wandering randomly
+    ///
+    /// See [`with_flow`][Self::with_flow] for more details.
+    pub fn set_flow(&mut self, flow: fn(Point<N>) -> Point<N>) {
+        self.flow = Some(flow);
+    }
+
+    /// Add points to the current frame, each with a fresh identifier
+    fn add(&mut self, points: &[Point<N>]) {
+        for &position in points {
+            let id = seed::derive(self.key, self.generated);
+            self.generated += 1;
+
+            self.index.insert(position, id);
+            self.points.push(Moving {
+                id,
+                position,
+                velocity: [0.0; N],
+            });
+        }
+    }
+
+    /// The velocity of a point this frame, no faster than our speed
+    fn velocity(&mut self, point: Moving<N>) -> Point<N> {
+        let velocity = match self.flow {
+            Some(flow) => flow(point.position),
+            None => {
+                // Wander smoothly, by slowly turning towards a random direction
+                let mut velocity = point.velocity;
+                for v in velocity.iter_mut() {
+                    *v =
+                        *v * DAMPING + self.rng.gen_range(-1.0..1.0) * self.speed * (1.0 - DAMPING);
+                }
+                velocity
+            }
+        };
+
+        let length = velocity.iter().map(|v| v * v).sum::<Float>().sqrt();
+        if length > self.speed {
+            velocity.map(|v| v * self.speed / length)
+        } else {
+            velocity
+        }
+    }
+
+    /// Move every point a step, then fill any gaps that opened up
+    fn step(&mut self) {
+        let radius = self.distribution.radius;
+
+        let mut order: Vec<usize> = (0..self.points.len()).collect();
+        order.shuffle(&mut self.rng);
+        let mut pending: Vec<(usize, Point<N>)> = order
+            .into_iter()
+            .map(|i| (i, self.velocity(self.points[i])))
+            .collect();
+
+        // A point may be blocked only by another that has yet to move out of its way, so blocked
+        // points try again for as long as any point still moves
+        let mut gone = vec![false; self.points.len()];
+        loop {
+            let mut blocked = Vec::new();
+            for &(i, velocity) in pending.iter() {
+                let point = self.points[i];
+                let mut next = point.position;
+                for (n, v) in next.iter_mut().zip(velocity) {
+                    *n += v;
+                }
+
+                if !self.distribution.in_space(next) {
+                    self.index.delete(point.position, point.id);
+                    gone[i] = true;
+                    continue;
+                }
+
+                let crowded = self
+                    .index
+                    .within_squared(next, radius.powi(2))
+                    .into_iter()
+                    .any(|(id, _)| id != point.id);
+                if crowded {
+                    blocked.push((i, velocity));
+                } else {
+                    self.index.delete(point.position, point.id);
+                    self.index.insert(next, point.id);
+                    self.points[i].position = next;
+                    self.points[i].velocity = velocity;
+                }
+            }
+
+            let stuck = blocked.len() == pending.len();
+            pending = blocked;
+            if stuck {
+                break;
+            }
+        }
+
+        // Points that stay blocked stop, rather than push through, and start again from rest
+        for (i, _) in pending {
+            self.points[i].velocity = [0.0; N];
+        }
+
+        let mut gone = gone.into_iter();
+        self.points.retain(|_| !gone.next().unwrap_or(false));
+
+        // Grow new points into the gaps, in the canonical frame
+        let mut refill = self.distribution.clone();
+        refill.transform = None;
+        refill.set_seed(seed::derive(self.key, self.frame));
+        let positions: Vec<Point<N>> = self.points.iter().map(|p| p.position).collect();
+        let merged = refill.merge(&positions, radius);
+        self.add(&merged[positions.len()..]);
+    }
+}
+
+impl<const N: usize, U, R> Iterator for Animation<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = Vec<(u64, Point<N>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frame == 0 {
+            let mut first = self.distribution.clone();
+            first.transform = None;
+            first.set_seed(self.key);
+            let points = first.generate();
+            self.add(&points);
+        } else {
+            self.step();
+        }
+        self.frame += 1;
+
+        Some(
+            self.points
+                .iter()
+                .map(|p| (p.id, self.distribution.transformed(p.position)))
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize, U, R> FusedIterator for Animation<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Point, Poisson2D};
+use std::collections::HashMap;
+
+/// The points of a frame, by identifier
+fn by_id(frame: &[(u64, Point<2>)]) -> HashMap<u64, Point<2>> {
+    frame.iter().copied().collect()
+}
+
+#[test]
+fn first_frame_is_distribution() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let first = poisson.animate().next().unwrap();
+
+    assert_eq!(first, poisson.iter_with_ids().collect::<Vec<_>>());
+}
+
+#[test]
+fn frames_keep_spacing_and_move_smoothly() {
+    let radius = 0.1;
+    let frames: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .animate()
+        .take(30)
+        .collect();
+
+    for frame in frames.iter() {
+        let points: Vec<_> = frame.iter().map(|&(_, p)| p).collect();
+        assert!(crate::stats::verify_min_distance(&points, radius));
+        assert!(points
+            .iter()
+            .all(|p| p.iter().all(|x| (0.0..1.0).contains(x))));
+    }
+
+    let mut moved = false;
+    for pair in frames.windows(2) {
+        let before = by_id(&pair[0]);
+        for (id, [x, y]) in pair[1].iter() {
+            if let Some([bx, by]) = before.get(id) {
+                let step = ((x - bx).powi(2) + (y - by).powi(2)).sqrt();
+                assert!(step <= 0.05 * radius + 1e-6);
+                moved |= step > 0.0;
+            }
+        }
+    }
+    assert!(moved);
+}
+
+#[test]
+fn flow_replaces_points() {
+    let frames: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .animate()
+        .with_speed(0.05)
+        .with_flow(|_| [0.05, 0.0])
+        .take(30)
+        .collect();
+
+    // After drifting 1.5 units to the right, none of the original points remain
+    let first = by_id(&frames[0]);
+    let last = frames.last().unwrap();
+    assert!(last.iter().all(|(id, _)| !first.contains_key(id)));
+
+    // ...yet the space stays about as full
+    let ratio = last.len() as Float / frames[0].len() as Float;
+    assert!(ratio > 0.8, "{ratio}");
+
+    let points: Vec<_> = last.iter().map(|&(_, p)| p).collect();
+    assert!(crate::stats::verify_min_distance(&points, 0.1));
+}
+
+#[test]
+fn deterministic_with_seed() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let a: Vec<_> = poisson.animate().take(5).collect();
+    let b: Vec<_> = poisson.animate().take(5).collect();
+
+    assert_eq!(a, b);
+}
@@ -0,0 +1,148 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Integration with the [Bevy](https://bevyengine.org/) game engine
+//!
+//! Add the [`PoissonPlugin`] to your app, then insert a [`PoissonSampling`] component on an entity
+//! to generate a distribution in the background. Points are generated on Bevy's
+//! [`AsyncComputeTaskPool`] one chunk at a time, and each chunk is delivered as a
+//! [`PoissonChunk`] event, so even large distributions never block a frame. The component is
+//! removed from the entity once the distribution is complete.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use fast_poisson::bevy::{vec2, PoissonChunk, PoissonPlugin, PoissonSampling};
+//! use fast_poisson::Poisson2D;
+//!
+//! fn setup(mut commands: Commands) {
+//!     let poisson = Poisson2D::new().with_radius(0.02);
+//!     commands.spawn(PoissonSampling::new(&poisson, 256));
+//! }
+//!
+//! fn place_trees(mut chunks: EventReader<PoissonChunk<2>>, mut commands: Commands) {
+//!     for chunk in chunks.read() {
+//!         for &point in chunk.points.iter() {
+//!             commands.spawn(Transform::from_translation(vec2(point).extend(0.0) * 100.0));
+//!         }
+//!     }
+//! }
+//!
+//! App::new()
+//!     .add_plugins((MinimalPlugins, PoissonPlugin))
+//!     .add_systems(Startup, setup)
+//!     .add_systems(Update, place_trees)
+//!     .run();
+//! ```
+
+use crate::{Iter, Point, Poisson};
+use ::bevy::app::{App, Plugin, Update};
+use ::bevy::ecs::prelude::*;
+use ::bevy::math::{Vec2, Vec3};
+use ::bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+
+#[cfg(test)]
+mod tests;
+
+/// Convert a 2-dimensional point into a Bevy [`Vec2`]
+pub fn vec2([x, y]: Point<2>) -> Vec2 {
+    Vec2::new(x as f32, y as f32)
+}
+
+/// Convert a 3-dimensional point into a Bevy [`Vec3`]
+pub fn vec3([x, y, z]: Point<3>) -> Vec3 {
+    Vec3::new(x as f32, y as f32, z as f32)
+}
+
+/// Registers the events and systems that drive [`PoissonSampling`] in 2 and 3 dimensions
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PoissonPlugin;
+
+impl Plugin for PoissonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PoissonChunk<2>>()
+            .add_event::<PoissonChunk<3>>()
+            .add_systems(Update, (poll_sampling::<2>, poll_sampling::<3>));
+    }
+}
+
+/// A chunk of points generated for an entity with a [`PoissonSampling`] component
+#[derive(Event, Debug, Clone)]
+pub struct PoissonChunk<const N: usize> {
+    /// The entity for which the points were generated
+    pub entity: Entity,
+    /// The points in this chunk, in the order they were generated
+    pub points: Vec<Point<N>>,
+    /// Whether this is the last chunk of the distribution
+    pub finished: bool,
+}
+
+/// The result of generating a single chunk: the iterator to continue from, and the points
+type ChunkResult<const N: usize> = (Iter<N, ()>, Vec<Point<N>>);
+
+/// Generates a distribution in the background, one chunk at a time
+///
+/// Requires the [`PoissonPlugin`], which emits a [`PoissonChunk`] event for every chunk. Only the
+/// next chunk is generated while the previous one is being delivered, so at most one chunk per
+/// frame is produced for each entity.
+#[derive(Component)]
+pub struct PoissonSampling<const N: usize> {
+    /// The task generating the next chunk
+    task: Task<ChunkResult<N>>,
+    /// The number of points in each chunk
+    chunk_size: usize,
+}
+
+impl<const N: usize> PoissonSampling<N> {
+    /// Start generating the distribution in chunks of `chunk_size` points
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0, or if Bevy's task pools have not yet been initialized.
+    pub fn new(distribution: &Poisson<N>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "Chunks must contain at least one point");
+
+        Self {
+            task: spawn_chunk(distribution.iter(), chunk_size),
+            chunk_size,
+        }
+    }
+}
+
+/// Generate the next chunk of points on the async compute task pool
+fn spawn_chunk<const N: usize>(mut iter: Iter<N, ()>, chunk_size: usize) -> Task<ChunkResult<N>> {
+    AsyncComputeTaskPool::get().spawn(async move {
+        let points = iter.by_ref().take(chunk_size).collect();
+        (iter, points)
+    })
+}
+
+/// Deliver finished chunks and start generating the next ones
+fn poll_sampling<const N: usize>(
+    mut commands: Commands,
+    mut samplers: Query<(Entity, &mut PoissonSampling<N>)>,
+    mut chunks: EventWriter<PoissonChunk<N>>,
+) {
+    for (entity, mut sampling) in samplers.iter_mut() {
+        let Some((iter, points)) = block_on(poll_once(&mut sampling.task)) else {
+            continue;
+        };
+
+        // The iterator is exhausted as soon as it falls short of a full chunk
+        let finished = points.len() < sampling.chunk_size;
+        if finished {
+            commands.entity(entity).remove::<PoissonSampling<N>>();
+        } else {
+            sampling.task = spawn_chunk(iter, sampling.chunk_size);
+        }
+
+        chunks.write(PoissonChunk {
+            entity,
+            points,
+            finished,
+        });
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+use ::bevy::app::TaskPoolPlugin;
+
+#[test]
+fn conversions() {
+    assert_eq!(vec2([0.25, 0.5]), Vec2::new(0.25, 0.5));
+    assert_eq!(vec3([0.25, 0.5, 1.0]), Vec3::new(0.25, 0.5, 1.0));
+}
+
+/// Every chunk received so far
+#[derive(Resource, Default)]
+struct Received(Vec<PoissonChunk<2>>);
+
+fn receive(mut events: EventReader<PoissonChunk<2>>, mut received: ResMut<Received>) {
+    received.0.extend(events.read().cloned());
+}
+
+#[test]
+fn streams_chunks() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+
+    let mut app = App::new();
+    app.add_plugins((TaskPoolPlugin::default(), PoissonPlugin))
+        .init_resource::<Received>()
+        .add_systems(Update, receive.after(poll_sampling::<2>));
+    let entity = app
+        .world_mut()
+        .spawn(PoissonSampling::new(&poisson, 10))
+        .id();
+
+    while app.world().get::<PoissonSampling<2>>(entity).is_some() {
+        app.update();
+    }
+    // Deliver the events from the final update
+    app.update();
+
+    let chunks = &app.world().resource::<Received>().0;
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|chunk| chunk.entity == entity));
+    assert!(chunks[..chunks.len() - 1]
+        .iter()
+        .all(|chunk| chunk.points.len() == 10 && !chunk.finished));
+    assert!(chunks.last().unwrap().finished);
+
+    let points: Vec<_> = chunks
+        .iter()
+        .flat_map(|chunk| chunk.points.clone())
+        .collect();
+    assert_eq!(points, poisson.generate());
+}
@@ -0,0 +1,188 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Generate Poisson disk distributions from the command line
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, ValueEnum};
+use fast_poisson::export::{self, PointCloudAttributes, SvgOptions};
+use fast_poisson::Poisson;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[cfg(not(feature = "single_precision"))]
+type Float = f64;
+#[cfg(feature = "single_precision")]
+type Float = f32;
+
+/// Generate a Poisson disk distribution in the unit box, using Bridson's algorithm
+#[derive(Debug, Parser)]
+#[command(name = "fast-poisson", version)]
+struct Args {
+    /// Number of dimensions, from 1 to 8
+    #[arg(short, long, default_value_t = 2)]
+    dimensions: usize,
+
+    /// Minimum distance between points
+    #[arg(short, long, default_value_t = 0.1)]
+    radius: Float,
+
+    /// Seed for a reproducible distribution; random if omitted
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Number of candidates tried around each point before giving up on it
+    #[arg(short = 'k', long, default_value_t = 30)]
+    samples: u32,
+
+    /// Stop after generating this many points
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    /// Output format; PLY requires 3 dimensions, and SVG requires 2
+    #[arg(short, long, value_enum, default_value_t = Format::Csv)]
+    format: Format,
+
+    /// Width and height of SVG images
+    #[arg(long, default_value_t = 500.0)]
+    scale: Float,
+
+    /// File to write to, instead of standard output
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+    Ply,
+    Svg,
+}
+
+impl Args {
+    /// The distribution described by these arguments
+    fn distribution<const N: usize>(&self) -> Poisson<N> {
+        let mut poisson = Poisson::<N>::new()
+            .with_radius(self.radius)
+            .with_samples(self.samples);
+        if let Some(seed) = self.seed {
+            poisson.set_seed(seed);
+        }
+
+        poisson
+    }
+
+    /// The maximum number of points to generate
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(usize::MAX)
+    }
+}
+
+/// Write a distribution as CSV or JSON
+fn write<const N: usize>(args: &Args, writer: impl Write) -> io::Result<()> {
+    let poisson = args.distribution::<N>();
+    let points = poisson.iter().take(args.limit());
+
+    match args.format {
+        Format::Csv => export::write_csv(writer, &poisson, points),
+        Format::Json => export::write_json(writer, &poisson, points),
+        Format::Ply | Format::Svg => unreachable!("Checked in main"),
+    }
+}
+
+/// Write a 3-dimensional distribution as PLY
+fn write_ply(args: &Args, writer: impl Write) -> io::Result<()> {
+    let poisson = args.distribution::<3>();
+    let points = poisson.iter().take(args.limit()).map(|point| (point, None));
+
+    export::write_ply(writer, &poisson, points, PointCloudAttributes::default())
+}
+
+/// Write a 2-dimensional distribution as SVG
+fn write_svg(args: &Args, mut writer: impl Write) -> io::Result<()> {
+    let poisson = args.distribution::<2>();
+    let options = SvgOptions {
+        scale: args.scale,
+        ..Default::default()
+    };
+
+    let points = poisson.iter().take(args.limit());
+    writer.write_all(export::svg(&poisson, points, &options).as_bytes())
+}
+
+fn run(args: &Args, writer: impl Write) -> io::Result<()> {
+    match (args.format, args.dimensions) {
+        (Format::Ply, _) => write_ply(args, writer),
+        (Format::Svg, _) => write_svg(args, writer),
+        (_, 1) => write::<1>(args, writer),
+        (_, 2) => write::<2>(args, writer),
+        (_, 3) => write::<3>(args, writer),
+        (_, 4) => write::<4>(args, writer),
+        (_, 5) => write::<5>(args, writer),
+        (_, 6) => write::<6>(args, writer),
+        (_, 7) => write::<7>(args, writer),
+        (_, 8) => write::<8>(args, writer),
+        _ => unreachable!("Checked in main"),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let mut command = Args::command();
+    if !(1..=8).contains(&args.dimensions) {
+        command
+            .error(ErrorKind::InvalidValue, "dimensions must be from 1 to 8")
+            .exit();
+    }
+    if args.format == Format::Ply && args.dimensions != 3 {
+        command
+            .error(
+                ErrorKind::ArgumentConflict,
+                "PLY output requires 3 dimensions",
+            )
+            .exit();
+    }
+    if args.format == Format::Svg && args.dimensions != 2 {
+        command
+            .error(
+                ErrorKind::ArgumentConflict,
+                "SVG output requires 2 dimensions",
+            )
+            .exit();
+    }
+    if !(args.radius > 0.0 && args.radius.is_finite()) {
+        command
+            .error(ErrorKind::InvalidValue, "radius must be positive and finite")
+            .exit();
+    }
+
+    let result = match &args.output {
+        Some(path) => File::create(path).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            run(&args, &mut writer)?;
+            writer.flush()
+        }),
+        None => {
+            let mut writer = BufWriter::new(io::stdout().lock());
+            run(&args, &mut writer).and_then(|_| writer.flush())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // Output piped into e.g. `head` is not an error
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Handling candidates that fall outside the box being sampled
+
+use crate::{Float, Point};
+
+#[cfg(test)]
+mod tests;
+
+/// What happens to candidates that fall outside the box being sampled
+///
+/// See [`Poisson::with_boundary`](crate::Poisson::with_boundary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Boundary {
+    /// Candidates outside the box are rejected, as in Bridson's algorithm
+    ///
+    /// Points near the edges have fewer candidates landing inside the box around them, so the
+    /// edges are filled a little less densely than the rest of the box.
+    #[default]
+    Reject,
+    /// Candidates outside the box are moved to the closest point on its edge
+    ///
+    /// This fills the edges densely, and places many points right on them.
+    Clamp,
+    /// Candidates outside the box are mirrored back into it across the edge they crossed
+    ///
+    /// This fills the edges about as densely as the rest of the box, without favoring points
+    /// right on them.
+    Reflect,
+}
+
+impl Boundary {
+    /// Move the point back into the box `[min, max)`, if it lies outside of it
+    ///
+    /// The moved point is always closer to any point within the box than the original was, so it
+    /// still lies within the annulus around the point it was generated from.
+    pub(crate) fn apply<const N: usize>(
+        self,
+        mut point: Point<N>,
+        [min, max]: [Point<N>; 2],
+    ) -> Point<N> {
+        if self == Boundary::Reject {
+            return point;
+        }
+
+        for i in 0..N {
+            if self == Boundary::Reflect {
+                if point[i] < min[i] {
+                    point[i] = 2.0 * min[i] - point[i];
+                } else if point[i] >= max[i] {
+                    point[i] = 2.0 * max[i] - point[i];
+                }
+            }
+            // A point reflected across one edge may still lie beyond the other, if it was further
+            // outside than the box is wide
+            point[i] = point[i].max(min[i]).min(below(max[i]));
+        }
+
+        point
+    }
+}
+
+/// The largest value below `x`, as the box excludes its maximum
+fn below(x: Float) -> Float {
+    if x > 0.0 {
+        Float::from_bits(x.to_bits() - 1)
+    } else if x < 0.0 {
+        Float::from_bits(x.to_bits() + 1)
+    } else {
+        -Float::from_bits(1)
+    }
+}
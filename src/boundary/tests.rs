@@ -0,0 +1,76 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Boundary;
+use crate::stats::verify_min_distance;
+use crate::{Float, Point, Poisson2D};
+
+const BOX: [Point<2>; 2] = [[0.0, 0.0], [1.0, 2.0]];
+
+#[test]
+fn points_inside_are_left_alone() {
+    for boundary in [Boundary::Reject, Boundary::Clamp, Boundary::Reflect] {
+        assert_eq!(boundary.apply([0.5, 1.5], BOX), [0.5, 1.5]);
+        assert_eq!(boundary.apply([0.0, 0.0], BOX), [0.0, 0.0]);
+    }
+}
+
+#[test]
+fn points_outside_are_moved_inside() {
+    assert_eq!(Boundary::Reject.apply([-0.25, 2.5], BOX), [-0.25, 2.5]);
+    assert_eq!(Boundary::Clamp.apply([-0.25, 0.5], BOX), [0.0, 0.5]);
+    assert_eq!(Boundary::Reflect.apply([-0.25, 2.5], BOX), [0.25, 1.5]);
+
+    // The maximum is excluded from the box
+    for boundary in [Boundary::Clamp, Boundary::Reflect] {
+        let [x, y] = boundary.apply([1.5, 2.0], BOX);
+        assert!((0.0..1.0).contains(&x) && (0.0..2.0).contains(&y));
+        assert!(y > 1.9);
+    }
+}
+
+#[test]
+fn points_far_outside_are_clamped_after_reflecting() {
+    assert_eq!(
+        Boundary::Reflect.apply([-3.0, 0.5], BOX),
+        [1.0 - Float::EPSILON / 2.0, 0.5]
+    );
+}
+
+#[test]
+fn edges_are_filled_densely() {
+    let near_edge = |boundary| {
+        let points = Poisson2D::new()
+            .with_seed(0xBADBEEF)
+            .with_radius(0.02)
+            .with_boundary(boundary)
+            .generate();
+
+        assert!(verify_min_distance(&points, 0.02 * 0.999));
+        assert!(points
+            .iter()
+            .all(|&[x, y]| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)));
+
+        points
+            .iter()
+            .filter(|p| p.iter().any(|&x| !(0.01..0.99).contains(&x)))
+            .count()
+    };
+
+    let rejected = near_edge(Boundary::Reject);
+    assert!(near_edge(Boundary::Clamp) > rejected);
+    assert!(near_edge(Boundary::Reflect) > rejected);
+}
+
+#[test]
+fn boundary_is_part_of_the_configuration() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let reflected = poisson.clone().with_boundary(Boundary::Reflect);
+
+    assert_ne!(poisson, reflected);
+    assert!(reflected.summary().contains("boundary: Reflect"));
+}
@@ -0,0 +1,353 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! A builder checking at compile time that a distribution is configured consistently
+
+use crate::{Float, Point, Poisson, Rand};
+use rand::{Rng, SeedableRng};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a [`PoissonBuilder`] without a seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unseeded;
+
+/// The state of a [`PoissonBuilder`] with a seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Seeded;
+
+/// The state of a [`PoissonBuilder`] still sampling the default space, the unit hypercube
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DefaultSpace;
+
+/// The state of a [`PoissonBuilder`] sampling an axis-aligned box
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BoxSpace;
+
+/// The state of a [`PoissonBuilder`] sampling a space described by a validation function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CustomSpace;
+
+/// A builder for [`Poisson`] distributions that rules out conflicting settings at compile time
+///
+/// The builder tracks in its type whether a seed was given, `S`, and how the space was described,
+/// `D`. The space can be described only once, either as a box or by a validation function, so the
+/// two can't silently replace one another; likewise the seed can only be given once. Only seeded
+/// builders implement [`Eq`] and [`Hash`], as only they always generate the same distribution;
+/// this lets them serve as keys for caching the distributions they generate. A space described
+/// by a validation function is part of the key, so its user data must implement [`Eq`] and
+/// [`Hash`] too.
+///
+/// ```
+/// # use fast_poisson::PoissonBuilder;
+/// let poisson = PoissonBuilder::<2>::new()
+///     .bounds([-512.0, -512.0], [512.0, 512.0])
+///     .radius(32.0)
+///     .seed(0xBADBEEF)
+///     .build();
+///
+/// let points = poisson.generate();
+/// ```
+///
+/// Describing the space twice doesn't compile:
+///
+/// ```compile_fail
+/// # use fast_poisson::PoissonBuilder;
+/// let poisson = PoissonBuilder::<2>::new()
+///     .bounds([-512.0, -512.0], [512.0, 512.0])
+///     .domain(|[x, y], _| x * x + y * y < 1.0, ());
+/// ```
+///
+/// Nor does comparing unseeded builders:
+///
+/// ```compile_fail
+/// # use fast_poisson::PoissonBuilder;
+/// let poisson = PoissonBuilder::<2>::new().radius(0.05);
+/// assert!(poisson == poisson.clone());
+/// ```
+///
+/// Nor comparing builders whose validation functions take user data that can't be compared:
+///
+/// ```compile_fail
+/// # use fast_poisson::PoissonBuilder;
+/// let poisson = PoissonBuilder::<2>::new()
+///     .domain(|[x, _], limit: &f64| x < *limit, 0.5)
+///     .seed(0xBADBEEF);
+/// assert!(poisson == poisson.clone());
+/// ```
+///
+/// Settings beyond these can be made on the built [`Poisson`].
+#[derive(Debug)]
+pub struct PoissonBuilder<const N: usize, U = (), R = Rand, S = Unseeded, D = DefaultSpace>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution being built
+    poisson: Poisson<N, U, R>,
+    /// Marker for our states
+    _state: PhantomData<(S, D)>,
+}
+
+impl<const N: usize, R> PoissonBuilder<N, (), R, Unseeded, DefaultSpace>
+where
+    R: Rng + SeedableRng,
+{
+    /// Start building a distribution with the default settings
+    ///
+    /// See [`Poisson::new`] for the defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<const N: usize, R> Default for PoissonBuilder<N, (), R, Unseeded, DefaultSpace>
+where
+    R: Rng + SeedableRng,
+{
+    fn default() -> Self {
+        Self {
+            poisson: Poisson::default(),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<const N: usize, U, R, S, D> PoissonBuilder<N, U, R, S, D>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Move the distribution into a builder of another state
+    fn into_state<T, E>(self) -> PoissonBuilder<N, U, R, T, E> {
+        PoissonBuilder {
+            poisson: self.poisson,
+            _state: PhantomData,
+        }
+    }
+
+    /// Specify the radius around each point
+    ///
+    /// See [`Poisson::with_radius`].
+    #[must_use]
+    pub fn radius(mut self, radius: Float) -> Self {
+        self.poisson.set_radius(radius);
+        self
+    }
+
+    /// Specify the maximum samples to generate around each point
+    ///
+    /// See [`Poisson::with_samples`].
+    #[must_use]
+    pub fn samples(mut self, samples: u32) -> Self {
+        self.poisson.set_samples(samples);
+        self
+    }
+
+    /// Specify the point from which the distribution grows outward
+    ///
+    /// See [`Poisson::with_first_point`].
+    #[must_use]
+    pub fn first_point(mut self, point: Point<N>) -> Self {
+        self.poisson.set_first_point(point);
+        self
+    }
+
+    /// Search for parts of the space that weren't reached by growing from the first point
+    ///
+    /// See [`Poisson::with_reseeding`].
+    #[must_use]
+    pub fn reseeding(mut self, attempts: u32, min: Point<N>, max: Point<N>) -> Self {
+        self.poisson.set_reseeding(attempts, min, max);
+        self
+    }
+
+    /// Finish building the distribution
+    #[must_use]
+    pub fn build(self) -> Poisson<N, U, R> {
+        self.poisson
+    }
+}
+
+impl<const N: usize, U, R, D> PoissonBuilder<N, U, R, Unseeded, D>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Specify the PRNG seed, making the distribution deterministic
+    ///
+    /// See [`Poisson::with_seed`].
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> PoissonBuilder<N, U, R, Seeded, D> {
+        self.poisson.set_seed(seed);
+        self.into_state()
+    }
+}
+
+impl<const N: usize, R, S> PoissonBuilder<N, (), R, S, DefaultSpace>
+where
+    R: Rng + SeedableRng,
+{
+    /// Sample the box from `min` to `max`, which may lie anywhere in space
+    ///
+    /// See [`Poisson::with_bounds`].
+    #[must_use]
+    pub fn bounds(mut self, min: Point<N>, max: Point<N>) -> PoissonBuilder<N, (), R, S, BoxSpace> {
+        self.poisson.set_bounds(min, max);
+        self.into_state()
+    }
+
+    /// Sample the space described by a validation function, with data of any type passed to it
+    ///
+    /// See [`Poisson::with_validate`].
+    #[must_use]
+    pub fn domain<V>(
+        self,
+        validate: fn(Point<N>, &V) -> bool,
+        user_data: V,
+    ) -> PoissonBuilder<N, V, R, S, CustomSpace>
+    where
+        V: Default + Clone,
+    {
+        PoissonBuilder {
+            poisson: self.poisson.into_space(validate, user_data),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<const N: usize, U, R, S, D> Clone for PoissonBuilder<N, U, R, S, D>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    fn clone(&self) -> Self {
+        Self {
+            poisson: self.poisson.clone(),
+            _state: PhantomData,
+        }
+    }
+}
+
+// Seeded distributions always generate the same points, so they equal themselves, as long as
+// none of their settings are NaN. The default space and boxes are fully described by the settings
+// of the distribution, which always has unit user data.
+macro_rules! impl_key {
+    ($space:ty) => {
+        impl<const N: usize, R> PartialEq for PoissonBuilder<N, (), R, Seeded, $space>
+        where
+            R: Rng + SeedableRng,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.poisson == other.poisson
+            }
+        }
+
+        impl<const N: usize, R> Eq for PoissonBuilder<N, (), R, Seeded, $space> where
+            R: Rng + SeedableRng
+        {
+        }
+
+        impl<const N: usize, R> Hash for PoissonBuilder<N, (), R, Seeded, $space>
+        where
+            R: Rng + SeedableRng,
+        {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                hash_settings(&self.poisson, state);
+            }
+        }
+    };
+}
+
+impl_key!(DefaultSpace);
+impl_key!(BoxSpace);
+
+/// A space described by a validation function also compares its user data, and the function
+/// itself by address
+///
+/// The same function may have different addresses in different parts of a program, which only
+/// ever makes equal builders compare unequal, never the reverse.
+impl<const N: usize, U, R> PartialEq for PoissonBuilder<N, U, R, Seeded, CustomSpace>
+where
+    U: Default + Clone + Eq + Hash,
+    R: Rng + SeedableRng,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.poisson == other.poisson
+            && self.poisson.validate as usize == other.poisson.validate as usize
+            && self.poisson.validate_user_data == other.poisson.validate_user_data
+    }
+}
+
+impl<const N: usize, U, R> Eq for PoissonBuilder<N, U, R, Seeded, CustomSpace>
+where
+    U: Default + Clone + Eq + Hash,
+    R: Rng + SeedableRng,
+{
+}
+
+impl<const N: usize, U, R> Hash for PoissonBuilder<N, U, R, Seeded, CustomSpace>
+where
+    U: Default + Clone + Eq + Hash,
+    R: Rng + SeedableRng,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_settings(&self.poisson, state);
+        (self.poisson.validate as usize).hash(state);
+        self.poisson.validate_user_data.hash(state);
+    }
+}
+
+/// Hash the same settings that are compared for equality, other than the density and transform,
+/// whose presence alone is hashed
+fn hash_settings<const N: usize, U, R, H>(poisson: &Poisson<N, U, R>, state: &mut H)
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    H: Hasher,
+{
+    // Zeroes of either sign are equal, so they must hash the same
+    fn float<H: Hasher>(x: Float, state: &mut H) {
+        let x = if x == 0.0 { 0.0 } else { x };
+        x.to_bits().hash(state);
+    }
+    fn point<const N: usize, H: Hasher>(p: &Point<N>, state: &mut H) {
+        p.iter().for_each(|&x| float(x, state));
+    }
+
+    float(poisson.radius, state);
+    poisson.seed.hash(state);
+    poisson.stream.map(|(index, _)| index).hash(state);
+    poisson.num_samples.hash(state);
+    poisson.adaptive_samples.hash(state);
+    poisson.annulus.iter().for_each(|&x| float(x, state));
+    float(poisson.radius_tolerance, state);
+    poisson.volume_uniform.hash(state);
+    poisson.first_points.len().hash(state);
+    poisson.first_points.iter().for_each(|p| point(p, state));
+    poisson.initial_point_emitted.hash(state);
+    poisson.reseed_attempts.hash(state);
+    poisson.reseed_bounds.iter().for_each(|p| point(p, state));
+    poisson.bounds.is_some().hash(state);
+    poisson
+        .bounds
+        .iter()
+        .flatten()
+        .for_each(|p| point(p, state));
+    poisson.boundary.hash(state);
+    poisson.quotas.hash(state);
+    poisson.shuffled_output.hash(state);
+    poisson.progressive_output.hash(state);
+    poisson.selection.hash(state);
+    poisson.candidate_sequence.hash(state);
+    poisson.density.is_some().hash(state);
+    poisson.transform.is_some().hash(state);
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+#[test]
+fn builds_same_as_poisson() {
+    let built = PoissonBuilder::<2>::new()
+        .radius(0.05)
+        .samples(20)
+        .seed(0xBADBEEF)
+        .build();
+    let poisson = Poisson2D::new()
+        .with_radius(0.05)
+        .with_samples(20)
+        .with_seed(0xBADBEEF);
+
+    assert_eq!(built, poisson);
+    assert_eq!(built.generate(), poisson.generate());
+}
+
+#[test]
+fn bounds_offset_the_space() {
+    let poisson = PoissonBuilder::<2>::new()
+        .seed(0xBADBEEF)
+        .bounds([-2.0, 10.0], [-1.0, 11.0])
+        .radius(0.1)
+        .build();
+    let points = poisson.generate();
+
+    assert!(points.len() > 50);
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (-2.0..-1.0).contains(&x) && (10.0..11.0).contains(&y)));
+    assert!(crate::stats::verify_min_distance(&points, 0.1));
+}
+
+#[test]
+fn domain_replaces_user_data() {
+    let poisson = PoissonBuilder::<2>::new()
+        .domain(
+            |[x, y], limit: &Float| (0.0..*limit).contains(&x) && (0.0..1.0).contains(&y),
+            0.5,
+        )
+        .seed(0xBADBEEF)
+        .build();
+
+    assert!(poisson.generate().iter().all(|[x, _]| *x < 0.5));
+}
+
+#[test]
+fn seeded_builders_are_eq() {
+    fn assert_eq_impl<T: Eq>(_: &T) {}
+
+    let a = PoissonBuilder::<2>::new().radius(0.2).seed(1337);
+    let b = a.clone();
+    assert_eq_impl(&a);
+    assert!(a == b);
+    assert!(a != PoissonBuilder::<2>::new().radius(0.2).seed(1338));
+}
+
+#[test]
+fn seeded_builders_hash_as_cache_keys() {
+    use std::collections::HashMap;
+
+    let builder = PoissonBuilder::<2>::new().radius(0.2).seed(1337);
+    let mut cache = HashMap::new();
+    cache.insert(builder.clone(), builder.clone().build().generate());
+
+    assert!(cache.contains_key(&builder.clone()));
+    assert!(!cache.contains_key(&PoissonBuilder::<2>::new().radius(0.2).seed(1338)));
+    assert_eq!(cache[&builder], builder.build().generate());
+}
+
+#[test]
+fn signed_zeroes_hash_the_same() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |builder: &PoissonBuilder<2, (), crate::Rand, Seeded>| {
+        let mut hasher = DefaultHasher::new();
+        builder.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let positive = PoissonBuilder::<2>::new().first_point([0.0, 0.5]).seed(1);
+    let negative = PoissonBuilder::<2>::new().first_point([-0.0, 0.5]).seed(1);
+    assert!(positive == negative);
+    assert_eq!(hash(&positive), hash(&negative));
+}
+
+#[test]
+fn domains_are_part_of_cache_keys() {
+    use std::collections::HashSet;
+
+    fn below(limit: u32) -> PoissonBuilder<2, u32, crate::Rand, Seeded, CustomSpace> {
+        PoissonBuilder::<2>::new()
+            .domain(
+                |[x, y], limit: &u32| {
+                    (0.0..*limit as Float / 10.0).contains(&x) && (0.0..1.0).contains(&y)
+                },
+                limit,
+            )
+            .seed(1)
+    }
+
+    assert!(below(5) == below(5));
+    assert!(below(5) != below(9));
+    assert_ne!(below(5).build().generate(), below(9).build().generate());
+
+    let keys: HashSet<_> = [below(5), below(9), below(5)].into_iter().collect();
+    assert_eq!(keys.len(), 2);
+}
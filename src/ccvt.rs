@@ -0,0 +1,244 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Capacity-constrained Voronoi tessellations, for optimizing points towards ideal blue noise
+//!
+//! This follows Balzer, Schlömer, and Deussen, "Capacity-Constrained Point Distributions: A
+//! Variant of Lloyd's Method" (2009). The space is represented by many sites, drawn according to
+//! the density, and each point is given an equal share of them. Pairs of neighboring points then
+//! trade sites whenever doing so brings the sites closer to their points, and each point moves to
+//! the centroid of its sites. Unlike plain Lloyd relaxation every point keeps covering the same
+//! share of the density, so points neither clump together nor settle into a regular grid.
+
+use crate::order::{self, SpatialOrder};
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of attempts to draw each site, before giving up on a space that is too sparse
+const SITE_ATTEMPTS: usize = 100;
+
+/// Distance, in radii around each point, within which other points are treated as neighbors
+const NEIGHBORHOOD: Float = 3.0;
+
+/// Optimize `points` towards a capacity-constrained Voronoi tessellation of the distribution
+///
+/// Returns the number of iterations run, which is fewer than `iterations` if no more sites could
+/// be traded.
+pub(crate) fn optimize<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    points: &mut [Point<N>],
+    sites_per_point: usize,
+    iterations: u32,
+) -> u32
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    if points.len() < 2 || sites_per_point == 0 {
+        return 0;
+    }
+
+    let sites = draw_sites(distribution, points.len() * sites_per_point);
+    let mut members = assign(points, &sites);
+
+    for iteration in 0..iterations {
+        let traded = trade(distribution, points, &sites, &mut members);
+
+        for (point, members) in points.iter_mut().zip(&members) {
+            if let Some(centroid) = centroid(&sites, members) {
+                *point = centroid;
+            }
+        }
+
+        if !traded {
+            return iteration + 1;
+        }
+    }
+
+    iterations
+}
+
+/// Draw `count` sites within the space, with the distribution's density
+fn draw_sites<const N: usize, U, R>(distribution: &Poisson<N, U, R>, count: usize) -> Vec<Point<N>>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let mut rng = distribution.rng();
+    let [min, max] = distribution.reseed_bounds;
+
+    let mut sites = Vec::with_capacity(count);
+    for _ in 0..count * SITE_ATTEMPTS {
+        if sites.len() == count {
+            break;
+        }
+
+        let site: Point<N> =
+            std::array::from_fn(|i| min[i] + (max[i] - min[i]) * rng.gen::<Float>());
+        let density = distribution
+            .density
+            .as_ref()
+            .map_or(1.0, |density| density.density(site));
+        if distribution.in_space(site) && rng.gen::<Float>() < density {
+            sites.push(site);
+        }
+    }
+
+    sites
+}
+
+/// Give each point an equal share of the sites, close to it
+///
+/// Points and sites are both ordered along a Hilbert curve, and each point takes the next run of
+/// sites along it. The shares are far from optimal, but already local enough that trading between
+/// neighbors can improve them.
+fn assign<const N: usize>(points: &[Point<N>], sites: &[Point<N>]) -> Vec<Vec<usize>> {
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in points.iter().chain(sites) {
+        for i in 0..N {
+            min[i] = min[i].min(point[i]);
+            max[i] = max[i].max(point[i]);
+        }
+    }
+
+    let bits = (128 / N.max(1)).clamp(1, 32) as u32;
+    let cells = ((1u64 << bits) - 1) as Float;
+    let key = |point: &Point<N>| {
+        let cell: [u32; N] = std::array::from_fn(|i| {
+            let extent = max[i] - min[i];
+            if extent > 0.0 {
+                ((point[i] - min[i]) / extent * cells) as u32
+            } else {
+                0
+            }
+        });
+        order::key(cell, bits, SpatialOrder::Hilbert)
+    };
+
+    let mut point_order: Vec<usize> = (0..points.len()).collect();
+    point_order.sort_by_cached_key(|&i| key(&points[i]));
+    let mut site_order: Vec<usize> = (0..sites.len()).collect();
+    site_order.sort_by_cached_key(|&i| key(&sites[i]));
+
+    let mut members = vec![Vec::new(); points.len()];
+    for (rank, &point) in point_order.iter().enumerate() {
+        let start = rank * sites.len() / points.len();
+        let end = (rank + 1) * sites.len() / points.len();
+        members[point] = site_order[start..end].to_vec();
+    }
+
+    members
+}
+
+/// Trade sites between every pair of neighboring points, wherever that brings them closer
+///
+/// Returns true if any sites were traded.
+fn trade<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    points: &[Point<N>],
+    sites: &[Point<N>],
+    members: &mut [Vec<usize>],
+) -> bool
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let mut index = DefaultIndex::<N>::with_spacing(distribution.radius);
+    for (i, &point) in points.iter().enumerate() {
+        index.insert(point, i as u64);
+    }
+
+    let mut traded = false;
+    for (i, &point) in points.iter().enumerate() {
+        let reach = NEIGHBORHOOD * distribution.radius_at(point);
+        let mut neighbors: Vec<usize> = index
+            .within_squared(point, reach.powi(2))
+            .into_iter()
+            .map(|(item, _)| item as usize)
+            .filter(|&j| j > i)
+            .collect();
+        // Trade in a consistent order, whatever order the index returns neighbors in
+        neighbors.sort_unstable();
+
+        for j in neighbors {
+            traded |= trade_pair(sites, points[i], points[j], members, i, j);
+        }
+    }
+
+    traded
+}
+
+/// Trade sites between points `i` and `j`, at `a` and `b`, pairing off those that would each be
+/// closer to the other point, as long as the pair as a whole gets closer
+///
+/// Returns true if any sites were traded.
+fn trade_pair<const N: usize>(
+    sites: &[Point<N>],
+    a: Point<N>,
+    b: Point<N>,
+    members: &mut [Vec<usize>],
+    i: usize,
+    j: usize,
+) -> bool {
+    // How much closer each site would be to the other point, most eager first
+    let gains = |members: &[usize], from: Point<N>, to: Point<N>| {
+        let mut gains: Vec<(Float, usize)> = members
+            .iter()
+            .enumerate()
+            .map(|(k, &site)| {
+                (
+                    distance_squared(sites[site], from) - distance_squared(sites[site], to),
+                    k,
+                )
+            })
+            .collect();
+        gains.sort_unstable_by(|x, y| y.0.total_cmp(&x.0));
+        gains
+    };
+    let from_i = gains(&members[i], a, b);
+    let from_j = gains(&members[j], b, a);
+
+    let mut traded = false;
+    for (&(gain_i, k), &(gain_j, l)) in from_i.iter().zip(&from_j) {
+        if gain_i + gain_j <= 0.0 {
+            break;
+        }
+
+        let site = members[i][k];
+        members[i][k] = members[j][l];
+        members[j][l] = site;
+        traded = true;
+    }
+
+    traded
+}
+
+/// The centroid of the given sites, if there are any
+fn centroid<const N: usize>(sites: &[Point<N>], members: &[usize]) -> Option<Point<N>> {
+    if members.is_empty() {
+        return None;
+    }
+
+    let mut sum = [0.0; N];
+    for &site in members {
+        for (s, x) in sum.iter_mut().zip(sites[site]) {
+            *s += x;
+        }
+    }
+
+    Some(sum.map(|s| s / members.len() as Float))
+}
+
+/// The squared distance between two points
+fn distance_squared<const N: usize>(a: Point<N>, b: Point<N>) -> Float {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
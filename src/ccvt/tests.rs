@@ -0,0 +1,116 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Point, Poisson2D, Rand};
+use rand::{Rng, SeedableRng};
+
+/// The distance from each point to its nearest neighbor
+fn nearest_distances(points: &[Point<2>]) -> Vec<Float> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, b)| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt())
+                .fold(Float::MAX, Float::min)
+        })
+        .collect()
+}
+
+/// The coefficient of variation of the distances between nearest neighbors
+fn irregularity(points: &[Point<2>]) -> Float {
+    let distances = nearest_distances(points);
+    let mean = distances.iter().sum::<Float>() / distances.len() as Float;
+    let variance =
+        distances.iter().map(|d| (d - mean).powi(2)).sum::<Float>() / distances.len() as Float;
+
+    variance.sqrt() / mean
+}
+
+#[test]
+fn points_are_kept_within_the_space() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.1);
+    let mut points = poisson.generate();
+    let count = points.len();
+
+    poisson.optimize_ccvt(&mut points, 64, 10);
+
+    assert_eq!(points.len(), count);
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)));
+}
+
+#[test]
+fn white_noise_becomes_blue() {
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    let mut points: Vec<Point<2>> = (0..100).map(|_| [rng.gen(), rng.gen()]).collect();
+    let before = nearest_distances(&points)
+        .into_iter()
+        .fold(Float::MAX, Float::min);
+
+    Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .optimize_ccvt(&mut points, 128, 50);
+
+    // 100 points evenly spread over the unit square are about 0.1 apart
+    let after = nearest_distances(&points)
+        .into_iter()
+        .fold(Float::MAX, Float::min);
+    assert!(after > 0.05 && after > 5.0 * before, "{before} {after}");
+    assert!(irregularity(&points) < 0.15);
+}
+
+#[test]
+fn density_is_followed() {
+    // Twice as many sites in the right half, so it should end up with twice as many points
+    let poisson = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.05)
+        .with_density(|[x, _]| if x < 0.5 { 0.5 } else { 1.0 }, 0.05, 0.1);
+    let mut points = poisson.generate();
+
+    poisson.optimize_ccvt(&mut points, 128, 30);
+
+    let right = points.iter().filter(|&&[x, _]| x >= 0.5).count() as Float;
+    let ratio = right / points.len() as Float;
+    assert!((ratio - 2.0 / 3.0).abs() < 0.05, "{ratio}");
+}
+
+#[test]
+fn stops_once_converged() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.2);
+    let mut points = poisson.generate();
+
+    let iterations = poisson.optimize_ccvt(&mut points, 32, 1000);
+
+    assert!(iterations > 0 && iterations < 1000);
+}
+
+#[test]
+fn optimization_is_deterministic() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.1);
+    let mut a = poisson.generate();
+    let mut b = a.clone();
+
+    poisson.optimize_ccvt(&mut a, 64, 10);
+    poisson.optimize_ccvt(&mut b, 64, 10);
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn too_few_points_are_left_alone() {
+    let poisson = Poisson2D::new();
+    let mut points = vec![[0.5, 0.5]];
+
+    assert_eq!(poisson.optimize_ccvt(&mut points, 64, 10), 0);
+    assert_eq!(points, vec![[0.5, 0.5]]);
+}
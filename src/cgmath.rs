@@ -0,0 +1,49 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Conversions to the points and vectors of [cgmath](https://crates.io/crates/cgmath)
+//!
+//! Coordinates keep their precision: they are `f64`, or `f32` with the `single_precision`
+//! feature.
+//!
+//! ```
+//! use fast_poisson::cgmath::point2;
+//! use fast_poisson::Poisson2D;
+//!
+//! let points: Vec<_> = Poisson2D::new().iter().map(point2).collect();
+//! ```
+
+use crate::{Float, Point};
+use ::cgmath::{Point2, Point3, Vector2, Vector3, Vector4};
+
+#[cfg(test)]
+mod tests;
+
+/// Convert a 2-dimensional point into a cgmath [`Point2`]
+pub fn point2([x, y]: Point<2>) -> Point2<Float> {
+    Point2::new(x, y)
+}
+
+/// Convert a 3-dimensional point into a cgmath [`Point3`]
+pub fn point3([x, y, z]: Point<3>) -> Point3<Float> {
+    Point3::new(x, y, z)
+}
+
+/// Convert a 2-dimensional point into a cgmath [`Vector2`]
+pub fn vector2(point: Point<2>) -> Vector2<Float> {
+    point.into()
+}
+
+/// Convert a 3-dimensional point into a cgmath [`Vector3`]
+pub fn vector3(point: Point<3>) -> Vector3<Float> {
+    point.into()
+}
+
+/// Convert a 4-dimensional point into a cgmath [`Vector4`]
+pub fn vector4(point: Point<4>) -> Vector4<Float> {
+    point.into()
+}
@@ -0,0 +1,20 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+
+#[test]
+fn conversions() {
+    assert_eq!(point2([0.25, 0.5]), Point2::new(0.25, 0.5));
+    assert_eq!(point3([0.25, 0.5, 1.0]), Point3::new(0.25, 0.5, 1.0));
+    assert_eq!(vector2([0.25, 0.5]), Vector2::new(0.25, 0.5));
+    assert_eq!(vector3([0.25, 0.5, 1.0]), Vector3::new(0.25, 0.5, 1.0));
+    assert_eq!(
+        vector4([0.25, 0.5, 1.0, 2.0]),
+        Vector4::new(0.25, 0.5, 1.0, 2.0)
+    );
+}
@@ -0,0 +1,172 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{seed, Float, Point, Poisson, Rand};
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Deterministic, chunk-by-chunk generation of an unbounded distribution
+///
+/// Space is divided into cubic chunks, each of which can be generated on its own, in any order,
+/// and always with the same result for the same seed. Points never violate the radius across chunk
+/// borders.
+///
+/// To achieve this, chunks are split into `2^N` phases by the parity of their coordinates, such
+/// that no two adjacent chunks share a phase. A chunk is generated around the points of its
+/// neighbors from earlier phases, which are generated first if necessary; since neighbors in
+/// turn depend on their own neighbors, generating a chunk may require generating a few chunks
+/// around it. Every generated chunk is cached, so this cost is amortized when generating many
+/// nearby chunks.
+///
+/// Because the space is unbounded, the distribution's validation function is not used.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// let mut chunks = Poisson2D::new().with_seed(0xBADBEEF).chunks(1.0);
+///
+/// // Chunks can be generated in any order, with identical results
+/// let far_away = chunks.chunk([1000, -1000]).to_vec();
+/// let origin = chunks.chunk([0, 0]).to_vec();
+/// ```
+pub struct Chunks<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution from which chunks are generated
+    distribution: Poisson<N, U, R>,
+    /// Width of each chunk along every axis
+    size: Float,
+    /// Seed from which each chunk's seed is derived
+    seed: u64,
+    /// Previously generated chunks
+    cache: HashMap<[i64; N], Vec<Point<N>>>,
+}
+
+/// The box a chunk's points must lie within
+#[derive(Debug, Clone)]
+struct ChunkBox<const N: usize> {
+    min: Point<N>,
+    max: Point<N>,
+}
+
+impl<const N: usize> Default for ChunkBox<N> {
+    fn default() -> Self {
+        Self {
+            min: [0.0; N],
+            max: [0.0; N],
+        }
+    }
+}
+
+impl<const N: usize> ChunkBox<N> {
+    fn contains(point: Point<N>, chunk: &ChunkBox<N>) -> bool {
+        (0..N).all(|i| (chunk.min[i]..chunk.max[i]).contains(&point[i]))
+    }
+}
+
+impl<const N: usize, U, R> Chunks<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Create a chunked generator for the distribution
+    pub(crate) fn new(distribution: Poisson<N, U, R>, size: Float) -> Self {
+        assert!(
+            size >= distribution.radius,
+            "Chunks must be at least as large as the radius"
+        );
+        let seed = distribution.seed.unwrap_or_else(rand::random);
+
+        Self {
+            distribution,
+            size,
+            seed,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The width of each chunk along every axis
+    #[must_use]
+    pub fn size(&self) -> Float {
+        self.size
+    }
+
+    /// The generation phase of a chunk, determined by the parity of its coordinates
+    fn phase(coord: [i64; N]) -> usize {
+        coord
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.rem_euclid(2) as usize) << i)
+            .sum()
+    }
+
+    /// All chunks adjacent to `coord`, including diagonally
+    fn neighbors(coord: [i64; N]) -> impl Iterator<Item = [i64; N]> {
+        (0..3_usize.pow(N as u32)).filter_map(move |n| {
+            let mut neighbor = coord;
+            let mut n = n;
+            for c in neighbor.iter_mut() {
+                *c += (n % 3) as i64 - 1;
+                n /= 3;
+            }
+
+            (neighbor != coord).then_some(neighbor)
+        })
+    }
+
+    /// The points within the chunk at `coord`, generating it if necessary
+    ///
+    /// The chunk at `coord` covers `[coord * size, (coord + 1) * size)` along each axis.
+    pub fn chunk(&mut self, coord: [i64; N]) -> &[Point<N>] {
+        if !self.cache.contains_key(&coord) {
+            let phase = Self::phase(coord);
+
+            // Earlier phases must be generated first, since we'll be generating around them
+            let mut existing = Vec::new();
+            for neighbor in Self::neighbors(coord).filter(|&n| Self::phase(n) < phase) {
+                existing.extend_from_slice(self.chunk(neighbor));
+            }
+
+            let mut bounds = ChunkBox::default();
+            let mut center = [0.0; N];
+            for i in 0..N {
+                bounds.min[i] = coord[i] as Float * self.size;
+                bounds.max[i] = bounds.min[i] + self.size;
+                center[i] = bounds.min[i] + self.size / 2.0;
+            }
+            let (min, max) = (bounds.min, bounds.max);
+            let seed = coord
+                .iter()
+                .fold(self.seed, |seed, &c| seed::derive(seed, c as u64));
+
+            let points = Poisson::<N, ChunkBox<N>, R>::new()
+                .with_radius(self.distribution.radius)
+                .with_samples(self.distribution.num_samples)
+                .with_seed(seed)
+                .with_validate(ChunkBox::contains, bounds)
+                .with_first_point(center)
+                .with_reseeding(100, min, max)
+                .merge(&existing, self.distribution.radius)
+                .split_off(existing.len());
+
+            self.cache.insert(coord, points);
+        }
+
+        &self.cache[&coord]
+    }
+
+    /// Forget all previously generated chunks
+    ///
+    /// Chunks generated afterwards are identical to before, but may take longer to generate.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
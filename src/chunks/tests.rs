@@ -0,0 +1,45 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::Poisson2D;
+
+#[test]
+fn chunks_respect_radius_across_seams() {
+    let mut chunks = Poisson2D::new().with_seed(0xBADBEEF).chunks(1.0);
+    let mut points = Vec::new();
+    for x in -1..2 {
+        for y in -1..2 {
+            let chunk = chunks.chunk([x, y]);
+            assert!(!chunk.is_empty());
+            points.extend_from_slice(chunk);
+        }
+    }
+
+    for (i, a) in points.iter().enumerate() {
+        for b in points[(i + 1)..].iter() {
+            assert!((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) >= 0.1 * 0.1);
+        }
+    }
+}
+
+#[test]
+fn chunks_are_order_independent() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+
+    let mut forward = poisson.chunks(1.0);
+    let a: Vec<_> = (0..3).map(|x| forward.chunk([x, 5]).to_vec()).collect();
+
+    let mut backward = poisson.chunks(1.0);
+    let mut b: Vec<_> = (0..3)
+        .rev()
+        .map(|x| backward.chunk([x, 5]).to_vec())
+        .collect();
+    b.reverse();
+
+    assert_eq!(a, b);
+    assert_eq!(a[1], poisson.chunk(1.0, [1, 5]));
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Convert distributions to Apache Arrow record batches and Parquet files
+
+use crate::export::axis_name;
+use crate::{Point, Poisson};
+use arrow::array::{ArrayRef, PrimitiveArray, RecordBatch};
+use arrow::datatypes::{ArrowPrimitiveType, Field, Schema, SchemaRef};
+use arrow::error::ArrowError;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// The Arrow type of our coordinates
+#[cfg(not(feature = "single_precision"))]
+type ArrowFloat = arrow::datatypes::Float64Type;
+/// The Arrow type of our coordinates
+#[cfg(feature = "single_precision")]
+type ArrowFloat = arrow::datatypes::Float32Type;
+
+/// The schema of a distribution: one column per axis, with the seed, radius, and dimensions as
+/// metadata
+pub(crate) fn schema<const N: usize, U, R>(distribution: &Poisson<N, U, R>) -> SchemaRef
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let fields: Vec<_> = (0..N)
+        .map(|i| Field::new(axis_name(i, N), ArrowFloat::DATA_TYPE, false))
+        .collect();
+
+    let mut metadata = HashMap::new();
+    if let Some(seed) = distribution.seed {
+        metadata.insert("seed".to_string(), seed.to_string());
+    }
+    metadata.insert("radius".to_string(), distribution.radius.to_string());
+    metadata.insert("dimensions".to_string(), N.to_string());
+
+    Arc::new(Schema::new_with_metadata(fields, metadata))
+}
+
+/// Build a record batch from a set of points
+pub(crate) fn record_batch<const N: usize>(
+    schema: SchemaRef,
+    points: &[Point<N>],
+) -> Result<RecordBatch, ArrowError> {
+    let columns: Vec<ArrayRef> = (0..N)
+        .map(|i| {
+            let column: PrimitiveArray<ArrowFloat> = points.iter().map(|point| point[i]).collect();
+            Arc::new(column) as ArrayRef
+        })
+        .collect();
+
+    RecordBatch::try_new(schema, columns)
+}
+
+/// Generate a distribution as a series of record batches of up to `batch_size` points each
+///
+/// Only one batch is held in memory at a time.
+pub(crate) fn record_batches<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    batch_size: usize,
+) -> impl Iterator<Item = Result<RecordBatch, ArrowError>>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let schema = schema(distribution);
+    let mut iter = distribution.iter();
+
+    std::iter::from_fn(move || {
+        let points: Vec<_> = iter.by_ref().take(batch_size.max(1)).collect();
+        if points.is_empty() {
+            None
+        } else {
+            Some(record_batch(schema.clone(), &points))
+        }
+    })
+}
+
+/// Generate a distribution, streaming it to `writer` as a Parquet file
+///
+/// Points are written in row groups of up to `batch_size` points each, so that only one batch is
+/// held in memory at a time.
+pub(crate) fn write_parquet<const N: usize, U, R, W>(
+    distribution: &Poisson<N, U, R>,
+    writer: W,
+    batch_size: usize,
+) -> Result<(), ParquetError>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    W: Write + Send,
+{
+    let mut writer = ArrowWriter::try_new(writer, schema(distribution), None)?;
+    for batch in record_batches(distribution, batch_size) {
+        writer.write(&batch?)?;
+        writer.flush()?;
+    }
+    writer.close()?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+use arrow::array::AsArray;
+
+#[test]
+fn record_batch_columns() {
+    let poisson = Poisson3D::new().with_seed(42);
+    let batch = poisson.to_record_batch().unwrap();
+    let points = poisson.generate();
+
+    assert_eq!(batch.num_rows(), points.len());
+    assert_eq!(batch.num_columns(), 3);
+    assert_eq!(batch.schema().field(2).name(), "z");
+    assert_eq!(batch.schema().metadata()["seed"], "42");
+
+    let y = batch.column(1).as_primitive::<ArrowFloat>();
+    assert!(points
+        .iter()
+        .zip(y.values().iter())
+        .all(|(p, &y)| p[1] == y));
+}
+
+#[test]
+fn batches_are_bounded() {
+    let poisson = Poisson2D::new().with_seed(42);
+    let sizes: Vec<_> = poisson
+        .record_batches(10)
+        .map(|batch| batch.unwrap().num_rows())
+        .collect();
+
+    assert!(sizes.iter().all(|&size| size <= 10));
+    assert_eq!(sizes.iter().sum::<usize>(), poisson.generate().len());
+}
+
+#[test]
+fn parquet_round_trip() {
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let poisson = Poisson2D::new().with_seed(42);
+    let path = std::env::temp_dir().join("fast_poisson_parquet_round_trip.parquet");
+    poisson
+        .write_parquet(std::fs::File::create(&path).unwrap(), 10)
+        .unwrap();
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut points = Vec::new();
+    for batch in reader {
+        let batch = batch.unwrap();
+        let x = batch.column(0).as_primitive::<ArrowFloat>();
+        let y = batch.column(1).as_primitive::<ArrowFloat>();
+        points.extend(
+            x.values()
+                .iter()
+                .zip(y.values().iter())
+                .map(|(&x, &y)| [x, y]),
+        );
+    }
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(points, poisson.generate());
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Analyze how well a set of points covers the space
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// Minimum number of probes used to search for gaps
+const MIN_PROBES: usize = 4096;
+
+/// Number of probes used to search for gaps, for each point
+const PROBES_PER_POINT: usize = 16;
+
+/// Number of rejected steps before the step size is halved while refining the largest gap
+const REFINE_ATTEMPTS: usize = 32;
+
+/// Seed used to probe the space, so that the analysis is deterministic
+const PROBE_SEED: u64 = 0xC0FE;
+
+/// How well a set of points covers the space
+///
+/// See [`Poisson::coverage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage<const N: usize> {
+    /// Whether no more points could be added without violating the radius
+    pub maximal: bool,
+    /// The center of the largest empty sphere found within the space
+    pub largest_gap: Point<N>,
+    /// The radius of the largest empty sphere found within the space
+    ///
+    /// This is infinite if there are no points at all.
+    pub largest_gap_radius: Float,
+}
+
+/// Search the space for the largest sphere not containing any of the points
+///
+/// The space is probed randomly within the reseeding box, and the best probe is then refined by
+/// hill climbing. The result is therefore a lower bound: gaps much smaller than the spacing between
+/// probes may be missed.
+pub(crate) fn analyze<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    points: &[Point<N>],
+) -> Coverage<N>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let mut tree = DefaultIndex::with_spacing(distribution.radius);
+    for point in points {
+        tree.insert(*point, 0);
+    }
+    let gap = |probe: &Point<N>| -> Float {
+        tree.nearest(*probe)
+            .map_or(Float::INFINITY, |(_, distance)| distance.sqrt())
+    };
+    let in_space = |probe: Point<N>| distribution.in_space(probe);
+
+    let [min, max] = distribution.reseed_bounds;
+    let mut rng = R::seed_from_u64(PROBE_SEED);
+
+    let mut best: Option<(Point<N>, Float)> = None;
+    for _ in 0..MIN_PROBES.max(PROBES_PER_POINT * points.len()) {
+        let mut probe = [0.0; N];
+        for (i, p) in probe.iter_mut().enumerate() {
+            *p = min[i] + (max[i] - min[i]) * rng.gen::<Float>();
+        }
+        if !in_space(probe) {
+            continue;
+        }
+
+        let radius = gap(&probe);
+        if best.map_or(true, |(_, best)| radius > best) {
+            best = Some((probe, radius));
+        }
+    }
+
+    let Some((mut center, mut radius)) = best else {
+        // We never even found the space
+        return Coverage {
+            maximal: true,
+            largest_gap: min,
+            largest_gap_radius: 0.0,
+        };
+    };
+
+    // Move the center around in ever smaller steps, for as long as the gap grows
+    let mut step = distribution.radius / 2.0;
+    let mut rejected = 0;
+    while radius.is_finite() && step > distribution.radius / 1000.0 {
+        let mut probe = center;
+        for p in probe.iter_mut() {
+            *p += step * (2.0 * rng.gen::<Float>() - 1.0);
+        }
+
+        let candidate = gap(&probe);
+        if in_space(probe) && candidate > radius {
+            center = probe;
+            radius = candidate;
+            rejected = 0;
+        } else {
+            rejected += 1;
+            if rejected == REFINE_ATTEMPTS {
+                step /= 2.0;
+                rejected = 0;
+            }
+        }
+    }
+
+    Coverage {
+        maximal: radius < distribution.radius,
+        largest_gap: center,
+        largest_gap_radius: radius,
+    }
+}
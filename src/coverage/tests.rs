@@ -0,0 +1,44 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+#[test]
+fn lattice_is_maximal() {
+    let points: Vec<_> = (0..10)
+        .flat_map(|x| (0..10).map(move |y| [x as Float / 10.0 + 0.05, y as Float / 10.0 + 0.05]))
+        .collect();
+
+    let coverage = Poisson2D::new().with_radius(0.1).coverage(&points);
+    assert!(coverage.maximal);
+    // The largest gaps are between four lattice points, or in the corners
+    let expected = (0.05 as Float).hypot(0.05);
+    assert!((coverage.largest_gap_radius - expected).abs() < 1e-3);
+}
+
+#[test]
+fn finds_hole() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let mut points = poisson.generate();
+
+    // Punch a hole in the middle
+    let hole = [0.5, 0.5];
+    points.retain(|p| (p[0] - hole[0]).hypot(p[1] - hole[1]) > 0.25);
+
+    let coverage = poisson.coverage(&points);
+    assert!(!coverage.maximal);
+    assert!(coverage.largest_gap_radius >= 0.25);
+    assert!((coverage.largest_gap[0] - hole[0]).hypot(coverage.largest_gap[1] - hole[1]) < 0.1);
+}
+
+#[test]
+fn empty_set() {
+    let coverage = Poisson2D::new().coverage(&[]);
+    assert!(!coverage.maximal);
+    assert!(coverage.largest_gap_radius.is_infinite());
+}
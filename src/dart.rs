@@ -0,0 +1,190 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Naive dart throwing, as a reference for the statistics of Poisson disk distributions
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson, Rand, Sampler};
+use rand::{Rng, SeedableRng};
+use std::iter::FusedIterator;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of consecutive rejected darts after which we give up, by default
+const DEFAULT_MAX_FAILURES: u32 = 10_000;
+
+/// A sampler throwing darts uniformly at random, keeping those that maintain the radius
+///
+/// This is the textbook definition of a Poisson disk distribution, and thus the gold standard
+/// for its statistical properties, but it is far slower than Bridson's algorithm: as the space
+/// fills up, ever more darts miss. Use it as a baseline when validating other samplers.
+///
+/// Darts are thrown within the [reseeding](Poisson::with_reseeding) box, by default the unit
+/// hypercube, and must also pass the validation function. Generation stops after a number of
+/// consecutive misses, so the distribution is close to, but not guaranteed to be, maximal.
+///
+/// ```
+/// # use fast_poisson::{Poisson2D, Sampler};
+/// let reference = Poisson2D::new().with_seed(0xBADBEEF).dart_throwing();
+/// let points = reference.generate();
+/// ```
+///
+/// See [`Poisson::dart_throwing`].
+#[derive(Debug)]
+pub struct DartThrowing<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution to throw darts into
+    distribution: Poisson<N, U, R>,
+    /// Number of consecutive rejected darts after which we give up
+    max_failures: u32,
+}
+
+impl<const N: usize, U, R> DartThrowing<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Throw darts into the specified distribution
+    pub(crate) fn new(distribution: Poisson<N, U, R>) -> Self {
+        Self {
+            distribution,
+            max_failures: DEFAULT_MAX_FAILURES,
+        }
+    }
+
+    /// Specify how many consecutive darts may miss before giving up
+    ///
+    /// More misses bring the distribution closer to maximal, at the cost of time. The default is
+    /// 10,000.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let points = Poisson2D::new()
+    ///     .dart_throwing()
+    ///     .with_max_failures(1000)
+    ///     .generate();
+    /// ```
+    #[must_use]
+    pub fn with_max_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures;
+        self
+    }
+
+    /// Specify how many consecutive darts may miss before giving up
+    ///
+    /// See [`with_max_failures`][Self::with_max_failures].
+    pub fn set_max_failures(&mut self, max_failures: u32) {
+        self.max_failures = max_failures;
+    }
+}
+
+impl<const N: usize, U, R> Clone for DartThrowing<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    fn clone(&self) -> Self {
+        Self {
+            distribution: self.distribution.clone(),
+            max_failures: self.max_failures,
+        }
+    }
+}
+
+impl<const N: usize, U, R> Sampler<N> for DartThrowing<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Iter = Darts<N, U, R>;
+
+    fn sample(&self) -> Self::Iter {
+        Darts::new(self.distribution.clone(), self.max_failures)
+    }
+}
+
+/// An iterator over the points of a [`DartThrowing`] sampler
+pub struct Darts<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution to throw darts into
+    distribution: Poisson<N, U, R>,
+    /// The RNG
+    rng: R,
+    /// Every point hit so far
+    sampled: DefaultIndex<N>,
+    /// Number of consecutive rejected darts after which we give up
+    max_failures: u32,
+}
+
+impl<const N: usize, U, R> Darts<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Start throwing darts into the specified distribution
+    fn new(distribution: Poisson<N, U, R>, max_failures: u32) -> Self {
+        let rng = distribution.rng();
+        let radius = distribution.radius;
+
+        Self {
+            distribution,
+            rng,
+            sampled: SpatialIndex::with_spacing(radius),
+            max_failures,
+        }
+    }
+
+    /// Returns true if the dart lands in our space, far enough from every point hit so far
+    fn hits(&self, point: Point<N>) -> bool {
+        let radius = self.distribution.radius_at(point);
+
+        self.distribution.in_space(point) && !self.sampled.any_within(point, radius.powi(2))
+    }
+}
+
+impl<const N: usize, U, R> Iterator for Darts<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Point<N>> {
+        let [min, max] = self.distribution.reseed_bounds;
+
+        // Once we've given up, `max_failures` is 0 so that we keep returning `None`
+        for _ in 0..self.max_failures {
+            let mut point = [0.0; N];
+            for (i, p) in point.iter_mut().enumerate() {
+                *p = min[i] + (max[i] - min[i]) * self.rng.gen::<Float>();
+            }
+
+            if self.hits(point) {
+                self.sampled.insert(point, 0);
+
+                return Some(self.distribution.transformed(point));
+            }
+        }
+        self.max_failures = 0;
+
+        None
+    }
+}
+
+impl<const N: usize, U, R> FusedIterator for Darts<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+}
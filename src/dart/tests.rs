@@ -0,0 +1,66 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::stats::verify_min_distance;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn darts_maintain_radius() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.05);
+    let points = poisson.dart_throwing().generate();
+
+    assert!(verify_min_distance(&points, 0.05));
+    assert!(points
+        .iter()
+        .all(|p| p.iter().all(|x| (0.0..1.0).contains(x))));
+
+    // Nearly as many points as Bridson's algorithm fits
+    let bridson = poisson.generate().len();
+    assert!(
+        points.len() > bridson * 9 / 10,
+        "{} vs {}",
+        points.len(),
+        bridson
+    );
+}
+
+#[test]
+fn darts_are_deterministic() {
+    let darts = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.2)
+        .dart_throwing();
+
+    assert_eq!(darts.generate(), darts.generate());
+}
+
+#[test]
+fn darts_respect_space() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(|[x, y], _| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.25, ())
+        .dart_throwing()
+        .generate();
+
+    assert!(points
+        .iter()
+        .all(|[x, y]| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.25));
+}
+
+#[test]
+fn darts_give_up() {
+    let mut darts = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(|_, _| false, ())
+        .dart_throwing()
+        .with_max_failures(10)
+        .sample();
+
+    assert_eq!(darts.next(), None);
+    assert_eq!(darts.next(), None);
+}
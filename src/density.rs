@@ -0,0 +1,142 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Vary the radius of a distribution across space
+
+use crate::{Float, Point};
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// A function giving the desired density of points across space, and the range of radii it maps
+/// onto
+#[derive(Clone)]
+pub(crate) struct Density<const N: usize> {
+    /// The density at each point, from 0 to 1
+    func: Arc<dyn Fn(Point<N>) -> Float + Send + Sync>,
+    /// The radius where the density is 1
+    min_radius: Float,
+    /// The largest radius, where the density is (close to) 0
+    max_radius: Float,
+}
+
+impl<const N: usize> Density<N> {
+    /// Create a density from a function
+    pub(crate) fn new<F>(func: F, min_radius: Float, max_radius: Float) -> Self
+    where
+        F: Fn(Point<N>) -> Float + Send + Sync + 'static,
+    {
+        Self {
+            func: Arc::new(func),
+            min_radius,
+            max_radius,
+        }
+    }
+
+    /// The density at the given point, from 0 to 1
+    pub(crate) fn density(&self, point: Point<N>) -> Float {
+        (self.func)(point).clamp(0.0, 1.0)
+    }
+
+    /// The radius around the given point
+    ///
+    /// The number of points per unit of volume is proportional to the density, so the radius
+    /// scales with the inverse of its `N`-th root, limited to the maximum radius.
+    pub(crate) fn radius(&self, point: Point<N>) -> Float {
+        let density = self.density(point);
+        if density <= 0.0 {
+            return self.max_radius;
+        }
+
+        (self.min_radius / density.powf(1.0 / N as Float)).min(self.max_radius)
+    }
+}
+
+/// Interpolate a grid of densities spanning the box from `min` to `max`
+///
+/// `data` holds the density of each cell, with the first axis varying fastest, as in the rows of
+/// an image. Each value lies at the center of its cell, and between the centers the densities are
+/// interpolated multilinearly; beyond the outermost centers the outermost values extend outward.
+///
+/// # Panics
+///
+/// Panics if any of the `dims` is 0, or if `data` doesn't hold exactly one value for each cell.
+pub(crate) fn grid<const N: usize>(
+    data: Vec<Float>,
+    dims: [usize; N],
+    [min, max]: [Point<N>; 2],
+) -> impl Fn(Point<N>) -> Float + Send + Sync + 'static {
+    assert!(
+        dims.iter().all(|&d| d > 0),
+        "every dimension must be at least 1"
+    );
+    assert_eq!(
+        data.len(),
+        dims.iter().product::<usize>(),
+        "the data must hold one value for each cell"
+    );
+
+    move |point| {
+        // The cell centers to either side along each axis, and how far along from the lower one
+        let mut lower = [0; N];
+        let mut fraction = [0.0; N];
+        for i in 0..N {
+            let cell = (point[i] - min[i]) / (max[i] - min[i]) * dims[i] as Float - 0.5;
+            let cell = cell.clamp(0.0, (dims[i] - 1) as Float);
+            lower[i] = (cell.floor() as usize).min(dims[i] - 1);
+            fraction[i] = cell - lower[i] as Float;
+        }
+
+        // Weigh the cells at every corner around the point
+        let mut density = 0.0;
+        for corner in 0..1usize << N {
+            let mut index = 0;
+            let mut stride = 1;
+            let mut weight = 1.0;
+            for i in 0..N {
+                let upper = corner >> i & 1 == 1;
+                let cell = if upper {
+                    (lower[i] + 1).min(dims[i] - 1)
+                } else {
+                    lower[i]
+                };
+                weight *= if upper {
+                    fraction[i]
+                } else {
+                    1.0 - fraction[i]
+                };
+                index += cell * stride;
+                stride *= dims[i];
+            }
+            if weight > 0.0 {
+                density += weight * data[index];
+            }
+        }
+
+        density
+    }
+}
+
+impl<const N: usize> fmt::Debug for Density<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Density")
+            .field("min_radius", &self.min_radius)
+            .field("max_radius", &self.max_radius)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Densities are only equal if they share the same function
+impl<const N: usize> PartialEq for Density<N> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.func, &other.func)
+            && self.min_radius == other.min_radius
+            && self.max_radius == other.max_radius
+    }
+}
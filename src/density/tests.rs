@@ -0,0 +1,94 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+#[test]
+fn radius_follows_density() {
+    let density = Density::new(|[x, _]: Point<2>| x, 0.01, 0.1);
+
+    assert_eq!(density.radius([1.0, 0.0]), 0.01);
+    assert!((density.radius([0.25, 0.0]) - 0.02).abs() < 1e-6);
+    assert_eq!(density.radius([0.0, 0.0]), 0.1);
+    assert_eq!(density.radius([0.001, 0.0]), 0.1);
+}
+
+#[test]
+fn denser_where_density_is_higher() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_density(|[x, _]| if x < 0.5 { 0.25 } else { 1.0 }, 0.02, 0.2)
+        .generate();
+
+    let left = points.iter().filter(|p| p[0] < 0.5).count() as Float;
+    let right = points.iter().filter(|p| p[0] >= 0.5).count() as Float;
+
+    // Four times the density on the right
+    assert!((right / left - 4.0).abs() < 1.0, "{} vs {}", left, right);
+    // Points are spaced according to where they are
+    let radius = |p: &Point<2>| -> Float {
+        if p[0] < 0.5 {
+            0.04
+        } else {
+            0.02
+        }
+    };
+    for (i, a) in points.iter().enumerate() {
+        for b in points[i + 1..].iter() {
+            let distance = (a[0] - b[0]).hypot(a[1] - b[1]);
+            assert!(distance >= radius(a).min(radius(b)) - 1e-9);
+        }
+    }
+}
+
+#[test]
+fn grid_interpolates_between_centers() {
+    let grid = grid(vec![0.0, 1.0, 0.5, 0.5], [2, 2], [[0.0; 2], [2.0; 2]]);
+
+    // Cell centers hold their own values
+    assert_eq!(grid([0.5, 0.5]), 0.0);
+    assert_eq!(grid([1.5, 0.5]), 1.0);
+    assert_eq!(grid([0.5, 1.5]), 0.5);
+    // Midway along the first axis, then between all four cells
+    assert!((grid([1.0, 0.5]) - 0.5).abs() < 1e-6);
+    assert!((grid([1.0, 1.0]) - 0.5).abs() < 1e-6);
+    assert!((grid([1.25, 0.5]) - 0.75).abs() < 1e-6);
+    // Outermost values extend to the edges and beyond
+    assert_eq!(grid([0.0, 0.0]), 0.0);
+    assert_eq!(grid([5.0, -1.0]), 1.0);
+}
+
+#[test]
+fn grid_in_three_dimensions() {
+    let data: Vec<Float> = (0..8).map(|i| i as Float / 7.0).collect();
+    let grid = grid(data, [2, 2, 2], [[0.0; 3], [1.0; 3]]);
+
+    assert!((grid([0.75, 0.75, 0.75]) - 1.0).abs() < 1e-6);
+    assert!((grid([0.5, 0.5, 0.5]) - 0.5).abs() < 1e-6);
+    // The third axis has the largest stride
+    assert!((grid([0.25, 0.25, 0.75]) - 4.0 / 7.0).abs() < 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn grid_must_match_dims() {
+    let _ = grid(vec![0.0; 5], [2, 3], [[0.0; 2], [1.0; 2]]);
+}
+
+#[test]
+fn denser_where_grid_is_higher() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_bounds([0.0, 0.0], [2.0, 1.0])
+        .with_density_grid(vec![0.25, 1.0], [2, 1], 0.02, 0.2)
+        .generate();
+
+    let left = points.iter().filter(|p| p[0] < 1.0).count();
+    let right = points.iter().filter(|p| p[0] >= 1.0).count();
+    assert!(right > 2 * left, "{} vs {}", left, right);
+}
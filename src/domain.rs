@@ -0,0 +1,769 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Shapes of space to sample, with built-in boxes, balls, annuli, simplices, and convex hulls
+//!
+//! A [`Domain`] describes the space to sample more fully than a validation function: besides
+//! whether it contains a point, it knows its bounding box and how to find a first point within
+//! it. Pass one to [`Poisson::with_domain`](crate::Poisson::with_domain).
+//!
+//! ```
+//! # use fast_poisson::Poisson2D;
+//! use fast_poisson::domain::Ball;
+//!
+//! let points = Poisson2D::new()
+//!     .with_domain(Ball::new([0.0, 0.0], 10.0))
+//!     .with_radius(0.5)
+//!     .generate();
+//!
+//! assert!(points.iter().all(|[x, y]| x * x + y * y < 100.0));
+//! ```
+//!
+//! Domains can be combined with [`union`](Domain::union),
+//! [`intersection`](Domain::intersection), and [`difference`](Domain::difference):
+//!
+//! ```
+//! # use fast_poisson::Poisson2D;
+//! use fast_poisson::domain::{Ball, Cuboid, Domain};
+//!
+//! // A box with two round holes
+//! let region = Cuboid::new([0.0, 0.0], [10.0, 10.0])
+//!     .difference(Ball::new([3.0, 3.0], 2.0))
+//!     .difference(Ball::new([7.0, 7.0], 2.0));
+//!
+//! let points = Poisson2D::new()
+//!     .with_domain(region)
+//!     .with_radius(0.5)
+//!     .generate();
+//!
+//! assert!(points.iter().all(|&p| region.contains(p)));
+//! ```
+
+use crate::{Float, Point};
+use rand::Rng;
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Attempts to find a point within a domain by rejection before falling back on its center
+const INITIAL_ATTEMPTS: u32 = 64;
+
+/// A region of space to sample
+///
+/// Implement this for your own shapes, or use the built-in [`Cuboid`], [`Ball`], [`Annulus`],
+/// [`Simplex`], and [`ConvexHull`], and combine them.
+pub trait Domain<const N: usize> {
+    /// Returns true if the point lies within the domain
+    fn contains(&self, point: Point<N>) -> bool;
+
+    /// The smallest axis-aligned box, as `[min, max]`, enclosing the domain
+    fn bounding_box(&self) -> [Point<N>; 2];
+
+    /// A random point within the domain, from which generation starts
+    ///
+    /// By default, points are drawn from the bounding box until one lies within the domain; if
+    /// none is found after a few attempts, the center of the bounding box is returned instead.
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        rejection_sample(self, rng)
+    }
+
+    /// The points within either this domain or the other
+    ///
+    /// Generation grows outward from a single point, so the parts of a union that don't touch
+    /// are only all filled with [reseeding](crate::Poisson::with_reseeding).
+    #[must_use]
+    fn union<D: Domain<N>>(self, other: D) -> Union<Self, D>
+    where
+        Self: Sized,
+    {
+        Union(self, other)
+    }
+
+    /// The points within both this domain and the other
+    #[must_use]
+    fn intersection<D: Domain<N>>(self, other: D) -> Intersection<Self, D>
+    where
+        Self: Sized,
+    {
+        Intersection(self, other)
+    }
+
+    /// The points within this domain but not the other
+    #[must_use]
+    fn difference<D: Domain<N>>(self, other: D) -> Difference<Self, D>
+    where
+        Self: Sized,
+    {
+        Difference(self, other)
+    }
+}
+
+/// Draw points from the bounding box of a domain until one lies within it, or else return the
+/// center of the box
+fn rejection_sample<const N: usize, D, G>(domain: &D, rng: &mut G) -> Point<N>
+where
+    D: Domain<N> + ?Sized,
+    G: Rng + ?Sized,
+{
+    let [min, max] = domain.bounding_box();
+
+    for _ in 0..INITIAL_ATTEMPTS {
+        let point = std::array::from_fn(|i| min[i] + (max[i] - min[i]) * rng.gen::<Float>());
+        if domain.contains(point) {
+            return point;
+        }
+    }
+
+    std::array::from_fn(|i| (min[i] + max[i]) / 2.0)
+}
+
+/// The union of two domains
+///
+/// See [`Domain::union`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Union<A, B>(pub A, pub B);
+
+impl<const N: usize, A: Domain<N>, B: Domain<N>> Domain<N> for Union<A, B> {
+    fn contains(&self, point: Point<N>) -> bool {
+        self.0.contains(point) || self.1.contains(point)
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        let ([a_min, a_max], [b_min, b_max]) = (self.0.bounding_box(), self.1.bounding_box());
+
+        [
+            std::array::from_fn(|i| a_min[i].min(b_min[i])),
+            std::array::from_fn(|i| a_max[i].max(b_max[i])),
+        ]
+    }
+
+    /// The initial point of the first domain
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        self.0.initial_point(rng)
+    }
+}
+
+/// The intersection of two domains
+///
+/// See [`Domain::intersection`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Intersection<A, B>(pub A, pub B);
+
+impl<const N: usize, A: Domain<N>, B: Domain<N>> Domain<N> for Intersection<A, B> {
+    fn contains(&self, point: Point<N>) -> bool {
+        self.0.contains(point) && self.1.contains(point)
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        let ([a_min, a_max], [b_min, b_max]) = (self.0.bounding_box(), self.1.bounding_box());
+
+        [
+            std::array::from_fn(|i| a_min[i].max(b_min[i])),
+            std::array::from_fn(|i| a_max[i].min(b_max[i])),
+        ]
+    }
+}
+
+/// The difference of two domains
+///
+/// See [`Domain::difference`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Difference<A, B>(pub A, pub B);
+
+impl<const N: usize, A: Domain<N>, B: Domain<N>> Domain<N> for Difference<A, B> {
+    fn contains(&self, point: Point<N>) -> bool {
+        self.0.contains(point) && !self.1.contains(point)
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        self.0.bounding_box()
+    }
+
+    /// An initial point of the first domain outside of the second, if one is found
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        for _ in 0..INITIAL_ATTEMPTS {
+            let point = self.0.initial_point(rng);
+            if !self.1.contains(point) {
+                return point;
+            }
+        }
+
+        rejection_sample(self, rng)
+    }
+}
+
+/// An axis-aligned box, including its lowest corner but not its highest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cuboid<const N: usize> {
+    /// The lowest corner
+    min: Point<N>,
+    /// The highest corner
+    max: Point<N>,
+}
+
+impl<const N: usize> Cuboid<N> {
+    /// The box from `min` to `max`
+    #[must_use]
+    pub fn new(min: Point<N>, max: Point<N>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<const N: usize> Default for Cuboid<N> {
+    /// The unit hypercube
+    fn default() -> Self {
+        Self::new([0.0; N], [1.0; N])
+    }
+}
+
+impl<const N: usize> Domain<N> for Cuboid<N> {
+    fn contains(&self, point: Point<N>) -> bool {
+        (0..N).all(|i| (self.min[i]..self.max[i]).contains(&point[i]))
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        [self.min, self.max]
+    }
+}
+
+/// A ball, including all points nearer to its center than its radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ball<const N: usize> {
+    /// The center
+    center: Point<N>,
+    /// The radius
+    radius: Float,
+}
+
+impl<const N: usize> Ball<N> {
+    /// The ball around `center` of the given radius
+    #[must_use]
+    pub fn new(center: Point<N>, radius: Float) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<const N: usize> Default for Ball<N> {
+    /// The ball inscribed in the unit hypercube
+    fn default() -> Self {
+        Self::new([0.5; N], 0.5)
+    }
+}
+
+impl<const N: usize> Domain<N> for Ball<N> {
+    fn contains(&self, point: Point<N>) -> bool {
+        squared_distance(point, self.center) < self.radius.powi(2)
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        [
+            self.center.map(|c| c - self.radius),
+            self.center.map(|c| c + self.radius),
+        ]
+    }
+}
+
+/// The shell between two concentric balls, including points at the inner radius but not the outer
+///
+/// In 2 dimensions this is a ring.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Annulus<const N: usize> {
+    /// The center
+    center: Point<N>,
+    /// The radius of the hole
+    inner: Float,
+    /// The radius of the outer edge
+    outer: Float,
+}
+
+impl<const N: usize> Annulus<N> {
+    /// The shell around `center` from radius `inner` to `outer`
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= inner < outer`.
+    #[must_use]
+    pub fn new(center: Point<N>, inner: Float, outer: Float) -> Self {
+        assert!(
+            0.0 <= inner && inner < outer,
+            "annulus radii must satisfy 0 <= inner < outer"
+        );
+
+        Self {
+            center,
+            inner,
+            outer,
+        }
+    }
+}
+
+impl<const N: usize> Default for Annulus<N> {
+    /// The ring inscribed in the unit hypercube, with a hole half as wide
+    fn default() -> Self {
+        Self::new([0.5; N], 0.25, 0.5)
+    }
+}
+
+impl<const N: usize> Domain<N> for Annulus<N> {
+    fn contains(&self, point: Point<N>) -> bool {
+        (self.inner.powi(2)..self.outer.powi(2)).contains(&squared_distance(point, self.center))
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        [
+            self.center.map(|c| c - self.outer),
+            self.center.map(|c| c + self.outer),
+        ]
+    }
+
+    /// A point midway through the shell, in a random direction
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        let mid = (self.inner + self.outer) / 2.0;
+        let direction = loop {
+            let v: Point<N> = std::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+            let length = squared_distance(v, [0.0; N]).sqrt();
+            if (0.1..=1.0).contains(&length) {
+                break v.map(|x| x / length);
+            }
+        };
+
+        std::array::from_fn(|i| self.center[i] + mid * direction[i])
+    }
+}
+
+/// A simplex, the convex hull of `N + 1` points: a triangle in 2 dimensions, a tetrahedron in 3
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simplex<const N: usize> {
+    /// The first vertex, from which the edges to the others are measured
+    origin: Point<N>,
+    /// Matrix whose columns are the edges from `origin` to the other vertices
+    edges: [[Float; N]; N],
+    /// Inverse of `edges`, mapping points to their barycentric coordinates
+    inverse: [[Float; N]; N],
+    /// The bounding box
+    bounds: [Point<N>; 2],
+}
+
+impl<const N: usize> Simplex<N> {
+    /// The simplex with the given `N + 1` vertices
+    ///
+    /// # Panics
+    ///
+    /// Panics unless exactly `N + 1` vertices are given, or if they are degenerate, such that the
+    /// simplex has no volume.
+    #[must_use]
+    pub fn new(vertices: &[Point<N>]) -> Self {
+        assert_eq!(vertices.len(), N + 1, "a simplex needs N + 1 vertices");
+
+        let origin = vertices[0];
+        let edges: [[Float; N]; N] = std::array::from_fn(|row| {
+            std::array::from_fn(|col| vertices[col + 1][row] - origin[row])
+        });
+        let inverse = invert(edges).expect("simplex must not be degenerate");
+
+        let mut bounds = [origin, origin];
+        for vertex in &vertices[1..] {
+            for i in 0..N {
+                bounds[0][i] = bounds[0][i].min(vertex[i]);
+                bounds[1][i] = bounds[1][i].max(vertex[i]);
+            }
+        }
+
+        Self {
+            origin,
+            edges,
+            inverse,
+            bounds,
+        }
+    }
+
+    /// The barycentric coordinates of a point, relative to every vertex but the first
+    fn barycentric(&self, point: Point<N>) -> [Float; N] {
+        std::array::from_fn(|row| {
+            (0..N)
+                .map(|col| self.inverse[row][col] * (point[col] - self.origin[col]))
+                .sum()
+        })
+    }
+}
+
+impl<const N: usize> Default for Simplex<N> {
+    /// The corner of the unit hypercube cut off by the plane through its neighbors of the origin
+    fn default() -> Self {
+        let mut vertices = vec![[0.0; N]];
+        for i in 0..N {
+            let mut vertex = [0.0; N];
+            vertex[i] = 1.0;
+            vertices.push(vertex);
+        }
+
+        Self::new(&vertices)
+    }
+}
+
+impl<const N: usize> Domain<N> for Simplex<N> {
+    fn contains(&self, point: Point<N>) -> bool {
+        let weights = self.barycentric(point);
+
+        weights.iter().all(|&w| w >= 0.0) && weights.iter().sum::<Float>() <= 1.0
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        self.bounds
+    }
+
+    /// A point drawn uniformly from within the simplex
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        // Normalized exponential variates are uniformly distributed barycentric coordinates
+        let mut weights = [0.0; N];
+        let mut total: Float = -(1.0 - rng.gen::<Float>()).ln();
+        for w in weights.iter_mut() {
+            *w = -(1.0 - rng.gen::<Float>()).ln();
+            total += *w;
+        }
+
+        std::array::from_fn(|row| {
+            self.origin[row]
+                + (0..N)
+                    .map(|col| self.edges[row][col] * weights[col] / total)
+                    .sum::<Float>()
+        })
+    }
+}
+
+/// The convex hull of a set of points, in any number of dimensions
+///
+/// This restricts sampling to a region known only as a cloud of points, such as a scanned or
+/// simulated volume. The hull is computed once, as the facets bounding it, so testing whether it
+/// contains a point takes time proportional to the number of facets. This grows quickly with the
+/// number of dimensions, so in many dimensions a hull of fewer points is much cheaper to sample.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// use fast_poisson::domain::{ConvexHull, Domain};
+///
+/// let scan = [[0.0, 0.0], [4.0, 1.0], [1.0, 1.0], [3.0, 4.0], [0.5, 3.0]];
+/// let hull = ConvexHull::new(&scan);
+/// assert!(hull.contains([2.0, 2.0]));
+/// assert!(!hull.contains([4.0, 4.0]));
+///
+/// let points = Poisson2D::new()
+///     .with_domain(hull.clone())
+///     .with_radius(0.2)
+///     .generate();
+///
+/// assert!(points.iter().all(|&p| hull.contains(p)));
+/// ```
+///
+/// See also [`Poisson::with_convex_hull`](crate::Poisson::with_convex_hull).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull<const N: usize> {
+    /// The outward unit normal and offset of each facet, such that points within the hull lie at
+    /// or below every offset along its normal
+    facets: Vec<(Point<N>, Float)>,
+    /// A point strictly within the hull
+    interior: Point<N>,
+    /// The bounding box
+    bounds: [Point<N>; 2],
+}
+
+impl<const N: usize> ConvexHull<N> {
+    /// The convex hull of the given points
+    ///
+    /// Points within the hull are simply skipped, so they needn't be removed first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the points are degenerate, such that their hull has no volume: there must be at
+    /// least `N + 1` points, not all within the same hyperplane.
+    #[must_use]
+    pub fn new(points: &[Point<N>]) -> Self {
+        let mut bounds = [[Float::INFINITY; N], [Float::NEG_INFINITY; N]];
+        for point in points {
+            for i in 0..N {
+                bounds[0][i] = bounds[0][i].min(point[i]);
+                bounds[1][i] = bounds[1][i].max(point[i]);
+            }
+        }
+
+        // Points this close to a facet are taken to lie within it
+        let extent = (0..N).fold(0.0, |max: Float, i| max.max(bounds[1][i] - bounds[0][i]));
+        let tolerance = extent * Float::EPSILON.sqrt();
+
+        let simplex =
+            initial_simplex(points, tolerance).expect("convex hull must not be degenerate");
+        let mut interior = [0.0; N];
+        for &vertex in &simplex {
+            for (c, x) in interior.iter_mut().zip(points[vertex]) {
+                *c += x / (N + 1) as Float;
+            }
+        }
+
+        let mut facets: Vec<Facet<N>> = (0..=N)
+            .map(|skip| {
+                let vertices = simplex
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != skip)
+                    .map(|(_, &vertex)| vertex)
+                    .collect();
+                Facet::new(points, vertices, interior)
+            })
+            .collect();
+
+        // Grow the hull one point at a time, replacing the facets each point lies beyond with
+        // facets joining it to their horizon
+        for (i, &point) in points.iter().enumerate() {
+            let visible: Vec<bool> = facets
+                .iter()
+                .map(|facet| facet.distance(point) > tolerance)
+                .collect();
+            if !visible.contains(&true) {
+                continue;
+            }
+
+            // Every ridge is shared by two facets, so those of only one visible facet lie on the
+            // horizon
+            let mut ridges = BTreeMap::new();
+            for facet in facets
+                .iter()
+                .zip(&visible)
+                .filter(|(_, &v)| v)
+                .map(|(f, _)| f)
+            {
+                for skip in 0..N {
+                    let mut ridge = facet.vertices.clone();
+                    ridge.remove(skip);
+                    ridge.sort_unstable();
+                    *ridges.entry(ridge).or_insert(0) += 1;
+                }
+            }
+
+            let mut visible = visible.into_iter();
+            facets.retain(|_| !visible.next().unwrap_or(false));
+            for (mut ridge, count) in ridges {
+                if count == 1 {
+                    ridge.push(i);
+                    facets.push(Facet::new(points, ridge, interior));
+                }
+            }
+        }
+
+        Self {
+            facets: facets
+                .into_iter()
+                .map(|facet| (facet.normal, facet.offset))
+                .collect(),
+            interior,
+            bounds,
+        }
+    }
+}
+
+impl<const N: usize> Default for ConvexHull<N> {
+    /// The corner of the unit hypercube cut off by the plane through its neighbors of the origin
+    fn default() -> Self {
+        let mut vertices = vec![[0.0; N]];
+        for i in 0..N {
+            let mut vertex = [0.0; N];
+            vertex[i] = 1.0;
+            vertices.push(vertex);
+        }
+
+        Self::new(&vertices)
+    }
+}
+
+impl<const N: usize> Domain<N> for ConvexHull<N> {
+    fn contains(&self, point: Point<N>) -> bool {
+        self.facets
+            .iter()
+            .all(|&(normal, offset)| dot(normal, point) <= offset)
+    }
+
+    fn bounding_box(&self) -> [Point<N>; 2] {
+        self.bounds
+    }
+
+    /// A point drawn from the bounding box, or else a point within the hull
+    fn initial_point<G: Rng + ?Sized>(&self, rng: &mut G) -> Point<N> {
+        let point = rejection_sample(self, rng);
+        if self.contains(point) {
+            point
+        } else {
+            self.interior
+        }
+    }
+}
+
+/// A facet of a convex hull under construction
+struct Facet<const N: usize> {
+    /// The indices of its `N` vertices
+    vertices: Vec<usize>,
+    /// The outward unit normal
+    normal: Point<N>,
+    /// The distance of the facet from the origin, along its normal
+    offset: Float,
+}
+
+impl<const N: usize> Facet<N> {
+    /// The facet through the given vertices, facing away from `interior`
+    fn new(points: &[Point<N>], vertices: Vec<usize>, interior: Point<N>) -> Self {
+        let origin = points[vertices[0]];
+        let edges: Vec<Point<N>> = vertices[1..]
+            .iter()
+            .map(|&vertex| std::array::from_fn(|i| points[vertex][i] - origin[i]))
+            .collect();
+
+        // The generalized cross product of the edges is perpendicular to all of them
+        let mut normal: Point<N> = std::array::from_fn(|col| {
+            let minor = edges
+                .iter()
+                .map(|edge| (0..N).filter(|&i| i != col).map(|i| edge[i]).collect())
+                .collect();
+            let sign = if col % 2 == 0 { 1.0 } else { -1.0 };
+            sign * determinant(minor)
+        });
+        let length = dot(normal, normal).sqrt();
+        normal = normal.map(|x| x / length);
+
+        let mut offset = dot(normal, origin);
+        if dot(normal, interior) > offset {
+            normal = normal.map(|x| -x);
+            offset = -offset;
+        }
+
+        Self {
+            vertices,
+            normal,
+            offset,
+        }
+    }
+
+    /// The signed distance of the point beyond the facet
+    fn distance(&self, point: Point<N>) -> Float {
+        dot(self.normal, point) - self.offset
+    }
+}
+
+/// The indices of `N + 1` points spanning as much volume as can be found greedily, or `None` if
+/// they all lie within a hyperplane
+fn initial_simplex<const N: usize>(points: &[Point<N>], tolerance: Float) -> Option<Vec<usize>> {
+    let first = *points.first()?;
+    let mut simplex = vec![0];
+    let mut basis: Vec<Point<N>> = Vec::new();
+
+    while simplex.len() <= N {
+        // The point furthest from the span of the simplex so far
+        let (vertex, residual, length) = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let mut residual: Point<N> = std::array::from_fn(|j| point[j] - first[j]);
+                for axis in &basis {
+                    let projection = dot(residual, *axis);
+                    for (r, a) in residual.iter_mut().zip(axis) {
+                        *r -= projection * a;
+                    }
+                }
+                (i, residual, dot(residual, residual).sqrt())
+            })
+            .max_by(|a, b| a.2.total_cmp(&b.2))?;
+
+        if length <= tolerance {
+            return None;
+        }
+        basis.push(residual.map(|r| r / length));
+        simplex.push(vertex);
+    }
+
+    Some(simplex)
+}
+
+/// The determinant of a square matrix, by Gaussian elimination
+fn determinant(mut matrix: Vec<Vec<Float>>) -> Float {
+    let n = matrix.len();
+    let mut det = 1.0;
+
+    for col in 0..n {
+        // Pivot on the largest entry, for numerical stability
+        let pivot = (col..n)
+            .max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))
+            .unwrap_or(col);
+        if matrix[pivot][col] == 0.0 {
+            return 0.0;
+        }
+        if pivot != col {
+            matrix.swap(col, pivot);
+            det = -det;
+        }
+        det *= matrix[col][col];
+
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+        }
+    }
+
+    det
+}
+
+/// The dot product of two vectors
+fn dot<const N: usize>(a: Point<N>, b: Point<N>) -> Float {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Squared distance between two points
+fn squared_distance<const N: usize>(a: Point<N>, b: Point<N>) -> Float {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+/// Invert a matrix by Gauss-Jordan elimination, or `None` if it is singular
+fn invert<const N: usize>(matrix: [[Float; N]; N]) -> Option<[[Float; N]; N]> {
+    // Pivots this much smaller than the largest entry are taken to be zero
+    let tolerance = matrix
+        .iter()
+        .flatten()
+        .fold(0.0, |max: Float, m| max.max(m.abs()))
+        * N as Float
+        * Float::EPSILON;
+
+    let mut m = matrix;
+    let mut inverse: [[Float; N]; N] =
+        std::array::from_fn(|row| std::array::from_fn(|col| if row == col { 1.0 } else { 0.0 }));
+
+    for col in 0..N {
+        // Pivot on the largest entry, for numerical stability
+        let pivot = (col..N).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col].abs() <= tolerance {
+            return None;
+        }
+        m.swap(col, pivot);
+        inverse.swap(col, pivot);
+
+        let scale = m[col][col];
+        for i in 0..N {
+            m[col][i] /= scale;
+            inverse[col][i] /= scale;
+        }
+
+        for row in (0..N).filter(|&row| row != col) {
+            let factor = m[row][col];
+            for i in 0..N {
+                m[row][i] -= factor * m[col][i];
+                inverse[row][i] -= factor * inverse[col][i];
+            }
+        }
+    }
+
+    Some(inverse)
+}
@@ -0,0 +1,251 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D, Rand};
+use rand::{Rng, SeedableRng};
+
+/// Generate within a domain, checking every point lies within it
+fn generate_within<D>(domain: D, radius: Float) -> Vec<Point<2>>
+where
+    D: Domain<2> + Default + Clone,
+{
+    let points = Poisson2D::new()
+        .with_domain(domain.clone())
+        .with_radius(radius)
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert!(points.iter().all(|&p| domain.contains(p)));
+    assert!(crate::stats::verify_min_distance(&points, radius));
+
+    points
+}
+
+#[test]
+fn cuboid() {
+    let cuboid = Cuboid::new([-3.0, 2.0], [-1.0, 3.0]);
+    assert!(cuboid.contains([-3.0, 2.0]));
+    assert!(!cuboid.contains([-1.0, 2.5]));
+
+    let points = generate_within(cuboid, 0.1);
+    assert!(points.len() > 100);
+}
+
+#[test]
+fn ball() {
+    let ball = Ball::new([1.0, 1.0], 2.0);
+    assert_eq!(ball.bounding_box(), [[-1.0, -1.0], [3.0, 3.0]]);
+    assert!(!ball.contains([2.5, 2.5]));
+
+    generate_within(ball, 0.2);
+}
+
+#[test]
+fn annulus() {
+    let annulus = Annulus::new([0.0, 0.0], 4.0, 5.0);
+    assert!(annulus.contains([4.0, 0.0]));
+    assert!(!annulus.contains([0.0, 0.0]));
+
+    // The ring is narrow, so the first point must come from the domain
+    let points = generate_within(annulus, 0.2);
+    assert!(points.len() > 200);
+
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    for _ in 0..100 {
+        assert!(annulus.contains(annulus.initial_point(&mut rng)));
+    }
+}
+
+#[test]
+#[should_panic]
+fn inverted_annulus() {
+    let _ = Annulus::new([0.0, 0.0], 5.0, 4.0);
+}
+
+#[test]
+fn simplex() {
+    let triangle = Simplex::new(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]]);
+    assert_eq!(triangle.bounding_box(), [[0.0, 0.0], [4.0, 3.0]]);
+    assert!(triangle.contains([1.0, 1.0]));
+    assert!(!triangle.contains([3.0, 2.0]));
+    assert!(!triangle.contains([-0.1, 1.0]));
+
+    generate_within(triangle, 0.1);
+
+    let tetrahedron = Simplex::<3>::default();
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    for _ in 0..100 {
+        assert!(tetrahedron.contains(tetrahedron.initial_point(&mut rng)));
+    }
+
+    let points = Poisson3D::new()
+        .with_domain(tetrahedron)
+        .with_seed(0xBADBEEF)
+        .generate();
+    assert!(points.iter().all(|&p| tetrahedron.contains(p)));
+}
+
+#[test]
+#[should_panic]
+fn degenerate_simplex() {
+    let _ = Simplex::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+}
+
+#[test]
+fn validation_within_domain() {
+    let ball = Ball::new([0.0, 0.0], 1.0);
+    let points = Poisson2D::new()
+        .with_domain(ball)
+        .with_validate(|p, ball| ball.contains(p) && p[0] > 0.0, ball)
+        .with_first_point([0.5, 0.0])
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|&[x, y]| x > 0.0 && x * x + y * y < 1.0));
+}
+
+#[test]
+fn combinators() {
+    let a = Ball::new([0.0, 0.0], 1.0);
+    let b = Ball::new([1.0, 0.0], 1.0);
+
+    let union = a.union(b);
+    assert!(union.contains([-0.9, 0.0]) && union.contains([1.9, 0.0]));
+    assert_eq!(union.bounding_box(), [[-1.0, -1.0], [2.0, 1.0]]);
+
+    let intersection = a.intersection(b);
+    assert!(intersection.contains([0.5, 0.0]));
+    assert!(!intersection.contains([-0.5, 0.0]));
+    assert_eq!(intersection.bounding_box(), [[0.0, -1.0], [1.0, 1.0]]);
+
+    let difference = a.difference(b);
+    assert!(difference.contains([-0.5, 0.0]));
+    assert!(!difference.contains([0.5, 0.0]));
+    assert_eq!(difference.bounding_box(), a.bounding_box());
+
+    generate_within(union, 0.1);
+    generate_within(intersection, 0.05);
+    generate_within(difference, 0.05);
+}
+
+#[test]
+fn box_minus_two_balls() {
+    let region = Cuboid::new([0.0, 0.0], [10.0, 10.0])
+        .difference(Ball::new([3.0, 3.0], 2.0))
+        .difference(Ball::new([7.0, 7.0], 2.0));
+
+    // The first point is never drawn from within a hole
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    for _ in 0..100 {
+        assert!(region.contains(region.initial_point(&mut rng)));
+    }
+
+    let points = generate_within(region, 0.5);
+    let full = generate_within(Cuboid::new([0.0, 0.0], [10.0, 10.0]), 0.5);
+    assert!(points.len() < full.len());
+}
+
+#[test]
+fn convex_hull() {
+    // A quadrilateral, with points inside and along its edges
+    let hull = ConvexHull::new(&[
+        [1.0, 1.0],
+        [0.0, 0.0],
+        [4.0, 0.0],
+        [2.0, 0.0],
+        [2.0, 1.0],
+        [4.0, 3.0],
+        [0.0, 3.0],
+        [0.0, 3.0],
+    ]);
+    assert_eq!(hull.bounding_box(), [[0.0, 0.0], [4.0, 3.0]]);
+    assert_eq!(hull.facets.len(), 4);
+    assert!(hull.contains([1.0, 2.0]));
+    assert!(hull.contains([3.9, 0.1]));
+    assert!(!hull.contains([-0.1, 1.0]));
+    assert!(!hull.contains([2.0, 3.1]));
+
+    let points = generate_within(hull, 0.1);
+    assert!(points.len() > 300);
+}
+
+#[test]
+fn convex_hull_of_a_cloud() {
+    // A cloud of points around a ball in 4 dimensions
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    let cloud: Vec<Point<4>> = (0..200)
+        .map(|_| std::array::from_fn(|_| rng.gen::<Float>() - 0.5))
+        .filter(|&p| squared_distance(p, [0.0; 4]) < 0.25)
+        .collect();
+    let hull = ConvexHull::new(&cloud);
+
+    // Mixtures of points of the cloud lie within the hull
+    for _ in 0..100 {
+        let [a, b, c] = [0; 3].map(|_| cloud[rng.gen_range(0..cloud.len())]);
+        let mixture = std::array::from_fn(|i| 0.99 * (a[i] + b[i] + c[i]) / 3.0);
+        assert!(hull.contains(mixture));
+    }
+
+    // Points past the furthest point of the cloud in any direction don't
+    for _ in 0..100 {
+        let direction: Point<4> = std::array::from_fn(|_| rng.gen::<Float>() - 0.5);
+        let furthest = cloud
+            .iter()
+            .copied()
+            .max_by(|&a, &b| dot(a, direction).total_cmp(&dot(b, direction)))
+            .unwrap();
+        assert!(!hull.contains(std::array::from_fn(|i| furthest[i] + 0.01 * direction[i])));
+    }
+
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    for _ in 0..100 {
+        assert!(hull.contains(hull.initial_point(&mut rng)));
+    }
+}
+
+#[test]
+fn convex_hull_of_a_cube() {
+    let corners: Vec<Point<3>> = (0..8)
+        .map(|i| std::array::from_fn(|axis| ((i >> axis) & 1) as Float))
+        .collect();
+    let hull = ConvexHull::new(&corners);
+
+    let points = Poisson3D::new()
+        .with_convex_hull(&corners)
+        .with_radius(0.2)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let cube = Poisson3D::new()
+        .with_radius(0.2)
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert!(points.iter().all(|&p| hull.contains(p)));
+    assert!(points.len() * 10 > cube.len() * 9);
+}
+
+#[test]
+#[should_panic]
+fn degenerate_convex_hull() {
+    let _ = ConvexHull::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]);
+}
+
+#[test]
+fn bounds_within_domain() {
+    let ball = Ball::new([0.0, 0.0], 1.0);
+    let points = Poisson2D::new()
+        .with_domain(ball)
+        .with_bounds([-1.0, -1.0], [1.0, 0.0])
+        .with_radius(0.1)
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|&p| ball.contains(p) && p[1] < 0.0));
+}
@@ -0,0 +1,289 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Distributions whose number of dimensions is only known at runtime
+
+use crate::{Float, Poisson, PoissonError};
+
+#[cfg(test)]
+mod tests;
+
+/// The largest number of dimensions supported at runtime
+const MAX_DIMENSIONS: usize = 8;
+
+/// The space of a [`PoissonDyn`], passed to its validation function
+#[derive(Debug, Clone, Copy, Default)]
+struct DynSpace {
+    /// The validation function, if any
+    validate: Option<fn(&[Float]) -> bool>,
+}
+
+/// A Poisson disk distribution whose number of dimensions is chosen at runtime
+///
+/// [`Poisson`] takes its number of dimensions as a const generic parameter, which must be known at
+/// compile time. When it's only known at runtime, such as when read from a configuration file, use
+/// `PoissonDyn` instead: points are `Vec`s of coordinates rather than arrays, and generation is
+/// handed off to a `Poisson` of the right number of dimensions, which may be from 1 to 8. The
+/// same seed gives the same points either way.
+///
+/// ```
+/// # use fast_poisson::{Poisson3D, PoissonDyn};
+/// let dimensions = 3; // Read from a configuration file
+/// let points = PoissonDyn::new(dimensions)
+///     .with_radius(0.2)
+///     .with_seed(0xBADBEEF)
+///     .generate();
+///
+/// assert!(points.iter().all(|point| point.len() == 3));
+///
+/// let fixed = Poisson3D::new().with_radius(0.2).with_seed(0xBADBEEF).generate();
+/// assert!(points.iter().zip(fixed).all(|(a, b)| a[..] == b[..]));
+/// ```
+///
+/// Only the most common settings are available; for anything more, match on the number of
+/// dimensions and use `Poisson` directly.
+#[derive(Debug, Clone)]
+pub struct PoissonDyn {
+    /// Number of dimensions
+    dimensions: usize,
+    /// Radius around each point that must remain empty
+    radius: Float,
+    /// Seed to use for the internal RNG
+    seed: Option<u64>,
+    /// Number of samples to generate and test around each point
+    samples: u32,
+    /// Box, as `[min, max]`, that every point must lie within, if any
+    bounds: Option<[Vec<Float>; 2]>,
+    /// Validation function, if any
+    validate: Option<fn(&[Float]) -> bool>,
+}
+
+impl PoissonDyn {
+    /// Create a new distribution of the given number of dimensions
+    ///
+    /// The defaults are the same as for [`Poisson::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `dimensions` is from 1 to 8; see [`try_new`][Self::try_new] for a fallible
+    /// alternative.
+    #[must_use]
+    pub fn new(dimensions: usize) -> Self {
+        match Self::try_new(dimensions) {
+            Ok(poisson) => poisson,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Create a new distribution of the given number of dimensions, if it is supported
+    ///
+    /// ```
+    /// # use fast_poisson::{PoissonDyn, PoissonError};
+    /// assert!(PoissonDyn::try_new(4).is_ok());
+    /// assert_eq!(
+    ///     PoissonDyn::try_new(0),
+    ///     Err(PoissonError::UnsupportedDimensions(0))
+    /// );
+    /// ```
+    pub fn try_new(dimensions: usize) -> Result<Self, PoissonError> {
+        if !(1..=MAX_DIMENSIONS).contains(&dimensions) {
+            return Err(PoissonError::UnsupportedDimensions(dimensions));
+        }
+
+        let defaults = Poisson::<1>::new();
+        Ok(Self {
+            dimensions,
+            radius: defaults.radius,
+            seed: None,
+            samples: defaults.num_samples,
+            bounds: None,
+            validate: None,
+        })
+    }
+
+    /// The number of dimensions
+    #[must_use]
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Specify the radius around each point
+    ///
+    /// See [`Poisson::with_radius`].
+    #[must_use]
+    pub fn with_radius(mut self, radius: Float) -> Self {
+        self.set_radius(radius);
+        self
+    }
+
+    /// Specify the radius around each point
+    pub fn set_radius(&mut self, radius: Float) {
+        self.radius = radius;
+    }
+
+    /// Specify the PRNG seed for this distribution
+    ///
+    /// See [`Poisson::with_seed`].
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.set_seed(seed);
+        self
+    }
+
+    /// Specify the PRNG seed for this distribution
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
+    /// Specify the maximum samples to generate around each point
+    ///
+    /// See [`Poisson::with_samples`].
+    #[must_use]
+    pub fn with_samples(mut self, samples: u32) -> Self {
+        self.set_samples(samples);
+        self
+    }
+
+    /// Specify the maximum samples to generate around each point
+    pub fn set_samples(&mut self, samples: u32) {
+        self.samples = samples;
+    }
+
+    /// Specify the box, from `min` to `max`, to sample
+    ///
+    /// See [`Poisson::with_bounds`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` or `max` doesn't have a coordinate for every dimension.
+    #[must_use]
+    pub fn with_bounds(mut self, min: &[Float], max: &[Float]) -> Self {
+        self.set_bounds(min, max);
+        self
+    }
+
+    /// Specify the box, from `min` to `max`, to sample
+    ///
+    /// See [`with_bounds`][Self::with_bounds] for more details.
+    pub fn set_bounds(&mut self, min: &[Float], max: &[Float]) {
+        assert!(
+            min.len() == self.dimensions && max.len() == self.dimensions,
+            "bounds must have a coordinate for every dimension"
+        );
+
+        self.bounds = Some([min.to_vec(), max.to_vec()]);
+    }
+
+    /// Specify the point validation function
+    ///
+    /// See [`Poisson::with_validate`].
+    #[must_use]
+    pub fn with_validate(mut self, validate: fn(&[Float]) -> bool) -> Self {
+        self.set_validate(validate);
+        self
+    }
+
+    /// Specify the point validation function
+    pub fn set_validate(&mut self, validate: fn(&[Float]) -> bool) {
+        self.validate = Some(validate);
+    }
+
+    /// Check that this distribution is configured correctly
+    ///
+    /// See [`Poisson::check`].
+    pub fn check(&self) -> Result<(), PoissonError> {
+        match self.dimensions {
+            1 => self.poisson::<1>().check(),
+            2 => self.poisson::<2>().check(),
+            3 => self.poisson::<3>().check(),
+            4 => self.poisson::<4>().check(),
+            5 => self.poisson::<5>().check(),
+            6 => self.poisson::<6>().check(),
+            7 => self.poisson::<7>().check(),
+            8 => self.poisson::<8>().check(),
+            _ => unreachable!("Dimensions are checked when created"),
+        }
+    }
+
+    /// Returns an iterator over the points in this distribution
+    #[must_use]
+    pub fn iter(&self) -> Box<dyn Iterator<Item = Vec<Float>>> {
+        match self.dimensions {
+            1 => Box::new(self.poisson::<1>().into_iter().map(Vec::from)),
+            2 => Box::new(self.poisson::<2>().into_iter().map(Vec::from)),
+            3 => Box::new(self.poisson::<3>().into_iter().map(Vec::from)),
+            4 => Box::new(self.poisson::<4>().into_iter().map(Vec::from)),
+            5 => Box::new(self.poisson::<5>().into_iter().map(Vec::from)),
+            6 => Box::new(self.poisson::<6>().into_iter().map(Vec::from)),
+            7 => Box::new(self.poisson::<7>().into_iter().map(Vec::from)),
+            8 => Box::new(self.poisson::<8>().into_iter().map(Vec::from)),
+            _ => unreachable!("Dimensions are checked when created"),
+        }
+    }
+
+    /// Generate the points in this distribution
+    #[must_use]
+    pub fn generate(&self) -> Vec<Vec<Float>> {
+        self.iter().collect()
+    }
+
+    /// Generate the points in this distribution, with their coordinates one after another in a
+    /// single `Vec`
+    ///
+    /// ```
+    /// # use fast_poisson::PoissonDyn;
+    /// let poisson = PoissonDyn::new(2).with_seed(0xBADBEEF);
+    /// let flat = poisson.generate_flat();
+    ///
+    /// assert_eq!(flat.len(), poisson.generate().len() * 2);
+    /// ```
+    #[must_use]
+    pub fn generate_flat(&self) -> Vec<Float> {
+        self.iter().flatten().collect()
+    }
+
+    /// The equivalent distribution of a fixed number of dimensions
+    fn poisson<const N: usize>(&self) -> Poisson<N, DynSpace> {
+        let mut poisson = Poisson::<N, DynSpace>::new()
+            .with_radius(self.radius)
+            .with_samples(self.samples);
+        if let Some(seed) = self.seed {
+            poisson.set_seed(seed);
+        }
+        if let Some([min, max]) = &self.bounds {
+            poisson.set_bounds(
+                std::array::from_fn(|i| min[i]),
+                std::array::from_fn(|i| max[i]),
+            );
+        }
+        if let Some(validate) = self.validate {
+            poisson.set_validate(
+                |point, space| space.validate.map_or(true, |validate| validate(&point)),
+                DynSpace {
+                    validate: Some(validate),
+                },
+            );
+        }
+
+        poisson
+    }
+}
+
+/// No object is equal, not even to itself, if the seed is unspecified
+///
+/// Validation functions can't be meaningfully compared, so they're ignored.
+impl PartialEq for PoissonDyn {
+    fn eq(&self, other: &Self) -> bool {
+        self.seed.is_some()
+            && other.seed.is_some()
+            && self.dimensions == other.dimensions
+            && self.radius == other.radius
+            && self.seed == other.seed
+            && self.samples == other.samples
+            && self.bounds == other.bounds
+    }
+}
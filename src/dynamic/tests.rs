@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::PoissonDyn;
+use crate::{Poisson2D, PoissonError};
+
+#[test]
+fn matches_fixed_dimensions() {
+    let points = PoissonDyn::new(2)
+        .with_radius(0.05)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let fixed = Poisson2D::new()
+        .with_radius(0.05)
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert_eq!(points.len(), fixed.len());
+    for (a, b) in points.iter().zip(fixed) {
+        assert_eq!(a[..], b[..]);
+    }
+}
+
+#[test]
+fn every_supported_dimension() {
+    for dimensions in 1..=8 {
+        let poisson = PoissonDyn::new(dimensions)
+            .with_radius(0.5)
+            .with_seed(0xBADBEEF);
+        assert_eq!(poisson.check(), Ok(()));
+
+        let points = poisson.generate();
+        assert!(points.iter().all(|point| point.len() == dimensions));
+        assert_eq!(poisson.generate_flat().len(), points.len() * dimensions);
+    }
+}
+
+#[test]
+fn unsupported_dimensions() {
+    assert_eq!(
+        PoissonDyn::try_new(0),
+        Err(PoissonError::UnsupportedDimensions(0))
+    );
+    assert_eq!(
+        PoissonDyn::try_new(9),
+        Err(PoissonError::UnsupportedDimensions(9))
+    );
+}
+
+#[test]
+#[should_panic]
+fn new_panics_on_unsupported_dimensions() {
+    let _ = PoissonDyn::new(9);
+}
+
+#[test]
+fn bounds_and_validate() {
+    let points = PoissonDyn::new(3)
+        .with_radius(0.2)
+        .with_seed(0xBADBEEF)
+        .with_bounds(&[-1.0, 2.0, 0.0], &[0.0, 3.0, 1.0])
+        .with_validate(|point| point[2] < 0.5)
+        .generate();
+
+    assert!(!points.is_empty());
+    for point in points {
+        assert!((-1.0..=0.0).contains(&point[0]));
+        assert!((2.0..=3.0).contains(&point[1]));
+        assert!((0.0..0.5).contains(&point[2]));
+    }
+}
+
+#[test]
+#[should_panic]
+fn bounds_must_match_dimensions() {
+    let _ = PoissonDyn::new(2).with_bounds(&[0.0], &[1.0]);
+}
+
+#[test]
+fn invalid_radius() {
+    assert_eq!(
+        PoissonDyn::new(2).with_radius(-1.0).check(),
+        Err(PoissonError::InvalidRadius(-1.0))
+    );
+}
+
+#[test]
+fn unseeded_is_never_equal() {
+    let poisson = PoissonDyn::new(2);
+    assert_ne!(poisson, poisson.clone());
+
+    let seeded = poisson.with_seed(0xBADBEEF);
+    assert_eq!(seeded, seeded.clone());
+}
@@ -0,0 +1,172 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Weighted sample elimination, reducing a set of points to any smaller number
+//!
+//! See Cem Yuksel, "Sample Elimination for Generating Poisson Disk Sample Sets", 2015. Every point
+//! is weighted by how closely it is crowded by its neighbors, and the most crowded point is
+//! removed until only as many remain as are wanted. Removing a point lightens its neighbors, so
+//! the points that remain stay spread as far apart as they can be.
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::weights::ball_volume;
+use crate::{Float, Point};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+#[cfg(test)]
+mod tests;
+
+/// Densest fraction of space that can be packed with equal balls, in 1 to 8 dimensions
+const DENSEST_PACKING: [Float; 8] = [1.0, 0.9069, 0.7405, 0.6169, 0.4653, 0.3729, 0.2953, 0.2537];
+
+/// Exponent of the weight function, which the paper found to work well in any dimension
+const ALPHA: Float = 8.0;
+
+/// Exponent used to shrink the distance below which weights stop growing
+const GAMMA: Float = 1.5;
+
+/// Scale of the distance below which weights stop growing
+const BETA: Float = 0.65;
+
+/// A point's weight, ordered heaviest first
+#[derive(Debug, Clone, Copy)]
+struct Heaviest {
+    weight: Float,
+    index: usize,
+}
+
+impl PartialEq for Heaviest {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Heaviest {}
+
+impl PartialOrd for Heaviest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Heaviest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Ties go to the earliest point, so that elimination is deterministic
+        self.weight
+            .total_cmp(&other.weight)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+/// The largest radius `count` points can be spread apart by within `volume`, as half the spacing
+fn max_radius(n: usize, volume: Float, count: usize) -> Float {
+    let packing = match n {
+        0 => 1.0,
+        1..=8 => DENSEST_PACKING[n - 1],
+        _ => DENSEST_PACKING[7] * Float::powi(0.5, n as i32 - 8),
+    };
+
+    (packing * volume / count.max(1) as Float / ball_volume(n, 1.0)).powf(1.0 / n as Float)
+}
+
+/// Eliminate points until only `target` remain, spread as far apart as possible
+///
+/// `volume` is the volume of the space the points fill. Returns the indices of every point: first
+/// those remaining, in their original order, followed by those eliminated, most recently
+/// eliminated first.
+pub(crate) fn eliminate<const N: usize>(
+    points: &[Point<N>],
+    volume: Float,
+    target: usize,
+) -> Vec<usize> {
+    let target = target.min(points.len());
+    let r_max = max_radius(N, volume, target);
+    let ratio = target as Float / points.len().max(1) as Float;
+    let r_min = r_max * (1.0 - ratio.powf(GAMMA)) * BETA;
+
+    let mut index = DefaultIndex::with_spacing(2.0 * r_max);
+    index.reserve(points.len());
+    for (i, point) in points.iter().enumerate() {
+        index.insert(*point, i as u64);
+    }
+
+    // How much a neighbor at this squared distance crowds a point
+    let weight = |squared_distance: Float| {
+        let distance = squared_distance.sqrt().max(r_min);
+        (1.0 - distance / (2.0 * r_max)).max(0.0).powf(ALPHA)
+    };
+    let neighbors = |index: &DefaultIndex<N>, i: usize| {
+        index
+            .within_squared(points[i], (2.0 * r_max).powi(2))
+            .into_iter()
+            .filter(move |&(j, _)| j as usize != i)
+    };
+
+    let mut weights: Vec<Float> = (0..points.len())
+        .map(|i| neighbors(&index, i).map(|(_, d)| weight(d)).sum())
+        .collect();
+    let mut heap: BinaryHeap<Heaviest> = weights
+        .iter()
+        .enumerate()
+        .map(|(index, &weight)| Heaviest { weight, index })
+        .collect();
+
+    let mut removed = vec![false; points.len()];
+    let mut eliminated = Vec::with_capacity(points.len() - target);
+    while eliminated.len() < points.len() - target {
+        let Some(Heaviest {
+            weight: w,
+            index: i,
+        }) = heap.pop()
+        else {
+            break;
+        };
+        // Weights only ever shrink, so outdated entries are found by their weight
+        if removed[i] || w != weights[i] {
+            continue;
+        }
+
+        removed[i] = true;
+        eliminated.push(i);
+        index.delete(points[i], i as u64);
+        for (j, d) in neighbors(&index, i) {
+            let j = j as usize;
+            weights[j] -= weight(d);
+            heap.push(Heaviest {
+                weight: weights[j],
+                index: j,
+            });
+        }
+    }
+
+    let mut order: Vec<usize> = (0..points.len()).filter(|&i| !removed[i]).collect();
+    order.extend(eliminated.into_iter().rev());
+
+    order
+}
+
+/// Order points progressively, so that every prefix of the order is itself well spread
+///
+/// The points are halved by elimination over and over, with the spacing adjusted to the number
+/// remaining each time, so that the points surviving longest come first. Returns the indices of
+/// every point, in their new order.
+pub(crate) fn rank<const N: usize>(points: &[Point<N>], volume: Float) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+
+    let mut count = points.len();
+    while count > 1 {
+        let subset: Vec<Point<N>> = order[..count].iter().map(|&i| points[i]).collect();
+        let ranked: Vec<usize> = eliminate(&subset, volume, count / 2)
+            .into_iter()
+            .map(|j| order[j])
+            .collect();
+        order[..count].copy_from_slice(&ranked);
+        count /= 2;
+    }
+
+    order
+}
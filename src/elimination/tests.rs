@@ -0,0 +1,92 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::stats::verify_min_distance;
+use crate::Poisson2D;
+use rand::{Rng, SeedableRng};
+
+/// The smallest distance between any two points
+fn min_distance<const N: usize>(points: &[Point<N>]) -> Float {
+    let mut min = Float::INFINITY;
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            let d: Float = a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum();
+            min = min.min(d.sqrt());
+        }
+    }
+
+    min
+}
+
+#[test]
+fn order_is_a_permutation() {
+    let points = Poisson2D::new().with_seed(0xBADBEEF).generate();
+    let order = eliminate(&points, 1.0, 20);
+
+    let mut sorted = order.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..points.len()).collect::<Vec<_>>());
+    assert!(order[..20].windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn elimination_spreads_points() {
+    let mut rng = crate::Rand::seed_from_u64(0xBADBEEF);
+    let points: Vec<Point<2>> = (0..2000).map(|_| [rng.gen(), rng.gen()]).collect();
+
+    let order = eliminate(&points, 1.0, 200);
+    let kept: Vec<_> = order[..200].iter().map(|&i| points[i]).collect();
+    let random = &points[..200];
+
+    // Far better spread than the same number of random points
+    assert!(min_distance(&kept) > 2.0 * min_distance(random));
+}
+
+#[test]
+fn rank_is_a_permutation() {
+    let points = Poisson2D::new().with_seed(0xBADBEEF).generate();
+    let mut order = rank(&points, 1.0);
+
+    assert!(min_distance(&order[..8].iter().map(|&i| points[i]).collect::<Vec<_>>()) > 0.2);
+    order.sort_unstable();
+    assert_eq!(order, (0..points.len()).collect::<Vec<_>>());
+}
+
+#[test]
+fn generate_exact_count() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    for count in [1, 37, 500] {
+        assert_eq!(poisson.generate_exact(count).len(), count);
+    }
+
+    // About as well spread as a Poisson disk distribution with as many points
+    let points = poisson.generate_exact(500);
+    let radius = crate::estimate::radius(&poisson, 500);
+    assert!(verify_min_distance(&points, radius * 0.7));
+}
+
+#[test]
+fn generate_exact_respects_space() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_validate(
+        |[x, y], _| (0.0..0.5).contains(&x) && (0.0..1.0).contains(&y),
+        (),
+    );
+
+    let points = poisson.generate_exact(100);
+    assert_eq!(points.len(), 100);
+    assert!(points.iter().all(|[x, _]| *x < 0.5));
+    assert_eq!(points, poisson.generate_exact(100));
+}
+
+#[test]
+fn generate_exact_empty_space() {
+    let poisson = Poisson2D::new().with_validate(|_, _| false, ());
+
+    assert!(poisson.generate_exact(10).is_empty());
+    assert!(Poisson2D::new().generate_exact(0).is_empty());
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Errors reported for invalid configurations
+
+use crate::Float;
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// A reason a [`Poisson`](crate::Poisson) distribution can't be generated as configured
+///
+/// Returned by [`Poisson::check`](crate::Poisson::check) and the fallible methods built upon it,
+/// such as [`try_generate`](crate::Poisson::try_generate).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PoissonError {
+    /// The radius is zero, negative, infinite, or NaN
+    InvalidRadius(Float),
+    /// No samples would be generated around each point, so the distribution could never grow
+    NoSamples,
+    /// The reseeding box is inverted or not finite along the given axis
+    InvalidReseeding(usize),
+    /// The bounds are empty or not finite along the given axis
+    InvalidBounds(usize),
+    /// The number of dimensions isn't supported at runtime
+    UnsupportedDimensions(usize),
+    /// No attempts were allowed at generating the required points
+    NoAttempts,
+    /// Every attempt generated fewer points than required
+    TooFewPoints {
+        /// The number of points required
+        required: usize,
+        /// The most points generated by any attempt
+        found: usize,
+    },
+}
+
+impl fmt::Display for PoissonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRadius(radius) => {
+                write!(f, "radius must be positive and finite, not {radius}")
+            }
+            Self::NoSamples => write!(f, "at least one sample must be generated around each point"),
+            Self::InvalidReseeding(axis) => {
+                write!(
+                    f,
+                    "reseeding box is inverted or not finite along axis {axis}"
+                )
+            }
+            Self::InvalidBounds(axis) => {
+                write!(f, "bounds are empty or not finite along axis {axis}")
+            }
+            Self::UnsupportedDimensions(dimensions) => {
+                write!(f, "{dimensions} dimensions are not supported, only 1 to 8")
+            }
+            Self::NoAttempts => write!(f, "at least one attempt must be allowed"),
+            Self::TooFewPoints { required, found } => {
+                write!(f, "at most {found} points were generated, not {required}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PoissonError {}
@@ -0,0 +1,110 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn invalid_radii() {
+    for radius in [0.0, -0.1, Float::INFINITY] {
+        assert_eq!(
+            Poisson2D::new().with_radius(radius).check(),
+            Err(PoissonError::InvalidRadius(radius))
+        );
+        assert!(Poisson2D::new().try_with_radius(radius).is_err());
+    }
+
+    assert!(matches!(
+        Poisson2D::new().with_radius(Float::NAN).try_generate(),
+        Err(PoissonError::InvalidRadius(r)) if r.is_nan()
+    ));
+}
+
+#[test]
+fn no_samples() {
+    assert_eq!(
+        Poisson2D::new().with_samples(0).check(),
+        Err(PoissonError::NoSamples)
+    );
+    assert_eq!(
+        Poisson2D::new().with_adaptive_samples(Some((0, 0))).check(),
+        Err(PoissonError::NoSamples)
+    );
+
+    // Adaptive samples replace the fixed number
+    let adaptive = Poisson2D::new()
+        .with_samples(0)
+        .with_adaptive_samples(Some((0, 10)));
+    assert_eq!(adaptive.check(), Ok(()));
+}
+
+#[test]
+fn invalid_reseeding() {
+    let inverted = Poisson3D::new().with_reseeding(10, [0.0, 1.0, 0.0], [1.0, 0.0, 1.0]);
+    assert_eq!(inverted.check(), Err(PoissonError::InvalidReseeding(1)));
+
+    let infinite = Poisson2D::new().with_reseeding(10, [0.0, 0.0], [Float::INFINITY, 1.0]);
+    assert_eq!(infinite.check(), Err(PoissonError::InvalidReseeding(0)));
+
+    // The box only matters when reseeding
+    let unused = Poisson2D::new().with_reseeding(0, [1.0, 1.0], [0.0, 0.0]);
+    assert_eq!(unused.check(), Ok(()));
+}
+
+#[test]
+fn invalid_bounds() {
+    let empty = Poisson2D::new().with_bounds([0.0, 1.0], [1.0, 1.0]);
+    assert_eq!(empty.check(), Err(PoissonError::InvalidBounds(1)));
+
+    let infinite = Poisson2D::new().with_bounds([Float::NEG_INFINITY, 0.0], [0.0, 1.0]);
+    assert_eq!(infinite.check(), Err(PoissonError::InvalidBounds(0)));
+}
+
+#[test]
+fn valid_configuration() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+
+    assert_eq!(poisson.check(), Ok(()));
+    assert_eq!(poisson.try_generate(), Ok(poisson.generate()));
+    assert_eq!(
+        poisson.try_iter().map(|iter| iter.count()),
+        Ok(poisson.generate().len())
+    );
+}
+
+#[test]
+fn errors_are_displayed() {
+    let error: Box<dyn std::error::Error> = Box::new(PoissonError::InvalidRadius(-1.0));
+
+    assert_eq!(
+        error.to_string(),
+        "radius must be positive and finite, not -1"
+    );
+}
+
+#[test]
+fn too_few_points() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.8);
+
+    // No more than 4 points fit in the unit square at this radius
+    assert!(matches!(
+        poisson.generate_at_least(5, 20),
+        Err(PoissonError::TooFewPoints { required: 5, found }) if (1..5).contains(&found)
+    ));
+    assert_eq!(
+        poisson.generate_at_least(1, 0),
+        Err(PoissonError::NoAttempts)
+    );
+    assert_eq!(
+        poisson.generate_at_least(0, 0),
+        Err(PoissonError::NoAttempts)
+    );
+    assert_eq!(
+        poisson.with_samples(0).generate_at_least(1, 20),
+        Err(PoissonError::NoSamples)
+    );
+}
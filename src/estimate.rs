@@ -0,0 +1,135 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Estimate the number of points in a distribution before generating it
+
+use crate::weights::ball_volume;
+use crate::{Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+use std::mem::size_of;
+
+#[cfg(test)]
+mod tests;
+
+/// Fraction of space covered by disks of half the radius around each point, in 1 to 4 dimensions
+///
+/// These were measured from distributions generated with the default number of samples.
+const PACKING: [Float; 4] = [0.67, 0.50, 0.33, 0.21];
+
+/// Factor by which the packing fraction shrinks with each dimension beyond those measured
+const PACKING_DECAY: Float = 0.63;
+
+/// Number of probes used to measure the volume of the space
+const PROBES: u32 = 4096;
+
+/// Seed used to measure the volume of the space, so that estimates are deterministic
+const PROBE_SEED: u64 = 0x5EED;
+
+/// Fraction by which capacity is reserved beyond the estimated count
+const CAPACITY_SLACK: Float = 0.1;
+
+/// Largest number of points room is reserved for up front
+const MAX_CAPACITY: usize = 1 << 24;
+
+/// The fraction of space covered by disks of half the radius around each point
+pub(crate) fn packing(n: usize) -> Float {
+    match n {
+        0 => 1.0,
+        1..=4 => PACKING[n - 1],
+        _ => PACKING[3] * PACKING_DECAY.powi(n as i32 - 4),
+    }
+}
+
+/// Measure the volume of the space described by the validation function
+///
+/// The space is sampled within the reseeding box, which defaults to the unit hypercube; any part
+/// of the space outside of it is not measured.
+pub(crate) fn volume<const N: usize, U, R>(distribution: &Poisson<N, U, R>) -> Float
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let [min, max] = distribution.reseed_bounds;
+    let boxed: Float = (0..N).map(|i| max[i] - min[i]).product();
+
+    let mut rng = R::seed_from_u64(PROBE_SEED);
+    let inside = (0..PROBES)
+        .filter(|_| {
+            let mut point = [0.0; N];
+            for (i, p) in point.iter_mut().enumerate() {
+                *p = min[i] + (max[i] - min[i]) * rng.gen::<Float>();
+            }
+
+            distribution.in_space(point)
+        })
+        .count();
+
+    boxed * inside as Float / PROBES as Float
+}
+
+/// Estimate the number of points in the distribution
+pub(crate) fn count<const N: usize, U, R>(distribution: &Poisson<N, U, R>) -> usize
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let covered = ball_volume(N, distribution.radius / 2.0);
+
+    (packing(N) * volume(distribution) / covered).round() as usize
+}
+
+/// Estimate how many points to allocate room for before generating the distribution
+///
+/// Unlike [`count`] this doesn't measure the space, which would cost as much as generating a
+/// small distribution; the whole reseeding box is assumed to be inside of it instead. No room is
+/// reserved when the radius varies with a density, as the distribution could be far sparser.
+pub(crate) fn capacity<const N: usize, U, R>(distribution: &Poisson<N, U, R>) -> usize
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    if distribution.density.is_some() {
+        return 0;
+    }
+
+    let [min, max] = distribution.reseed_bounds;
+    let boxed: Float = (0..N).map(|i| max[i] - min[i]).product();
+    let covered = ball_volume(N, distribution.radius / 2.0);
+    let estimate = packing(N) * boxed / covered;
+
+    // Leave some room for the estimate being low, rather than reallocating right at the end
+    ((estimate * (1.0 + CAPACITY_SLACK)) as usize).min(MAX_CAPACITY)
+}
+
+/// Estimate the memory used to generate and collect the distribution, in bytes
+///
+/// Each point is held in the output, in the list of points generated so far along with its index
+/// in the output, in the spatial index along with its item, and at worst in the active list along
+/// with its index in the output.
+pub(crate) fn memory<const N: usize, U, R>(distribution: &Poisson<N, U, R>) -> usize
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let point = size_of::<Point<N>>();
+    let index = size_of::<Option<usize>>();
+    let per_point = 4 * point + 2 * index + size_of::<u64>();
+
+    count(distribution) * per_point
+}
+
+/// Estimate the radius at which the distribution has approximately `count` points
+pub(crate) fn radius<const N: usize, U, R>(distribution: &Poisson<N, U, R>, count: usize) -> Float
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    // Each point covers a ball of half the radius, and together they cover `packing` of the space
+    let covered = packing(N) * volume(distribution) / count.max(1) as Float;
+
+    2.0 * (covered / ball_volume(N, 1.0)).powf(1.0 / N as Float)
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn volume_of_space() {
+    assert!((volume(&Poisson2D::new()) - 1.0).abs() < 1e-6);
+
+    // A disk of radius 0.5
+    let disk = Poisson2D::new()
+        .with_validate(|[x, y], _| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.25, ());
+    let expected = std::f64::consts::PI as Float / 4.0;
+    assert!((volume(&disk) - expected).abs() < 0.03);
+}
+
+#[test]
+fn estimated_count_is_approximate() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let count = poisson.generate().len() as Float;
+    let estimate = poisson.estimate_count() as Float;
+    assert!(
+        (estimate / count - 1.0).abs() < 0.15,
+        "{} for {}",
+        estimate,
+        count
+    );
+
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.02);
+    let count = poisson.generate().len() as Float;
+    let estimate = poisson.estimate_count() as Float;
+    assert!(
+        (estimate / count - 1.0).abs() < 0.1,
+        "{} for {}",
+        estimate,
+        count
+    );
+
+    // Estimating the radius for a count reverses the estimate
+    let poisson = Poisson3D::new().with_target_count(1234);
+    assert!(poisson.estimate_count().abs_diff(1234) <= 1);
+}
+
+#[test]
+fn target_count_is_approximate() {
+    for target in [100, 1000] {
+        let poisson = Poisson2D::new()
+            .with_seed(0xBADBEEF)
+            .with_target_count(target);
+        let count = poisson.generate().len() as Float;
+        assert!(
+            (count / target as Float - 1.0).abs() < 0.15,
+            "{} for {}",
+            count,
+            target
+        );
+    }
+
+    let poisson = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .with_target_count(1000);
+    let count = poisson.generate().len() as Float;
+    assert!((count / 1000.0 - 1.0).abs() < 0.15, "{} for 1000", count);
+}
+
+#[test]
+fn capacity_covers_distribution() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.02);
+    let count = poisson.generate().len();
+    let reserved = capacity(&poisson);
+    assert!(
+        (count..count * 3 / 2).contains(&reserved),
+        "{} for {}",
+        reserved,
+        count
+    );
+
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF).with_radius(0.1);
+    let count = poisson.generate().len();
+    let reserved = capacity(&poisson);
+    assert!(
+        (count..count * 3 / 2).contains(&reserved),
+        "{} for {}",
+        reserved,
+        count
+    );
+
+    // A density may make the distribution far sparser than its smallest radius suggests
+    let poisson = Poisson2D::new().with_density(|[x, _]| x, 0.02, 0.2);
+    assert_eq!(capacity(&poisson), 0);
+}
@@ -0,0 +1,333 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Write distributions to files
+//!
+//! Every format includes the seed, radius, and number of dimensions of the distribution, so that
+//! files are self-describing. Points are written as they are received, so a distribution can be
+//! streamed straight from its iterator without ever being collected in memory.
+//!
+//! ```
+//! # use fast_poisson::Poisson2D;
+//! use fast_poisson::export;
+//!
+//! let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+//!
+//! let mut csv = Vec::new();
+//! export::write_csv(&mut csv, &poisson, poisson.iter())?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! 3D distributions can also be written as point clouds in the PLY and XYZ formats, for use with
+//! tools such as MeshLab or CloudCompare, and 2D distributions can be rendered as SVG images, or
+//! with the `image` feature as PNG images.
+
+use crate::{Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+use std::io::{self, Write};
+
+#[cfg(feature = "image")]
+mod raster;
+#[cfg(feature = "image")]
+pub(crate) use raster::rasterize;
+#[cfg(feature = "image")]
+pub use raster::RasterOptions;
+#[cfg(test)]
+mod tests;
+
+/// The name of the `i`-th axis of an `n`-dimensional space
+pub(crate) fn axis_name(i: usize, n: usize) -> String {
+    match (i, n) {
+        (0, 1..=4) => "x".to_string(),
+        (1, 2..=4) => "y".to_string(),
+        (2, 3..=4) => "z".to_string(),
+        (3, 4) => "w".to_string(),
+        _ => format!("x{}", i),
+    }
+}
+
+/// Describe the seed for a header
+fn seed_text<const N: usize, U, R>(distribution: &Poisson<N, U, R>, none: &str) -> String
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    distribution
+        .seed
+        .map_or_else(|| none.to_string(), |seed| seed.to_string())
+}
+
+/// Write points as CSV
+///
+/// The file starts with comment lines, prefixed by `#`, recording the seed, radius, and
+/// dimensions, followed by a row of column names and then one row per point.
+///
+/// ```text
+/// # seed: 195935983
+/// # radius: 0.1
+/// # dimensions: 2
+/// x,y
+/// 0.5134247156079981,0.43155478279063126
+/// ...
+/// ```
+pub fn write_csv<W, I, const N: usize, U, R>(
+    mut writer: W,
+    distribution: &Poisson<N, U, R>,
+    points: I,
+) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Point<N>>,
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    writeln!(writer, "# seed: {}", seed_text(distribution, "none"))?;
+    writeln!(writer, "# radius: {}", distribution.radius)?;
+    writeln!(writer, "# dimensions: {}", N)?;
+
+    let names: Vec<_> = (0..N).map(|i| axis_name(i, N)).collect();
+    writeln!(writer, "{}", names.join(","))?;
+
+    for point in points {
+        let values: Vec<_> = point.iter().map(|value| value.to_string()).collect();
+        writeln!(writer, "{}", values.join(","))?;
+    }
+
+    Ok(())
+}
+
+/// Write points as JSON
+///
+/// The file holds a single object recording the seed (`null` if unspecified), radius, and
+/// dimensions, along with the points as an array of arrays.
+///
+/// ```text
+/// {"seed":195935983,"radius":0.1,"dimensions":2,"points":[
+/// [0.5134247156079981,0.43155478279063126],
+/// ...
+/// ]}
+/// ```
+pub fn write_json<W, I, const N: usize, U, R>(
+    mut writer: W,
+    distribution: &Poisson<N, U, R>,
+    points: I,
+) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Point<N>>,
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    write!(
+        writer,
+        "{{\"seed\":{},\"radius\":{},\"dimensions\":{},\"points\":[",
+        seed_text(distribution, "null"),
+        distribution.radius,
+        N
+    )?;
+
+    for (i, point) in points.into_iter().enumerate() {
+        let values: Vec<_> = point.iter().map(|value| value.to_string()).collect();
+        let separator = if i == 0 { "" } else { "," };
+        write!(writer, "{}\n[{}]", separator, values.join(","))?;
+    }
+
+    writeln!(writer, "\n]}}")
+}
+
+/// Optional attributes written with each point of a point cloud
+///
+/// See [`write_ply`] and [`write_xyz`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointCloudAttributes {
+    /// Write the index of each point in the output
+    pub id: bool,
+    /// Write the distance from each point to the closest point generated before it, or 0 for the
+    /// very first point
+    pub spacing: bool,
+}
+
+/// Format a point and its requested attributes as space-separated values
+fn cloud_values(
+    index: usize,
+    point: Point<3>,
+    spacing: Option<Float>,
+    attributes: PointCloudAttributes,
+) -> String {
+    let mut values: Vec<_> = point.iter().map(|value| value.to_string()).collect();
+    if attributes.id {
+        values.push(index.to_string());
+    }
+    if attributes.spacing {
+        values.push(spacing.unwrap_or(0.0).to_string());
+    }
+
+    values.join(" ")
+}
+
+/// Write points as an ASCII PLY point cloud
+///
+/// The seed, radius, and dimensions are recorded as comments in the header. Since PLY requires
+/// the number of points up front, the points are collected before being written.
+///
+/// Points are given as they are yielded by [`Poisson::iter_with_spacing`]; the spacing is only
+/// used if requested in `attributes`.
+///
+/// ```
+/// # use fast_poisson::Poisson3D;
+/// use fast_poisson::export::{self, PointCloudAttributes};
+///
+/// let poisson = Poisson3D::new().with_radius(0.2);
+/// let attributes = PointCloudAttributes { id: true, spacing: true };
+///
+/// let mut ply = Vec::new();
+/// export::write_ply(&mut ply, &poisson, poisson.iter_with_spacing(), attributes)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_ply<W, I, U, R>(
+    mut writer: W,
+    distribution: &Poisson<3, U, R>,
+    points: I,
+    attributes: PointCloudAttributes,
+) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = (Point<3>, Option<Float>)>,
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let points: Vec<_> = points.into_iter().collect();
+    let float = if std::mem::size_of::<Float>() == 8 {
+        "double"
+    } else {
+        "float"
+    };
+
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format ascii 1.0")?;
+    writeln!(writer, "comment seed {}", seed_text(distribution, "none"))?;
+    writeln!(writer, "comment radius {}", distribution.radius)?;
+    writeln!(writer, "comment dimensions 3")?;
+    writeln!(writer, "element vertex {}", points.len())?;
+    for axis in ["x", "y", "z"] {
+        writeln!(writer, "property {} {}", float, axis)?;
+    }
+    if attributes.id {
+        writeln!(writer, "property uint id")?;
+    }
+    if attributes.spacing {
+        writeln!(writer, "property {} spacing", float)?;
+    }
+    writeln!(writer, "end_header")?;
+
+    for (i, (point, spacing)) in points.into_iter().enumerate() {
+        writeln!(writer, "{}", cloud_values(i, point, spacing, attributes))?;
+    }
+
+    Ok(())
+}
+
+/// Write points as an XYZ point cloud
+///
+/// Each line holds the coordinates of a point separated by spaces, followed by the requested
+/// attributes. The format has no header, so the seed and radius are not recorded; on the other
+/// hand the points are written as they are received.
+///
+/// Points are given as they are yielded by [`Poisson::iter_with_spacing`]; the spacing is only
+/// used if requested in `attributes`.
+pub fn write_xyz<W, I>(mut writer: W, points: I, attributes: PointCloudAttributes) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = (Point<3>, Option<Float>)>,
+{
+    for (i, (point, spacing)) in points.into_iter().enumerate() {
+        writeln!(writer, "{}", cloud_values(i, point, spacing, attributes))?;
+    }
+
+    Ok(())
+}
+
+/// Options for rendering a 2D distribution as SVG
+///
+/// See [`Poisson::to_svg`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// Number of SVG units per unit of space
+    pub scale: Float,
+    /// Radius of the dot drawn for each point, in SVG units
+    pub point_radius: Float,
+    /// Fill color of the dots
+    pub point_color: String,
+    /// Whether to draw the circle of the distribution's radius around each point, which no other
+    /// point may enter
+    pub exclusion_circles: bool,
+    /// Stroke color of the exclusion circles
+    pub exclusion_color: String,
+    /// Background color, if any
+    pub background: Option<String>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            scale: 500.0,
+            point_radius: 2.0,
+            point_color: "black".to_string(),
+            exclusion_circles: false,
+            exclusion_color: "lightgray".to_string(),
+            background: Some("white".to_string()),
+        }
+    }
+}
+
+/// Render points, such as those generated by `distribution`, as an SVG image
+///
+/// The image covers the distribution's reseeding box, which defaults to the unit square. Also see
+/// [`Poisson::to_svg`], which renders the distribution's own points.
+pub fn svg<I, U, R>(distribution: &Poisson<2, U, R>, points: I, options: &SvgOptions) -> String
+where
+    I: IntoIterator<Item = Point<2>>,
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let [min, max] = distribution.reseed_bounds;
+    let scale = options.scale;
+    let width = (max[0] - min[0]) * scale;
+    let height = (max[1] - min[1]) * scale;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width, height, width, height
+    );
+    if let Some(background) = &options.background {
+        svg += &format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            background
+        );
+    }
+
+    for point in points {
+        let x = (point[0] - min[0]) * scale;
+        let y = (point[1] - min[1]) * scale;
+
+        if options.exclusion_circles {
+            svg += &format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"none\" stroke=\"{}\"/>\n",
+                x,
+                y,
+                distribution.radius * scale,
+                options.exclusion_color
+            );
+        }
+        svg += &format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+            x, y, options.point_radius, options.point_color
+        );
+    }
+
+    svg + "</svg>\n"
+}
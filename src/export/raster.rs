@@ -0,0 +1,135 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Rasterize 2D distributions for visual debugging
+
+use crate::{Float, Point, Poisson};
+use image::{Rgb, RgbImage};
+use rand::{Rng, SeedableRng};
+
+/// Color of the space inside the domain
+pub(super) const INSIDE: Rgb<u8> = Rgb([255, 255, 255]);
+/// Color of the space outside of the domain
+pub(super) const OUTSIDE: Rgb<u8> = Rgb([192, 192, 192]);
+/// Color of the exclusion circles
+pub(super) const CIRCLE: Rgb<u8> = Rgb([128, 160, 255]);
+/// Color of the points
+pub(super) const POINT: Rgb<u8> = Rgb([0, 0, 0]);
+
+/// Options for rasterizing a 2D distribution
+///
+/// See [`Poisson::to_image`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasterOptions {
+    /// Radius of the dot drawn for each point, in pixels
+    pub point_radius: u32,
+    /// Whether to draw the circle of the distribution's radius around each point
+    pub exclusion_circles: bool,
+    /// Whether to shade the parts of the image that are outside of the space, according to the
+    /// validation function
+    pub domain_mask: bool,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            point_radius: 2,
+            exclusion_circles: false,
+            domain_mask: true,
+        }
+    }
+}
+
+/// Rasterize points into an image
+///
+/// The image covers the distribution's reseeding box, which defaults to the unit square, with the
+/// minimum in the top left corner.
+pub(crate) fn rasterize<I, U, R>(
+    distribution: &Poisson<2, U, R>,
+    points: I,
+    width: u32,
+    height: u32,
+    options: &RasterOptions,
+) -> RgbImage
+where
+    I: IntoIterator<Item = Point<2>>,
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let [min, max] = distribution.reseed_bounds;
+    let scale = [
+        width as Float / (max[0] - min[0]),
+        height as Float / (max[1] - min[1]),
+    ];
+    // The center of a pixel, in space
+    let to_space = |x: u32, y: u32| -> Point<2> {
+        [
+            min[0] + (x as Float + 0.5) / scale[0],
+            min[1] + (y as Float + 0.5) / scale[1],
+        ]
+    };
+
+    let mut image = RgbImage::from_fn(width, height, |x, y| {
+        if !options.domain_mask || distribution.in_space(to_space(x, y)) {
+            INSIDE
+        } else {
+            OUTSIDE
+        }
+    });
+
+    // Draw each shape by testing every pixel within the given radius, in pixels, of a point
+    let draw = |image: &mut RgbImage,
+                point: Point<2>,
+                reach: Float,
+                test: &dyn Fn(Float) -> bool,
+                color| {
+        let center = [
+            (point[0] - min[0]) * scale[0],
+            (point[1] - min[1]) * scale[1],
+        ];
+        let x0 = (center[0] - reach).floor().max(0.0) as u32;
+        let y0 = (center[1] - reach).floor().max(0.0) as u32;
+        let x1 = ((center[0] + reach).ceil().max(0.0) as u32).min(width);
+        let y1 = ((center[1] + reach).ceil().max(0.0) as u32).min(height);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let dx = x as Float + 0.5 - center[0];
+                let dy = y as Float + 0.5 - center[1];
+                if test(dx.hypot(dy)) {
+                    image.put_pixel(x, y, color);
+                }
+            }
+        }
+    };
+
+    let points: Vec<_> = points.into_iter().collect();
+    if options.exclusion_circles {
+        let radius = distribution.radius * scale[0].max(scale[1]);
+        for &point in points.iter() {
+            draw(
+                &mut image,
+                point,
+                radius + 1.0,
+                &|distance| (distance - radius).abs() < 0.5,
+                CIRCLE,
+            );
+        }
+    }
+    let dot = options.point_radius as Float;
+    for &point in points.iter() {
+        draw(
+            &mut image,
+            point,
+            dot + 1.0,
+            &|distance| distance <= dot,
+            POINT,
+        );
+    }
+
+    image
+}
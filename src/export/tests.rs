@@ -0,0 +1,149 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Float, Poisson2D, Poisson3D};
+
+#[test]
+fn axis_names() {
+    assert_eq!(axis_name(0, 1), "x");
+    assert_eq!(axis_name(2, 3), "z");
+    assert_eq!(axis_name(3, 4), "w");
+    assert_eq!(axis_name(4, 5), "x4");
+}
+
+#[test]
+fn csv() {
+    let poisson = Poisson3D::new().with_seed(42).with_radius(0.5);
+    let mut output = Vec::new();
+    poisson.write_csv(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("# seed: 42"));
+    assert_eq!(lines.next(), Some("# radius: 0.5"));
+    assert_eq!(lines.next(), Some("# dimensions: 3"));
+    assert_eq!(lines.next(), Some("x,y,z"));
+
+    let points: Vec<Vec<Float>> = lines
+        .map(|line| line.split(',').map(|v| v.parse().unwrap()).collect())
+        .collect();
+    let expected: Vec<Vec<Float>> = poisson.iter().map(|p| p.to_vec()).collect();
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn json() {
+    let poisson = Poisson2D::new();
+    let mut output = Vec::new();
+    poisson.write_json(&mut output).unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["seed"], serde_json::Value::Null);
+    assert_eq!(json["radius"], 0.1);
+    assert_eq!(json["dimensions"], 2);
+    assert!(!json["points"].as_array().unwrap().is_empty());
+
+    // Streaming an existing set of points
+    let mut output = Vec::new();
+    write_json(&mut output, &poisson, Vec::new()).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert!(json["points"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn ply() {
+    let poisson = Poisson3D::new().with_seed(42).with_radius(0.3);
+    let attributes = PointCloudAttributes {
+        id: true,
+        spacing: true,
+    };
+    let mut output = Vec::new();
+    poisson.write_ply(&mut output, attributes).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let (header, body) = output.split_once("end_header\n").unwrap();
+    let points = poisson.generate_with_spacing();
+    assert!(header.starts_with("ply\nformat ascii 1.0\ncomment seed 42\n"));
+    assert!(header.contains(&format!("element vertex {}\n", points.len())));
+    assert!(header.contains("property uint id\nproperty "));
+    assert!(header.ends_with(" spacing\n"));
+
+    let rows: Vec<_> = body.lines().collect();
+    assert_eq!(rows.len(), points.len());
+    assert_eq!(
+        rows[1],
+        format!(
+            "{} {} {} 1 {}",
+            points[1].0[0],
+            points[1].0[1],
+            points[1].0[2],
+            points[1].1.unwrap()
+        )
+    );
+}
+
+#[test]
+fn xyz() {
+    let poisson = Poisson3D::new().with_seed(42).with_radius(0.3);
+    let mut output = Vec::new();
+    poisson
+        .write_xyz(&mut output, PointCloudAttributes::default())
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let points: Vec<Vec<Float>> = output
+        .lines()
+        .map(|line| line.split(' ').map(|v| v.parse().unwrap()).collect())
+        .collect();
+    let expected: Vec<Vec<Float>> = poisson.iter().map(|p| p.to_vec()).collect();
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn svg_image() {
+    let poisson = Poisson2D::new().with_seed(42);
+    let points = poisson.generate();
+
+    let image = poisson.to_svg(&SvgOptions::default());
+    assert!(image.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"500\""));
+    assert!(image.ends_with("</svg>\n"));
+    assert_eq!(image.matches("<circle").count(), points.len());
+    assert!(image.contains(&format!("cx=\"{}\"", points[0][0] * 500.0)));
+
+    let options = SvgOptions {
+        exclusion_circles: true,
+        background: None,
+        ..Default::default()
+    };
+    let image = poisson.to_svg(&options);
+    assert_eq!(image.matches("<circle").count(), 2 * points.len());
+    assert!(!image.contains("<rect"));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn raster_image() {
+    let poisson = Poisson2D::new()
+        .with_seed(42)
+        .with_validate(
+            |[x, y], _| (0.0..0.5).contains(&x) && (0.0..1.0).contains(&y),
+            (),
+        )
+        .with_reseeding(100, [0.0, 0.0], [1.0, 1.0]);
+    let points = poisson.generate();
+
+    let image = poisson.to_image(100, 100, &RasterOptions::default());
+    assert_eq!(image.dimensions(), (100, 100));
+    // The right half is outside of the space, and has no points
+    assert_eq!(*image.get_pixel(99, 50), raster::OUTSIDE);
+    let [x, y] = points[0];
+    assert_eq!(
+        *image.get_pixel((x * 100.0) as u32, (y * 100.0) as u32),
+        raster::POINT
+    );
+}
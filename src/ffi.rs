@@ -0,0 +1,269 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! C bindings
+//!
+//! These functions let C, C++, and anything else that can call C functions, such as native Unity
+//! plugins, generate distributions. The matching header is `include/fast_poisson.h`.
+//!
+//! A sampler is created with [`fast_poisson_new`], configured with the `fast_poisson_set_*`
+//! functions, and freed with [`fast_poisson_free`]. [`fast_poisson_generate`] generates its
+//! distribution and returns the number of points, after which [`fast_poisson_fill`] copies the
+//! points into a buffer allocated by the caller:
+//!
+//! ```c
+//! fast_poisson_sampler *sampler = fast_poisson_new(2);
+//! fast_poisson_set_radius(sampler, 0.05);
+//! fast_poisson_set_seed(sampler, 42);
+//!
+//! size_t count = fast_poisson_generate(sampler);
+//! fast_poisson_point2 *points = malloc(count * sizeof(fast_poisson_point2));
+//! fast_poisson_fill(sampler, (double *)points, count);
+//!
+//! fast_poisson_free(sampler);
+//! ```
+//!
+//! Points are written as consecutive coordinates, so a buffer of `count * dimensions` floats can
+//! be used in place of the `#[repr(C)]` point structs. With the `single_precision` feature,
+//! coordinates are `float` rather than `double`.
+//!
+//! The crate is built as a Rust library by default. To build a shared library to link against
+//! instead, ask for the `cdylib` crate type explicitly:
+//!
+//! ```sh
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+
+use crate::{Float, Poisson};
+use std::ptr;
+
+#[cfg(test)]
+mod tests;
+
+/// The largest number of dimensions supported through the C bindings
+const MAX_DIMENSIONS: usize = 8;
+
+/// A 2-dimensional point, with the same layout as the coordinates written by [`fast_poisson_fill`]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PoissonPoint2 {
+    pub x: Float,
+    pub y: Float,
+}
+
+/// A 3-dimensional point, with the same layout as the coordinates written by [`fast_poisson_fill`]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PoissonPoint3 {
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+}
+
+/// A sampler created through the C bindings
+///
+/// This is opaque to C; it is only ever handled through a pointer.
+#[derive(Debug)]
+pub struct PoissonSampler {
+    dimensions: usize,
+    radius: Float,
+    seed: Option<u64>,
+    samples: u32,
+    /// The coordinates of the generated points, if they have been generated since the sampler
+    /// was last changed
+    points: Option<Vec<Float>>,
+}
+
+impl PoissonSampler {
+    fn new(dimensions: usize) -> Self {
+        let defaults = Poisson::<2>::new();
+
+        Self {
+            dimensions,
+            radius: defaults.radius,
+            seed: None,
+            samples: defaults.num_samples,
+            points: None,
+        }
+    }
+
+    /// Generate the distribution, unless it was already generated
+    fn points(&mut self) -> &[Float] {
+        if self.points.is_none() {
+            self.points = Some(match self.dimensions {
+                1 => self.generate::<1>(),
+                2 => self.generate::<2>(),
+                3 => self.generate::<3>(),
+                4 => self.generate::<4>(),
+                5 => self.generate::<5>(),
+                6 => self.generate::<6>(),
+                7 => self.generate::<7>(),
+                8 => self.generate::<8>(),
+                _ => unreachable!("Dimensions are checked when set"),
+            });
+        }
+
+        self.points.as_deref().unwrap_or_default()
+    }
+
+    fn generate<const N: usize>(&self) -> Vec<Float> {
+        let mut poisson = Poisson::<N>::new()
+            .with_radius(self.radius)
+            .with_samples(self.samples);
+        if let Some(seed) = self.seed {
+            poisson.set_seed(seed);
+        }
+
+        poisson.iter().flatten().collect()
+    }
+}
+
+/// Create a sampler generating points in the unit box of the given number of dimensions
+///
+/// Returns a null pointer if the number of dimensions is not from 1 to 8. The sampler must be
+/// freed with [`fast_poisson_free`].
+#[no_mangle]
+pub extern "C" fn fast_poisson_new(dimensions: usize) -> *mut PoissonSampler {
+    if !(1..=MAX_DIMENSIONS).contains(&dimensions) {
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(PoissonSampler::new(dimensions)))
+}
+
+/// Free a sampler created with [`fast_poisson_new`]
+///
+/// # Safety
+///
+/// `sampler` must be null, or a pointer returned by [`fast_poisson_new`] that has not yet been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_free(sampler: *mut PoissonSampler) {
+    if !sampler.is_null() {
+        drop(Box::from_raw(sampler));
+    }
+}
+
+/// Set the number of dimensions, from 1 to 8
+///
+/// Returns `false`, leaving the sampler unchanged, if the number of dimensions is not supported.
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`].
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_set_dimensions(
+    sampler: *mut PoissonSampler,
+    dimensions: usize,
+) -> bool {
+    let Some(sampler) = sampler.as_mut() else {
+        return false;
+    };
+    if !(1..=MAX_DIMENSIONS).contains(&dimensions) {
+        return false;
+    }
+
+    sampler.dimensions = dimensions;
+    sampler.points = None;
+    true
+}
+
+/// Set the minimum distance between points
+///
+/// Returns `false`, leaving the sampler unchanged, if the radius is not positive.
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`].
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_set_radius(
+    sampler: *mut PoissonSampler,
+    radius: Float,
+) -> bool {
+    let Some(sampler) = sampler.as_mut() else {
+        return false;
+    };
+    if radius.is_nan() || radius <= 0.0 {
+        return false;
+    }
+
+    sampler.radius = radius;
+    sampler.points = None;
+    true
+}
+
+/// Set the seed, making the distribution reproducible
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`].
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_set_seed(sampler: *mut PoissonSampler, seed: u64) {
+    if let Some(sampler) = sampler.as_mut() {
+        sampler.seed = Some(seed);
+        sampler.points = None;
+    }
+}
+
+/// Set the number of candidates tried around each point before giving up on it
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`].
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_set_samples(sampler: *mut PoissonSampler, samples: u32) {
+    if let Some(sampler) = sampler.as_mut() {
+        sampler.samples = samples;
+        sampler.points = None;
+    }
+}
+
+/// Generate the distribution, returning its number of points
+///
+/// The points are kept until the sampler is changed, so calling this again, or calling
+/// [`fast_poisson_fill`], returns the same points even without a seed.
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`].
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_generate(sampler: *mut PoissonSampler) -> usize {
+    match sampler.as_mut() {
+        Some(sampler) => sampler.points().len() / sampler.dimensions,
+        None => 0,
+    }
+}
+
+/// Copy up to `capacity` points into `buffer`, returning the number of points copied
+///
+/// The distribution is generated first if needed. Each point is written as consecutive
+/// coordinates, so `buffer` must have room for `capacity * dimensions` floats.
+///
+/// # Safety
+///
+/// `sampler` must be null, or a valid pointer returned by [`fast_poisson_new`]. `buffer` must be
+/// null, or valid for writing `capacity * dimensions` floats.
+#[no_mangle]
+pub unsafe extern "C" fn fast_poisson_fill(
+    sampler: *mut PoissonSampler,
+    buffer: *mut Float,
+    capacity: usize,
+) -> usize {
+    let Some(sampler) = sampler.as_mut() else {
+        return 0;
+    };
+    if buffer.is_null() {
+        return 0;
+    }
+
+    let dimensions = sampler.dimensions;
+    let points = sampler.points();
+    let count = capacity.min(points.len() / dimensions);
+    ptr::copy_nonoverlapping(points.as_ptr(), buffer, count * dimensions);
+
+    count
+}
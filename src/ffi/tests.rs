@@ -0,0 +1,81 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson3D;
+
+#[test]
+fn matches_rust_api() {
+    unsafe {
+        let sampler = fast_poisson_new(3);
+        assert!(fast_poisson_set_radius(sampler, 0.2));
+        fast_poisson_set_seed(sampler, 0xBADBEEF);
+
+        let count = fast_poisson_generate(sampler);
+        let mut points = vec![PoissonPoint3::default(); count];
+        let copied = fast_poisson_fill(sampler, points.as_mut_ptr().cast(), count);
+        fast_poisson_free(sampler);
+
+        let expected = Poisson3D::new()
+            .with_radius(0.2)
+            .with_seed(0xBADBEEF)
+            .generate();
+        assert_eq!(copied, expected.len());
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|[x, y, z]| PoissonPoint3 { x, y, z })
+            .collect();
+        assert_eq!(points, expected);
+    }
+}
+
+#[test]
+fn fill_respects_capacity() {
+    unsafe {
+        let sampler = fast_poisson_new(2);
+        let count = fast_poisson_generate(sampler);
+        assert!(count > 5);
+
+        // Unseeded, but the generated points are kept
+        let mut all = vec![PoissonPoint2::default(); count];
+        fast_poisson_fill(sampler, all.as_mut_ptr().cast(), count);
+        let mut few = [0.0; 10];
+        assert_eq!(fast_poisson_fill(sampler, few.as_mut_ptr(), 5), 5);
+        let expected: Vec<_> = all[..5].iter().flat_map(|p| [p.x, p.y]).collect();
+        assert_eq!(few[..], expected[..]);
+
+        // Changing the sampler discards them
+        assert!(fast_poisson_set_dimensions(sampler, 4));
+        assert!(fast_poisson_set_radius(sampler, 0.3));
+        let mut points = vec![0.0; 4 * count];
+        let copied = fast_poisson_fill(sampler, points.as_mut_ptr(), count);
+        assert!(copied > 0);
+        assert!(copied <= count);
+
+        fast_poisson_free(sampler);
+    }
+}
+
+#[test]
+fn invalid_arguments() {
+    unsafe {
+        assert!(fast_poisson_new(0).is_null());
+        assert!(fast_poisson_new(9).is_null());
+
+        let sampler = fast_poisson_new(2);
+        assert!(!fast_poisson_set_radius(sampler, 0.0));
+        assert!(!fast_poisson_set_radius(sampler, Float::NAN));
+        assert!(!fast_poisson_set_dimensions(sampler, 9));
+        assert_eq!(fast_poisson_fill(sampler, ptr::null_mut(), 10), 0);
+        fast_poisson_free(sampler);
+
+        // Null samplers are ignored
+        assert!(!fast_poisson_set_radius(ptr::null_mut(), 0.5));
+        assert_eq!(fast_poisson_generate(ptr::null_mut()), 0);
+        fast_poisson_free(ptr::null_mut());
+    }
+}
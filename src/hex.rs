@@ -0,0 +1,237 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Jittered hexagonal lattices, a denser and less regular alternative to jittered grids
+
+use crate::{Float, Point, Poisson, Rand, Sampler};
+use rand::{Rng, SeedableRng};
+use std::iter::FusedIterator;
+
+#[cfg(test)]
+mod tests;
+
+/// A sampler displacing each point of a hexagonal lattice by a random amount
+///
+/// A hexagonal lattice is the densest packing of points at a given distance from each other, but
+/// on its own it looks entirely regular. Moving each point a random distance within a small disk
+/// breaks up the pattern while still keeping every point at least the radius from every other:
+/// the lattice is spread out by the [jitter](Self::with_jitter) to make room for the movement.
+/// Like [`JitteredGrid`](crate::JitteredGrid) this needs no neighbor searches, so it takes
+/// constant time per point.
+///
+/// The lattice covers the [reseeding](Poisson::with_reseeding) box, by default the unit square;
+/// points outside the box are skipped, as are points failing the validation function. A
+/// [density](Poisson::with_density) is not supported.
+///
+/// ```
+/// # use fast_poisson::{Poisson2D, Sampler};
+/// let points = Poisson2D::new()
+///     .with_seed(0xBADBEEF)
+///     .jittered_hex()
+///     .generate();
+///
+/// assert!(points.iter().all(|&[x, y]| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)));
+/// ```
+///
+/// See [`Poisson::jittered_hex`].
+#[derive(Debug)]
+pub struct JitteredHex<U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution to approximate
+    distribution: Poisson<2, U, R>,
+    /// How far each point may move, relative to the radius
+    jitter: Float,
+}
+
+impl<U, R> JitteredHex<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Approximate the specified distribution
+    pub(crate) fn new(distribution: Poisson<2, U, R>) -> Self {
+        Self {
+            distribution,
+            jitter: 0.5,
+        }
+    }
+
+    /// Specify how far each point may move from its place in the lattice, relative to the radius
+    ///
+    /// Points are moved up to half of `jitter` times the radius in any direction, and the lattice
+    /// is spaced `1 + jitter` times the radius apart to make room. A jitter of 0 gives a perfect
+    /// hexagonal lattice; larger jitters look more random, but give fewer points. The default is
+    /// 0.5.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `jitter` is negative or not finite.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let poisson = Poisson2D::new().with_radius(0.1);
+    /// let regular = poisson.jittered_hex().with_jitter(0.0).generate();
+    /// let irregular = poisson.jittered_hex().with_jitter(1.0).generate();
+    ///
+    /// assert!(regular.len() > irregular.len());
+    /// ```
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: Float) -> Self {
+        self.set_jitter(jitter);
+        self
+    }
+
+    /// Specify how far each point may move from its place in the lattice, relative to the radius
+    ///
+    /// See [`with_jitter`][Self::with_jitter] for more details.
+    pub fn set_jitter(&mut self, jitter: Float) {
+        assert!(
+            jitter.is_finite() && jitter >= 0.0,
+            "jitter must be finite and non-negative"
+        );
+        self.jitter = jitter;
+    }
+}
+
+impl<U, R> Clone for JitteredHex<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    fn clone(&self) -> Self {
+        Self {
+            distribution: self.distribution.clone(),
+            jitter: self.jitter,
+        }
+    }
+}
+
+impl<U, R> Sampler<2> for JitteredHex<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Iter = Hexes<U, R>;
+
+    fn sample(&self) -> Self::Iter {
+        Hexes::new(self.distribution.clone(), self.jitter)
+    }
+}
+
+/// An iterator over the points of a [`JitteredHex`] sampler
+pub struct Hexes<U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution being approximated
+    distribution: Poisson<2, U, R>,
+    /// The RNG
+    rng: R,
+    /// The distance between neighboring points of the lattice
+    spacing: Float,
+    /// How far each point may move from its place in the lattice
+    offset: Float,
+    /// The number of points along each row, and the number of rows
+    size: [usize; 2],
+    /// The next point of the lattice, or `None` once every point has been visited
+    next: Option<[usize; 2]>,
+}
+
+impl<U, R> Hexes<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Start placing points on the lattice of the specified distribution
+    fn new(distribution: Poisson<2, U, R>, jitter: Float) -> Self {
+        let rng = distribution.rng();
+
+        // Points that move at most `offset` stay at least `spacing - 2 * offset` apart, which is
+        // exactly the radius
+        let radius = distribution.radius;
+        let spacing = radius * (1.0 + jitter);
+        let offset = 0.5 * radius * jitter;
+
+        // The lattice starts `offset` into the box, so points along its lower edges can't be
+        // moved out of it; points moved out along the upper edges are skipped
+        let [min, max] = distribution.reseed_bounds;
+        let row_height = spacing * 0.75f64.sqrt() as Float;
+        let size = [
+            ((max[0] - min[0] - offset) / spacing).ceil() as usize,
+            ((max[1] - min[1] - offset) / row_height).ceil() as usize,
+        ];
+        let next = if size.iter().all(|&s| s > 0) {
+            Some([0; 2])
+        } else {
+            None
+        };
+
+        Self {
+            distribution,
+            rng,
+            spacing,
+            offset,
+            size,
+            next,
+        }
+    }
+}
+
+impl<U, R> Iterator for Hexes<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = Point<2>;
+
+    fn next(&mut self) -> Option<Point<2>> {
+        let [min, max] = self.distribution.reseed_bounds;
+        let row_height = self.spacing * 0.75f64.sqrt() as Float;
+
+        while let Some([column, row]) = self.next {
+            // A uniformly random point within a disk around the lattice point
+            let angle = self.rng.gen::<Float>() * std::f64::consts::TAU as Float;
+            let distance = self.offset * self.rng.gen::<Float>().sqrt();
+            // Every other row is shifted by half the spacing, so it may hold one fewer point
+            let shift = if row % 2 == 1 { 0.5 } else { 0.0 };
+            let point = [
+                min[0]
+                    + self.offset
+                    + (column as Float + shift) * self.spacing
+                    + distance * angle.cos(),
+                min[1] + self.offset + row as Float * row_height + distance * angle.sin(),
+            ];
+
+            // Step along the row, then on to the next one
+            self.next = if column + 1 < self.size[0] {
+                Some([column + 1, row])
+            } else if row + 1 < self.size[1] {
+                Some([0, row + 1])
+            } else {
+                None
+            };
+
+            let in_box = (0..2).all(|i| (min[i]..max[i]).contains(&point[i]));
+            if in_box && self.distribution.in_space(point) {
+                return Some(self.distribution.transformed(point));
+            }
+        }
+
+        None
+    }
+}
+
+impl<U, R> FusedIterator for Hexes<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+}
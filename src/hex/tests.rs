@@ -0,0 +1,80 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::stats::verify_min_distance;
+use crate::{Poisson2D, Sampler};
+
+#[test]
+fn min_distance_is_kept() {
+    for jitter in [0.0, 0.5, 1.0, 2.0] {
+        let points = Poisson2D::new()
+            .with_seed(0xBADBEEF)
+            .with_radius(0.02)
+            .jittered_hex()
+            .with_jitter(jitter)
+            .generate();
+
+        assert!(!points.is_empty());
+        // Without jitter neighbors are exactly the radius apart, give or take rounding
+        assert!(verify_min_distance(&points, 0.02 * 0.999));
+    }
+}
+
+#[test]
+fn regular_lattice_is_dense() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.1)
+        .jittered_hex()
+        .with_jitter(0.0)
+        .generate();
+
+    // 12 rows of 10 points, as the shifted rows still fit within the unit square
+    assert_eq!(points.len(), 120);
+    assert!(points.iter().any(|&[x, _]| (x - 0.95).abs() < 1e-6));
+}
+
+#[test]
+fn lattice_covers_reseeding_box() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.05)
+        .with_validate(|_, _| true, ())
+        .with_reseeding(1, [-1.0, 2.0], [1.0, 3.0])
+        .jittered_hex()
+        .generate();
+
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (-1.0..1.0).contains(&x) && (2.0..3.0).contains(&y)));
+    assert!(points.iter().any(|&[x, y]| x > 0.9 && y > 2.9));
+    assert!(points.iter().any(|&[x, y]| x < -0.9 && y < 2.1));
+}
+
+#[test]
+fn jitter_is_deterministic() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+
+    assert_eq!(
+        poisson.jittered_hex().generate(),
+        poisson.jittered_hex().generate()
+    );
+    assert_ne!(
+        poisson.jittered_hex().generate(),
+        poisson
+            .clone()
+            .with_seed(0xC0FFEE)
+            .jittered_hex()
+            .generate()
+    );
+}
+
+#[test]
+#[should_panic]
+fn negative_jitter() {
+    let _ = Poisson2D::new().jittered_hex().with_jitter(-1.0);
+}
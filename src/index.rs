@@ -0,0 +1,104 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Read-only spatial queries over a generated distribution
+
+use crate::{Float, Point};
+use kiddo::{ImmutableKdTree, SquaredEuclidean};
+use std::num::NonZeroUsize;
+
+#[cfg(test)]
+mod tests;
+
+/// A generated distribution, indexed for fast nearest neighbor queries
+///
+/// The index is built once all points have been generated and can't be modified afterwards,
+/// which allows for a more compact layout than the k-d tree used during generation, with better
+/// query throughput and lower memory use. Points are identified by their index in
+/// [`points`][Self::points], which is the order in which they were generated.
+///
+/// See [`Poisson::generate_immutable_kd_tree`][crate::Poisson::generate_immutable_kd_tree].
+pub struct PointIndex<const N: usize> {
+    /// The indexed points
+    points: Vec<Point<N>>,
+    /// The spatial index, whose items are indices into `points`
+    tree: ImmutableKdTree<Float, N>,
+}
+
+impl<const N: usize> PointIndex<N> {
+    /// Index the given points
+    pub(crate) fn new(points: Vec<Point<N>>) -> Self {
+        let tree = ImmutableKdTree::new_from_slice(&points);
+
+        Self { points, tree }
+    }
+
+    /// The indexed points
+    #[must_use]
+    pub fn points(&self) -> &[Point<N>] {
+        &self.points
+    }
+
+    /// The number of indexed points
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if there are no indexed points
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Find the point closest to `point`
+    ///
+    /// Returns its index along with its distance, or `None` if there are no points at all.
+    #[must_use]
+    pub fn nearest(&self, point: Point<N>) -> Option<(usize, Float)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let nearest = self.tree.nearest_one::<SquaredEuclidean>(&point);
+
+        Some((nearest.item as usize, nearest.distance.sqrt()))
+    }
+
+    /// Find the `count` points closest to `point`
+    ///
+    /// Returns their indices along with their distances, closest first.
+    #[must_use]
+    pub fn nearest_n(&self, point: Point<N>, count: usize) -> Vec<(usize, Float)> {
+        let Some(count) = NonZeroUsize::new(count.min(self.len())) else {
+            return Vec::new();
+        };
+
+        self.tree
+            .nearest_n::<SquaredEuclidean>(&point, count)
+            .into_iter()
+            .map(|neighbor| (neighbor.item as usize, neighbor.distance.sqrt()))
+            .collect()
+    }
+
+    /// Find every point less than `distance` away from `point`
+    ///
+    /// Returns their indices along with their distances, closest first.
+    #[must_use]
+    pub fn within(&self, point: Point<N>, distance: Float) -> Vec<(usize, Float)> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        self.tree
+            .within::<SquaredEuclidean>(&point, distance.powi(2))
+            .into_iter()
+            .filter(|neighbor| neighbor.distance < distance.powi(2))
+            .map(|neighbor| (neighbor.item as usize, neighbor.distance.sqrt()))
+            .collect()
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+/// Every point's index and distance from `point`, closest first
+fn brute_force<const N: usize>(points: &[Point<N>], point: Point<N>) -> Vec<(usize, Float)> {
+    let mut distances: Vec<_> = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let distance = p
+                .iter()
+                .zip(point.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<Float>()
+                .sqrt();
+            (i, distance)
+        })
+        .collect();
+    distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    distances
+}
+
+#[test]
+fn queries_match_brute_force() {
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF).with_radius(0.1);
+    let index = poisson.generate_immutable_kd_tree();
+    assert_eq!(index.points(), &poisson.generate()[..]);
+
+    for query in [[0.5, 0.5, 0.5], [0.0, 0.2, 1.0], [2.0, -1.0, 0.5]] {
+        let expected = brute_force(index.points(), query);
+
+        assert_eq!(index.nearest(query), Some(expected[0]));
+        assert_eq!(index.nearest_n(query, 5), expected[..5]);
+
+        let within: Vec<_> = expected.iter().copied().filter(|n| n.1 < 0.3).collect();
+        assert_eq!(index.within(query, 0.3), within);
+    }
+}
+
+#[test]
+fn empty_index() {
+    let index = PointIndex::<2>::new(Vec::new());
+
+    assert!(index.is_empty());
+    assert_eq!(index.nearest([0.5, 0.5]), None);
+    assert!(index.nearest_n([0.5, 0.5], 3).is_empty());
+    assert!(index.within([0.5, 0.5], 1.0).is_empty());
+}
+
+#[test]
+fn nearest_n_is_limited_to_len() {
+    let index = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.3)
+        .generate_immutable_kd_tree();
+
+    assert_eq!(index.nearest_n([0.5, 0.5], 1000).len(), index.len());
+    assert!(index.nearest_n([0.5, 0.5], 0).is_empty());
+}
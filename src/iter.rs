@@ -5,13 +5,17 @@
 // https://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use crate::Rand;
+use crate::quasi::Halton;
+use crate::quota::QUOTA_ATTEMPTS;
+#[cfg(feature = "simd")]
+use crate::simd::Neighborhood;
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{elimination, estimate, CandidateSequence, Event, GenerationStats, Observer, Rand};
 
 use super::{Float, Poisson};
-use kiddo::{KdTree, SquaredEuclidean};
 use rand::prelude::*;
 use rand_distr::StandardNormal;
-use std::iter::FusedIterator;
+use std::{collections::VecDeque, iter::FusedIterator};
 
 #[cfg(test)]
 mod tests;
@@ -19,146 +23,1240 @@ mod tests;
 /// A Point is simply an array of Float values
 pub type Point<const N: usize> = [Float; N];
 
+/// Item used in the k-d tree to mark points that were loaded rather than generated
+///
+/// Generated points instead use their index in the output.
+const EXISTING: u64 = u64::MAX;
+
+/// Number of visits to active points over which the acceptance rate is averaged, when the number of
+/// samples adapts to it
+const ACCEPTANCE_WINDOW: Float = 64.0;
+
+/// Number of points between progress events
+#[cfg(feature = "tracing")]
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// How the next active point to generate candidates around is chosen
+///
+/// The choice shapes how the distribution grows, and thus the order of its points, but not the
+/// spacing between them. See [`Poisson::with_selection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SelectionStrategy {
+    /// Any active point, at random, as in Bridson's algorithm
+    ///
+    /// The distribution grows outward in every direction at once, in a ragged front.
+    #[default]
+    Random,
+    /// The active point that was added first
+    ///
+    /// The distribution grows outward in an even, roughly circular front, and each point is
+    /// returned close to the points returned just before it.
+    Oldest,
+    /// The active point that was added most recently
+    ///
+    /// The distribution grows as a winding path that fills in the space around itself, much like
+    /// a random walk.
+    Newest,
+}
+
+/// A point of reordered output, with its parent's index and its spacing
+type Shuffled<const N: usize> = (Point<N>, Option<usize>, Option<Float>);
+
 /// An iterator over the points in the Poisson disk distribution
-pub struct Iter<const N: usize, U, R = Rand>
+///
+/// Points are kept in a [spatial index](SpatialIndex) of type `I` to test the spacing of new
+/// candidates; see [`Poisson::iter_with_index`] to choose another.
+pub struct Iter<const N: usize, U, R = Rand, I = DefaultIndex<N>>
 where
     U: Default + Clone,
     R: Rng + SeedableRng,
+    I: SpatialIndex<N>,
 {
     /// The distribution from which this iterator was built
     distribution: Poisson<N, U, R>,
     /// The RNG
     rng: R,
     /// All previously-selected samples, to ensure new samples maintain minimum radius
-    sampled: KdTree<Float, N>,
-    /// A list of valid points that we have not yet visited
-    active: Vec<Point<N>>,
+    sampled: I,
+    /// Every point in `sampled`, in the order they were added, in world coordinates
+    samples: Vec<Point<N>>,
+    /// Output index of each point in `samples`, if it is part of the output
+    sample_indices: Vec<Option<usize>>,
+    /// A list of valid points that we have not yet visited, oldest first
+    active: VecDeque<Point<N>>,
+    /// Output index of each point in `active`, if it is part of the output
+    active_indices: VecDeque<Option<usize>>,
+    /// Number of points added to the output so far
+    count: usize,
+    /// Output index of the point the most recently returned point was generated around
+    parent: Option<usize>,
+    /// Distance from the most recently returned point to the closest point accepted before it
+    spacing: Option<Float>,
+    /// Reordered output not yet returned, in reverse order, with each point's parent and spacing
+    shuffled: Option<Vec<Shuffled<N>>>,
+    /// Points already added to the pattern that have not yet been returned, with their spacing
+    pending: Vec<(Point<N>, Option<Float>)>,
+    /// Statistics about the generation so far
+    stats: GenerationStats,
+    /// Whether generation has finished, so that we keep returning `None` once we have
+    done: bool,
+    /// Radius to maintain around points loaded with [`Iter::load_existing`], if any were loaded
+    existing_radius: Option<Float>,
+    /// Recent fraction of visits to active points that found a new point
+    acceptance: Float,
+    /// The sequence placing candidates, if they aren't placed at random
+    halton: Option<Halton>,
+    /// Receives every step of generation, if anything is observing it
+    observer: Option<Box<dyn Observer<N> + Send>>,
+    /// Number of generated points within each cell, if there are quotas
+    quota_counts: Vec<usize>,
+    /// The first cell that may still be short of its minimum quota
+    quota_cell: usize,
+    /// Origin of the local frame in which we generate, in world coordinates
+    ///
+    /// Far from the world origin the spacing between representable values grows, and distances
+    /// computed there lose precision. Everything other than `samples` and our output is therefore
+    /// kept relative to this origin, which lies within the space being generated.
+    origin: Point<N>,
+    /// Every generated point, by output index, for vectorized distance checks
+    #[cfg(feature = "simd")]
+    points: Vec<Point<N>>,
+    /// Span within which generation runs
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
-impl<const N: usize, U, R> Iter<N, U, R>
+impl<const N: usize, U, R, I> Iter<N, U, R, I>
 where
     U: Default + Clone,
     R: Rng + SeedableRng,
+    I: SpatialIndex<N>,
 {
     /// Create an iterator over the specified distribution
     pub(crate) fn new(distribution: Poisson<N, U, R>) -> Self {
-        // If we were not given a seed, generate one non-deterministically
-        let mut rng = match distribution.seed {
-            None => R::from_entropy(),
-            Some(seed) => R::seed_from_u64(seed),
+        let mut rng = distribution.rng();
+
+        // Generate around the first point, or else the lowest corner of the bounds or of the
+        // reseeding box; the default space starts at the world origin, where no translation is
+        // needed
+        let origin = match (distribution.first_points.first(), distribution.bounds) {
+            (Some(&point), _) => point,
+            (None, Some([min, _])) => min,
+            (None, None) if distribution.reseed_attempts > 0 => distribution.reseed_bounds[0],
+            (None, None) => [0.0; N],
         };
 
-        // We have to generate an initial point, just to ensure we've got *something* in the active list
-        let mut first_point = [0.0; N];
-        for i in first_point.iter_mut() {
-            // Start somewhere near the middle, but still randomly distributed
-            *i = (0.5 - rng.gen::<Float>()) * distribution.radius;
+        // We have to have an initial point, just to ensure we've got *something* in the active list
+        let mut first_points = distribution.first_points.clone();
+        if first_points.is_empty() {
+            let first_point = match distribution.sample_first {
+                // A domain knows best where within it to start
+                Some(sample) => sample(&distribution.validate_user_data, &mut rng),
+                None => {
+                    // Within bounds, start near their center, which is sure to lie within them
+                    let center = distribution.bounds.map_or([0.0; N], |[min, max]| {
+                        std::array::from_fn(|i| (min[i] + max[i]) / 2.0)
+                    });
+                    let mut first_point = [0.0; N];
+                    for (i, c) in first_point.iter_mut().zip(center) {
+                        // Start somewhere near the middle, but still randomly distributed
+                        *i = c + (0.5 - rng.gen::<Float>()) * distribution.radius;
+                    }
+                    first_point
+                }
+            };
+            first_points.push(first_point);
         }
+        let first_points: Vec<_> = first_points
+            .into_iter()
+            .map(|point| local(point, origin))
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "poisson",
+            dimensions = N,
+            radius = distribution.radius,
+            seed = distribution.seed,
+        );
+        #[cfg(feature = "tracing")]
+        span.in_scope(|| tracing::debug!("generation started"));
 
-        Iter {
+        let radius = distribution.radius;
+        let halton = match distribution.candidate_sequence {
+            CandidateSequence::Random => None,
+            CandidateSequence::Halton => Some(Halton::new(quasi_dimensions(N))),
+        };
+        let quota_counts = vec![0; distribution.quotas.map_or(0, |quotas| quotas.len())];
+        let mut iter = Iter {
             distribution,
             rng,
-            sampled: KdTree::new(),
-            // Add our initial point to `active`, to give us somewhere to start, but don't add it to
-            // `sampled` since this initial point never gets returned, creating a void in the output.
-            // See #36
-            active: vec![first_point],
+            sampled: I::with_spacing(radius),
+            samples: Vec::new(),
+            sample_indices: Vec::new(),
+            // Add our initial points to `active`, to give us somewhere to start, but don't add them
+            // to `sampled` since these initial points never get returned, creating a void in the
+            // output. See #36
+            active: first_points.iter().copied().collect(),
+            active_indices: vec![None; first_points.len()].into(),
+            count: 0,
+            parent: None,
+            spacing: None,
+            shuffled: None,
+            stats: GenerationStats::default(),
+            pending: Vec::new(),
+            done: false,
+            existing_radius: None,
+            acceptance: 1.0,
+            halton,
+            observer: None,
+            quota_counts,
+            quota_cell: 0,
+            origin,
+            #[cfg(feature = "simd")]
+            points: Vec::new(),
+            #[cfg(feature = "tracing")]
+            span,
+        };
+
+        // Unless we've been asked to return them after all, in which case they're regular samples;
+        // those outside of our space still serve as starting points, but are never returned
+        if iter.distribution.initial_point_emitted {
+            let mut emitted = Vec::new();
+            for (i, point) in first_points.into_iter().enumerate() {
+                if iter.in_space(point) {
+                    let spacing = iter.nearest_distance(point);
+                    iter.active_indices[i] = Some(iter.count);
+                    iter.sampled.insert(point, iter.count as u64);
+                    iter.samples.push(iter.to_world(point));
+                    iter.sample_indices.push(Some(iter.count));
+                    #[cfg(feature = "simd")]
+                    iter.points.push(point);
+                    iter.count += 1;
+                    iter.count_in_cell(iter.to_world(point));
+                    emitted.push((iter.to_world(point), spacing));
+                }
+            }
+            // `pending` is popped from the back, so reverse them to be returned in order
+            iter.pending = emitted.into_iter().rev().collect();
+        }
+
+        iter
+    }
+
+    /// Load an existing set of points that new samples must keep at least `radius` away from
+    ///
+    /// The larger of `radius` and the distribution's own radius is maintained around these points.
+    /// They are also made active so that the new samples grow around them, but they are never
+    /// returned by the iterator.
+    pub(crate) fn load_existing(&mut self, points: &[Point<N>], radius: Float) {
+        self.existing_radius = Some(self.existing_radius.map_or(radius, |r| r.max(radius)));
+
+        for &point in points {
+            let local = self.to_local(point);
+            self.sampled.insert(local, EXISTING);
+            self.samples.push(point);
+            self.sample_indices.push(None);
+            self.active.push_back(local);
+            self.active_indices.push_back(None);
+        }
+    }
+
+    /// Resume generation with a new, smaller radius
+    ///
+    /// Every point generated so far is kept and made active again, and generation continues by
+    /// filling the gaps between them while maintaining the new radius. The existing spatial index
+    /// is reused, so this is considerably cheaper than [merging](Poisson::merge) into a fresh
+    /// distribution. Repeating this with decreasing radii produces a progressively denser pattern.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut iter = Poisson2D::new().with_radius(0.1).iter();
+    /// let coarse: Vec<_> = iter.by_ref().collect();
+    ///
+    /// iter.densify(0.05);
+    /// let fine: Vec<_> = iter.collect();
+    /// ```
+    ///
+    /// Existing points are never moved nor removed, so a larger radius than before only affects
+    /// points generated from now on. Any [density](Poisson::with_density) is replaced by the new,
+    /// uniform radius.
+    pub fn densify(&mut self, radius: Float) {
+        self.distribution.radius = radius;
+        self.distribution.density = None;
+        self.active = self.samples.iter().map(|&p| self.to_local(p)).collect();
+        self.active_indices = self.sample_indices.iter().copied().collect();
+        self.shuffled = None;
+        self.quota_cell = 0;
+        self.done = false;
+    }
+
+    /// Report every step of generation from now on to `observer`
+    ///
+    /// Candidates generated, rejected, and accepted, and active points retired, are each reported
+    /// as an [`Event`] as they happen, such as to animate the algorithm or to find out why a
+    /// validation function leaves some regions sparse. This replaces any previous observer.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use fast_poisson::Event;
+    ///
+    /// let mut accepted = 0;
+    /// let points: Vec<_> = Poisson2D::new()
+    ///     .iter()
+    ///     .with_observer(move |event| {
+    ///         if let Event::Accepted(point) = event {
+    ///             accepted += 1;
+    ///             println!("point {accepted} at {point:?}");
+    ///         }
+    ///     })
+    ///     .collect();
+    /// ```
+    ///
+    /// See [`Observer`] for details.
+    #[must_use]
+    pub fn with_observer<O>(mut self, observer: O) -> Self
+    where
+        O: Observer<N> + Send + 'static,
+    {
+        self.set_observer(observer);
+
+        self
+    }
+
+    /// Report every step of generation from now on to `observer`
+    ///
+    /// See [`with_observer`][Self::with_observer] for more details.
+    pub fn set_observer<O>(&mut self, observer: O)
+    where
+        O: Observer<N> + Send + 'static,
+    {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Report an event to the observer, if there is one
+    fn observe(&mut self, event: Event<N>) {
+        if let Some(observer) = &mut self.observer {
+            observer.event(event);
+        }
+    }
+
+    /// Reserve room for at least `additional` more points
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.sampled.reserve(additional);
+        self.samples.reserve(additional);
+        self.sample_indices.reserve(additional);
+        #[cfg(feature = "simd")]
+        self.points.reserve(additional);
+    }
+
+    /// The radius currently maintained between generated points
+    pub(crate) fn radius(&self) -> Float {
+        self.distribution.radius
+    }
+
+    /// All points in the pattern so far, including any loaded existing points
+    pub(crate) fn samples(&self) -> &[Point<N>] {
+        &self.samples
+    }
+
+    /// Add a point to the pattern if it is within our space and maintains the radius
+    ///
+    /// Returns true if the point was added.
+    pub(crate) fn insert(&mut self, point: Point<N>) -> bool {
+        let point = self.to_local(point);
+        if self.in_space(point) && !self.in_neighborhood(point) {
+            self.add_point(point);
+
+            true
+        } else {
+            false
         }
     }
 
-    /// Add a point to our pattern
-    fn add_point(&mut self, point: Point<N>) {
+    /// Remove every point in the pattern matching the predicate
+    ///
+    /// Returns the number of points removed.
+    pub(crate) fn remove_where<F>(&mut self, mut predicate: F) -> usize
+    where
+        F: FnMut(&Point<N>) -> bool,
+    {
+        let before = self.samples.len();
+
+        let mut i = 0;
+        while i < self.samples.len() {
+            let point = self.samples[i];
+            if predicate(&point) {
+                let item = self.sample_indices[i].map_or(EXISTING, |index| index as u64);
+                if item != EXISTING {
+                    if let Some(cell) = self.quota_cell_of(point) {
+                        self.quota_counts[cell] -= 1;
+                    }
+                }
+                self.sampled.delete(self.to_local(point), item);
+                self.samples.remove(i);
+                self.sample_indices.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut i = 0;
+        while i < self.active.len() {
+            if predicate(&self.to_world(self.active[i])) {
+                self.retire(i);
+            } else {
+                i += 1;
+            }
+        }
+        // Cells may have fallen short of their quotas again
+        self.quota_cell = 0;
+
+        before - self.samples.len()
+    }
+
+    /// Remove the `i`th point from the active list
+    ///
+    /// Only random selection can do without the order of the active list, so only then is it
+    /// disturbed to save moving points around.
+    fn retire(&mut self, i: usize) {
+        if self.observer.is_some() {
+            self.observe(Event::Retired(self.to_world(self.active[i])));
+        }
+
+        if self.distribution.selection == SelectionStrategy::Random {
+            self.active.swap_remove_back(i);
+            self.active_indices.swap_remove_back(i);
+        } else {
+            self.active.remove(i);
+            self.active_indices.remove(i);
+        }
+    }
+
+    /// Add a point to our pattern, returning it in world coordinates
+    fn add_point(&mut self, point: Point<N>) -> Point<N> {
+        self.spacing = self.nearest_distance(point);
+
         // Add it to the active list
-        self.active.push(point);
+        self.active.push_back(point);
+        self.active_indices.push_back(Some(self.count));
 
         // Now stash this point in our samples
-        self.sampled.add(&point, 0);
+        let world = self.to_world(point);
+        self.sampled.insert(point, self.count as u64);
+        self.samples.push(world);
+        self.sample_indices.push(Some(self.count));
+        #[cfg(feature = "simd")]
+        self.points.push(point);
+        self.count += 1;
+        self.count_in_cell(world);
+        self.observe(Event::Accepted(world));
+
+        #[cfg(feature = "tracing")]
+        if self.count % PROGRESS_INTERVAL == 0 {
+            tracing::trace!(
+                points = self.count,
+                indexed = self.samples.len(),
+                active = self.active.len(),
+                "generation progress"
+            );
+        }
+
+        world
     }
 
-    /// Generate a random point between `radius` and `2 * radius` away from the given point
-    fn generate_random_point(&mut self, around: Point<N>) -> Point<N> {
-        // Pick a random distance away from our point
-        let dist = self.distribution.radius * (1.0 + self.rng.gen::<Float>());
+    /// Generate the next point, in the natural order
+    fn generate_next(&mut self) -> Option<Point<N>> {
+        if let Some((point, spacing)) = self.pending.pop() {
+            self.parent = None;
+            self.spacing = spacing;
+
+            return Some(point);
+        }
+        if self.done {
+            return None;
+        }
+
+        while !self.active.is_empty() {
+            let i = match self.distribution.selection {
+                SelectionStrategy::Random => self.rng.gen_range(0..self.active.len()),
+                SelectionStrategy::Oldest => 0,
+                SelectionStrategy::Newest => self.active.len() - 1,
+            };
+
+            if let Some(halton) = &mut self.halton {
+                halton.restart(&mut self.rng);
+            }
+            let found = self.sample_around(self.active[i]);
+            let accepted = if found.is_some() { 1.0 } else { 0.0 };
+            self.acceptance += (accepted - self.acceptance) / ACCEPTANCE_WINDOW;
+
+            if let Some(point) = found {
+                // We've got a good one!
+                let point = self.add_point(point);
+                self.parent = self.active_indices[i];
+
+                return Some(point);
+            }
 
-        // Generate a randomly distributed vector
-        let mut vector: [Float; N] = [0.0; N];
-        for i in vector.iter_mut() {
-            *i = self.rng.sample(StandardNormal);
+            self.retire(i);
+            self.stats.retired += 1;
         }
-        // Now find this new vector's magnitude
-        let mag = vector.iter().map(|&x| x.powi(2)).sum::<Float>().sqrt();
 
-        // Dividing each of the vector's components by `mag` will produce a unit vector; then by
-        // multiplying each component by `dist`, we'll have a vector pointing `dist` away from the
-        // origin. If we then add each of those components to our point, we'll have effectively
-        // translated our point by `dist` in a randomly chosen direction.
-        // Conveniently, we can do all of this in just one step!
-        let mut point = [0.0; N];
-        let translate = dist / mag; // compute this just once!
-        for i in 0..N {
-            point[i] = around[i] + vector[i] * translate;
+        // We may not have reached every part of our space; if so, start growing from a new point
+        let point = self.fill_quota().or_else(|| self.reseed());
+        self.done = point.is_none();
+
+        #[cfg(feature = "tracing")]
+        if self.done {
+            tracing::debug!(
+                points = self.count,
+                candidates = self.stats.candidates,
+                accepted = self.stats.accepted,
+                "generation finished"
+            );
         }
 
         point
     }
 
+    /// Find an acceptable point among `num_samples` candidates around the given point
+    #[cfg(not(feature = "simd"))]
+    fn sample_around(&mut self, around: Point<N>) -> Option<Point<N>> {
+        self.sample_each(around)
+    }
+
+    /// Find an acceptable point among `num_samples` candidates around the given point
+    ///
+    /// Candidates lie within `outer * radius` of `around`, for the outer edge of the annulus, so any
+    /// point within `radius` of a candidate lies within `(outer + 1) * radius` of `around`. A single
+    /// spatial query for those points thus replaces one query per candidate, and each candidate is
+    /// then tested against all of them using SIMD lanes. Candidates are still generated and tested
+    /// one at a time, so the distribution is the same as without SIMD.
+    ///
+    /// This only applies to a uniform, strict radius without any existing points; otherwise each
+    /// candidate is tested with its own query.
+    #[cfg(feature = "simd")]
+    fn sample_around(&mut self, around: Point<N>) -> Option<Point<N>> {
+        if self.distribution.density.is_some()
+            || self.existing_radius.is_some()
+            || self.distribution.radius_tolerance > 0.0
+        {
+            return self.sample_each(around);
+        }
+
+        let radius = self.distribution.radius;
+        let [_, outer] = self.distribution.annulus;
+        let neighborhood = Neighborhood::new(
+            self.sampled
+                .within_squared(around, ((outer + 1.0) * radius).powi(2))
+                .into_iter()
+                .map(|(item, _)| self.points[item as usize]),
+        );
+
+        for _ in 0..self.samples_per_point() {
+            let candidate = self.generate_random_point(around);
+            let in_space = self.in_space(candidate);
+            let too_close = in_space && neighborhood.any_within(candidate, radius.powi(2));
+            if self.record_candidate(candidate, in_space, too_close) {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    /// Generate up to `num_samples` candidates around the given point, one at a time, returning the
+    /// first acceptable one
+    fn sample_each(&mut self, around: Point<N>) -> Option<Point<N>> {
+        for _ in 0..self.samples_per_point() {
+            // Generate up to `num_samples` random points within the annulus around the current point
+            let point = self.generate_random_point(around);
+
+            // Ensure we've picked a point inside the bounds of our rectangle, and more than `radius`
+            // distance from any other sampled point
+            if self.is_acceptable(point) {
+                return Some(point);
+            }
+        }
+
+        None
+    }
+
+    /// Generate a random point within the annulus around the given point
+    ///
+    /// A point outside the box is moved back into it as the [boundary](Poisson::with_boundary)
+    /// specifies. The point is snapped to the closest one representable in world coordinates, so
+    /// that the distances we test are exactly those between the points we return.
+    fn generate_random_point(&mut self, around: Point<N>) -> Point<N> {
+        let radius = self.distribution.radius_at(self.to_world(around));
+        let point = match &mut self.halton {
+            Some(halton) => {
+                quasi_point_around(&self.distribution, radius, halton.next_point(), around)
+            }
+            None => random_point_around(&self.distribution, radius, &mut self.rng, around),
+        };
+        let world = self.distribution.bounded(self.to_world(point));
+
+        if self.observer.is_some() {
+            self.observe(Event::Candidate {
+                point: world,
+                around: Some(self.to_world(around)),
+            });
+        }
+
+        self.to_local(world)
+    }
+
+    /// Try to find a fresh starting point anywhere in the reseeding box
+    ///
+    /// The point is added to the pattern and returned if one was found within the configured
+    /// number of attempts.
+    fn reseed(&mut self) -> Option<Point<N>> {
+        let [min, max] = self.distribution.reseed_bounds;
+
+        for _ in 0..self.distribution.reseed_attempts {
+            let mut point = [0.0; N];
+            for (i, p) in point.iter_mut().enumerate() {
+                *p = min[i] + (max[i] - min[i]) * self.rng.gen::<Float>();
+            }
+            self.observe(Event::Candidate {
+                point,
+                around: None,
+            });
+            let point = self.to_local(point);
+
+            if self.is_acceptable(point) {
+                let point = self.add_point(point);
+                self.parent = None;
+
+                return Some(point);
+            }
+        }
+
+        None
+    }
+
+    /// Try to find a point in the first cell holding fewer points than its minimum quota
+    ///
+    /// The point is added to the pattern and returned if one was found. A cell is given up on once
+    /// a point could not be found within it after a number of attempts.
+    fn fill_quota(&mut self) -> Option<Point<N>> {
+        let quotas = self.distribution.quotas?;
+        let bounds = self.distribution.sampled_box();
+
+        while self.quota_cell < self.quota_counts.len() {
+            if self.quota_counts[self.quota_cell] < quotas.min {
+                let [min, max] = quotas.cell_bounds(self.quota_cell, bounds);
+                for _ in 0..QUOTA_ATTEMPTS {
+                    let point: Point<N> = std::array::from_fn(|i| {
+                        min[i] + (max[i] - min[i]) * self.rng.gen::<Float>()
+                    });
+                    self.observe(Event::Candidate {
+                        point,
+                        around: None,
+                    });
+                    let point = self.to_local(point);
+
+                    if self.is_acceptable(point) {
+                        let point = self.add_point(point);
+                        self.parent = None;
+
+                        return Some(point);
+                    }
+                }
+            }
+
+            self.quota_cell += 1;
+        }
+
+        None
+    }
+
+    /// The cell of the quotas containing the point, in world coordinates, if there are quotas
+    fn quota_cell_of(&self, point: Point<N>) -> Option<usize> {
+        self.distribution
+            .quotas?
+            .cell(point, self.distribution.sampled_box())
+    }
+
+    /// Count a point, in world coordinates, against the quota of its cell
+    fn count_in_cell(&mut self, point: Point<N>) {
+        if let Some(cell) = self.quota_cell_of(point) {
+            self.quota_counts[cell] += 1;
+        }
+    }
+
+    /// Returns true if the candidate point is within our space and maintains the radius
+    ///
+    /// The outcome is recorded in our statistics.
+    fn is_acceptable(&mut self, point: Point<N>) -> bool {
+        let in_space = self.in_space(point);
+        let too_close = in_space && self.in_neighborhood(point);
+
+        self.record_candidate(point, in_space, too_close)
+    }
+
+    /// Record the outcome of testing a candidate point in our statistics, and report rejections
+    ///
+    /// Returns true if the candidate was accepted.
+    fn record_candidate(&mut self, point: Point<N>, in_space: bool, too_close: bool) -> bool {
+        self.stats.candidates += 1;
+
+        if !in_space {
+            self.stats.out_of_domain += 1;
+            if self.observer.is_some() {
+                self.observe(Event::OutOfDomain(self.to_world(point)));
+            }
+            false
+        } else if too_close {
+            self.stats.too_close += 1;
+            if self.observer.is_some() {
+                self.observe(Event::TooClose(self.to_world(point)));
+            }
+            false
+        } else {
+            self.stats.accepted += 1;
+            true
+        }
+    }
+
+    /// The number of candidates to generate around the next active point
+    ///
+    /// With [adaptive samples](Poisson::with_adaptive_samples) this shrinks along with the recent
+    /// acceptance rate, from the maximum while nearly every active point still has room around it
+    /// to the minimum once almost none do.
+    fn samples_per_point(&self) -> u32 {
+        match self.distribution.adaptive_samples {
+            None => self.distribution.num_samples,
+            Some([min, max]) => {
+                min + ((max - min) as Float * self.acceptance.clamp(0.0, 1.0)).round() as u32
+            }
+        }
+    }
+
+    /// Statistics about the generation so far
+    pub(crate) fn stats(&self) -> &GenerationStats {
+        &self.stats
+    }
+
     /// Returns true if the point is within the bounds of our space.
     ///
-    /// This is true if 0 ≤ point[i] < dimensions[i]
+    /// This is true if 0 ≤ point[i] < dimensions[i], and the point's cell, if there are
+    /// [quotas](Poisson::with_quotas), has room for it.
     fn in_space(&self, point: Point<N>) -> bool {
-        (self.distribution.validate)(point, &self.distribution.validate_user_data)
+        let world = self.to_world(point);
+        let has_room = match (self.distribution.quotas, self.quota_cell_of(world)) {
+            (Some(quotas), Some(cell)) => self.quota_counts[cell] < quotas.max,
+            _ => true,
+        };
+
+        has_room && self.distribution.in_space(world)
+    }
+
+    /// Translate a point from our local frame into world coordinates
+    fn to_world(&self, point: Point<N>) -> Point<N> {
+        let mut world = point;
+        for (w, o) in world.iter_mut().zip(self.origin) {
+            *w += o;
+        }
+
+        world
+    }
+
+    /// Translate a point from world coordinates into our local frame
+    fn to_local(&self, point: Point<N>) -> Point<N> {
+        local(point, self.origin)
     }
 
     /// Returns true if there is at least one other sample point within `radius` of this point
-    fn in_neighborhood(&self, point: Point<N>) -> bool {
-        !self
-            .sampled
-            .within::<SquaredEuclidean>(&point, self.distribution.radius.powi(2))
-            .is_empty()
+    ///
+    /// Existing points loaded with [`Iter::load_existing`] may demand a larger radius.
+    ///
+    /// This is the hottest path in generation, so it is answered with
+    /// [`any_within`](SpatialIndex::any_within), which the default index answers with a nearest
+    /// neighbor query that unlike a range query needs no allocation.
+    fn in_neighborhood(&mut self, point: Point<N>) -> bool {
+        let local = self.distribution.radius_at(self.to_world(point));
+        if self.sampled.any_within(point, local.powi(2)) && !self.tolerated(point, local) {
+            return true;
+        }
+
+        // Existing points may be further away, yet still too close for their larger radius
+        match self.existing_radius {
+            Some(existing) if existing > local => self
+                .sampled
+                .within_squared(point, existing.powi(2))
+                .into_iter()
+                .any(|(item, _)| item == EXISTING),
+            _ => false,
+        }
+    }
+
+    /// Returns true if a point closer than `radius` to another may be kept anyway
+    ///
+    /// With a [radius tolerance](Poisson::with_radius_tolerance), a point whose nearest neighbor
+    /// is within the tolerance is kept with a probability falling linearly from 1 at the radius to
+    /// 0 at the closest distance allowed.
+    fn tolerated(&mut self, point: Point<N>, radius: Float) -> bool {
+        let tolerance = self.distribution.radius_tolerance;
+        if tolerance <= 0.0 {
+            return false;
+        }
+
+        let closest = radius * (1.0 - tolerance);
+        match self.nearest_distance(point) {
+            Some(nearest) if nearest >= closest => {
+                self.rng.gen::<Float>() * (radius - closest) < nearest - closest
+            }
+            _ => false,
+        }
+    }
+
+    /// Generate every remaining point, and shuffle them or order them progressively
+    ///
+    /// The reordered points are returned in reverse order, ready to be popped, along with their
+    /// parents' indices in the reordered output, and their spacing.
+    fn reorder_remaining(&mut self) -> Vec<Shuffled<N>> {
+        // Points already returned keep their indices
+        let base = self.count - self.pending.len();
+
+        let mut batch = Vec::new();
+        while let Some(point) = self.generate_next() {
+            batch.push((point, self.parent, self.spacing));
+        }
+
+        let order = if self.distribution.progressive_output {
+            let points: Vec<Point<N>> = batch.iter().map(|&(point, _, _)| point).collect();
+            elimination::rank(&points, estimate::volume(&self.distribution))
+        } else {
+            let mut order: Vec<usize> = (0..batch.len()).collect();
+            order.shuffle(&mut self.rng);
+            order
+        };
+
+        let mut position = vec![0; batch.len()];
+        for (i, &j) in order.iter().enumerate() {
+            position[j] = base + i;
+        }
+
+        order
+            .into_iter()
+            .rev()
+            .map(|j| {
+                let (point, parent, spacing) = batch[j];
+                let parent = parent.map(|p| if p < base { p } else { position[p - base] });
+
+                (point, parent, spacing)
+            })
+            .collect()
+    }
+
+    /// Find the neighbors within `distance` of every generated point
+    ///
+    /// Returns the generated points in the order they were added, along with the indices of each
+    /// point's neighbors, in ascending order. Loaded existing points are not included.
+    pub(crate) fn neighbors(&self, distance: Float) -> (Vec<Point<N>>, Vec<Vec<usize>>) {
+        let mut points = Vec::new();
+        let mut neighbors = Vec::new();
+
+        for (point, index) in self.samples.iter().zip(self.sample_indices.iter()) {
+            let Some(index) = *index else { continue };
+
+            let mut adjacent: Vec<usize> = self
+                .sampled
+                .within_squared(self.to_local(*point), distance.powi(2))
+                .into_iter()
+                .filter(|&(item, _)| item != EXISTING && item != index as u64)
+                .map(|(item, _)| item as usize)
+                .collect();
+            adjacent.sort_unstable();
+
+            points.push(*point);
+            neighbors.push(adjacent);
+        }
+
+        (points, neighbors)
+    }
+
+    /// Returns the distance to the closest sample point, if there are any
+    fn nearest_distance(&self, point: Point<N>) -> Option<Float> {
+        self.sampled
+            .nearest(point)
+            .map(|(_, distance)| distance.sqrt())
+    }
+
+    /// Call `f` with every remaining point, in the order they would be returned by `next`
+    ///
+    /// Generation runs in a single loop within our span, rather than entering it and checking the
+    /// order of the output anew for every point.
+    pub(crate) fn for_each_point<F: FnMut(Point<N>)>(mut self, mut f: F) {
+        #[cfg(feature = "tracing")]
+        let _span = self.span.clone().entered();
+
+        if self.distribution.shuffled_output || self.distribution.progressive_output {
+            let shuffled = match self.shuffled.take() {
+                Some(shuffled) => shuffled,
+                None => self.reorder_remaining(),
+            };
+            // Reordered points are kept in reverse, ready to be popped
+            for (point, _, _) in shuffled.into_iter().rev() {
+                f(self.distribution.transformed(point));
+            }
+            return;
+        }
+
+        while let Some(point) = self.generate_next() {
+            f(self.distribution.transformed(point));
+        }
     }
 
     pub(crate) fn to_empty(mut self) -> Self {
         while self.next().is_some() {}
         self
-    } 
+    }
 
-    pub(crate) fn to_sampled(self) -> KdTree<Float, N> {
-        self.sampled
+    /// The spatial index of every point in the pattern, in world coordinates
+    #[cfg(feature = "kiddo")]
+    pub(crate) fn to_sampled(self) -> I {
+        if self.origin == [0.0; N] {
+            return self.sampled;
+        }
+
+        let mut sampled = I::with_spacing(self.distribution.radius);
+        for (point, index) in self.samples.iter().zip(self.sample_indices) {
+            sampled.insert(*point, index.map_or(EXISTING, |index| index as u64));
+        }
+
+        sampled
     }
 }
 
-impl<const N: usize, U, R> Iterator for Iter<N, U, R>
+/// Translate a point from world coordinates into the frame with the given origin
+fn local<const N: usize>(point: Point<N>, origin: Point<N>) -> Point<N> {
+    let mut local = point;
+    for (l, o) in local.iter_mut().zip(origin) {
+        *l -= o;
+    }
+
+    local
+}
+
+/// Generate a random point within the distribution's annulus around the given point, scaled by
+/// `radius`
+pub(crate) fn random_point_around<const N: usize, U, R, G>(
+    distribution: &Poisson<N, U, R>,
+    radius: Float,
+    rng: &mut G,
+    around: Point<N>,
+) -> Point<N>
 where
     U: Default + Clone,
     R: Rng + SeedableRng,
+    G: Rng,
 {
-    type Item = Point<N>;
+    // Pick a random distance away from our point
+    let dist = annulus_distance(distribution, radius, rng.gen());
 
-    fn next(&mut self) -> Option<Point<N>> {
-        while !self.active.is_empty() {
-            dbg!(&self.active);
+    // Then translate our point by `dist` in a randomly chosen direction
+    let direction: [Float; N] = random_direction(rng);
+    let mut point = [0.0; N];
+    for i in 0..N {
+        point[i] = around[i] + direction[i] * dist;
+    }
 
-            let i = self.rng.gen_range(0..self.active.len());
+    point
+}
 
-            for _ in 0..self.distribution.num_samples {
-                // Generate up to `num_samples` random points between radius and 2*radius from the current point
-                let point = self.generate_random_point(self.active[i]);
+/// Place a point within the distribution's annulus around the given point, scaled by `radius`,
+/// from a point of a low-discrepancy sequence
+///
+/// The first coordinate of `sequence` picks the distance, and the rest the direction; there must
+/// be [`quasi_dimensions`] of them.
+fn quasi_point_around<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    radius: Float,
+    sequence: &[Float],
+    around: Point<N>,
+) -> Point<N>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let dist = annulus_distance(distribution, radius, sequence[0]);
 
-                // Ensure we've picked a point inside the bounds of our rectangle, and more than `radius`
-                // distance from any other sampled point
-                if self.in_space(point) && !self.in_neighborhood(point) {
-                    // We've got a good one!
-                    self.add_point(point);
+    let direction: [Float; N] = quasi_direction(&sequence[1..]);
+    let mut point = [0.0; N];
+    for i in 0..N {
+        point[i] = around[i] + direction[i] * dist;
+    }
+
+    point
+}
 
-                    return Some(point);
+/// The distance within the distribution's annulus, scaled by `radius`, at `fraction` of the way
+/// from its inner to its outer edge, or of its volume with [`Poisson::with_volume_uniform`]
+fn annulus_distance<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    radius: Float,
+    fraction: Float,
+) -> Float
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let [inner, outer] = distribution.annulus;
+    if distribution.volume_uniform {
+        // The volume within a distance grows with its Nth power, so spread that evenly instead
+        let (inner, outer) = (inner.powi(N as i32), outer.powi(N as i32));
+        radius * (inner + (outer - inner) * fraction).powf(1.0 / N as Float)
+    } else {
+        radius * (inner + (outer - inner) * fraction)
+    }
+}
+
+/// The number of coordinates of a low-discrepancy sequence needed to place a candidate in `n`
+/// dimensions: one for the distance, and the rest for the direction
+fn quasi_dimensions(n: usize) -> usize {
+    1 + match n {
+        2 => 1,
+        3 => 2,
+        // Pairs of coordinates for the Box-Muller transform
+        _ => n + n % 2,
+    }
+}
+
+/// Map coordinates within the unit hypercube onto a unit vector, evenly
+///
+/// This follows [`random_direction`], except that in other than 2 and 3 dimensions the normally
+/// distributed vector is drawn by the Box-Muller transform.
+fn quasi_direction<const N: usize>(sequence: &[Float]) -> [Float; N] {
+    let tau = std::f64::consts::TAU as Float;
+    let mut direction = [0.0; N];
+
+    match N {
+        2 => {
+            let (sin, cos) = (tau * sequence[0]).sin_cos();
+            direction[0] = cos;
+            direction[1] = sin;
+        }
+        3 => {
+            let z = 2.0 * sequence[0] - 1.0;
+            let r = (1.0 - z * z).sqrt();
+            let (sin, cos) = (tau * sequence[1]).sin_cos();
+            direction[0] = r * cos;
+            direction[1] = r * sin;
+            direction[2] = z;
+        }
+        _ => {
+            for (i, pair) in sequence.chunks_exact(2).enumerate() {
+                let r = (-2.0 * (1.0 - pair[0]).ln()).sqrt();
+                let (sin, cos) = (tau * pair[1]).sin_cos();
+                direction[2 * i] = r * cos;
+                if 2 * i + 1 < N {
+                    direction[2 * i + 1] = r * sin;
                 }
             }
+            let mag = direction.iter().map(|&x| x.powi(2)).sum::<Float>().sqrt();
+            for i in direction.iter_mut() {
+                *i /= mag;
+            }
+        }
+    }
+
+    direction
+}
 
-            self.active.swap_remove(i);
+/// Generate a uniformly distributed unit vector
+///
+/// In 2 and 3 dimensions the direction is drawn directly from angles, which is considerably
+/// cheaper than the general method. `N` is a constant, so only one branch is ever compiled in.
+fn random_direction<const N: usize, R: Rng>(rng: &mut R) -> [Float; N] {
+    let tau = std::f64::consts::TAU as Float;
+    let mut direction = [0.0; N];
+
+    match N {
+        2 => {
+            let (sin, cos) = (tau * rng.gen::<Float>()).sin_cos();
+            direction[0] = cos;
+            direction[1] = sin;
+        }
+        3 => {
+            // Archimedes: the height of a uniform point on a sphere is itself uniform
+            let z = 2.0 * rng.gen::<Float>() - 1.0;
+            let r = (1.0 - z * z).sqrt();
+            let (sin, cos) = (tau * rng.gen::<Float>()).sin_cos();
+            direction[0] = r * cos;
+            direction[1] = r * sin;
+            direction[2] = z;
         }
+        _ => {
+            // Generate a randomly distributed vector
+            for i in direction.iter_mut() {
+                *i = rng.sample(StandardNormal);
+            }
+            // Now find this new vector's magnitude
+            let mag = direction.iter().map(|&x| x.powi(2)).sum::<Float>().sqrt();
 
-        None
+            // Dividing each of the vector's components by `mag` will produce a unit vector
+            for i in direction.iter_mut() {
+                *i /= mag;
+            }
+        }
+    }
+
+    direction
+}
+
+impl<const N: usize, U, R, I> Iterator for Iter<N, U, R, I>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    I: SpatialIndex<N>,
+{
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Point<N>> {
+        #[cfg(feature = "tracing")]
+        let _span = self.span.clone().entered();
+
+        if !self.distribution.shuffled_output && !self.distribution.progressive_output {
+            let point = self.generate_next()?;
+            return Some(self.distribution.transformed(point));
+        }
+
+        if self.shuffled.is_none() {
+            self.shuffled = Some(self.reorder_remaining());
+        }
+        let (point, parent, spacing) = self.shuffled.as_mut()?.pop()?;
+        self.parent = parent;
+        self.spacing = spacing;
+
+        Some(self.distribution.transformed(point))
     }
 }
 
-impl<const N: usize, U: Default + Clone> FusedIterator for Iter<N, U> {}
+impl<const N: usize, U: Default + Clone, I: SpatialIndex<N>> FusedIterator for Iter<N, U, Rand, I> {}
+
+/// An iterator over the points in the Poisson disk distribution, along with the index of the
+/// point each was generated around
+///
+/// See [`Poisson::iter_with_parent`].
+pub struct WithParent<const N: usize, U, R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The underlying iterator
+    iter: Iter<N, U, R>,
+}
+
+impl<const N: usize, U, R> WithParent<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Report the parent of each point of the iterator
+    pub(crate) fn new(iter: Iter<N, U, R>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<const N: usize, U, R> Iterator for WithParent<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = (Option<usize>, Point<N>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next()?;
+
+        Some((self.iter.parent, point))
+    }
+}
+
+impl<const N: usize, U: Default + Clone> FusedIterator for WithParent<N, U> {}
+
+/// An iterator over the points in the Poisson disk distribution, along with the distance from each
+/// to the closest point accepted before it
+///
+/// See [`Poisson::iter_with_spacing`].
+pub struct WithSpacing<const N: usize, U, R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The underlying iterator
+    iter: Iter<N, U, R>,
+}
+
+impl<const N: usize, U, R> WithSpacing<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Report the spacing of each point of the iterator
+    pub(crate) fn new(iter: Iter<N, U, R>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<const N: usize, U, R> Iterator for WithSpacing<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = (Point<N>, Option<Float>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next()?;
+
+        Some((point, self.iter.spacing))
+    }
+}
+
+impl<const N: usize, U: Default + Clone> FusedIterator for WithSpacing<N, U> {}
+
+/// An iterator over the points in the Poisson disk distribution, along with attributes drawn for
+/// each from the distribution's own RNG
+///
+/// See [`Poisson::iter_scatter`].
+pub struct Scatter<const N: usize, U, R, F>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The underlying iterator, whose RNG we share
+    iter: Iter<N, U, R>,
+    /// Function drawing the attributes for each point
+    attributes: F,
+}
+
+impl<const N: usize, U, R, F, T> Scatter<N, U, R, F>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    F: FnMut(&mut R, Point<N>) -> T,
+{
+    /// Scatter attributes over the points of the iterator
+    pub(crate) fn new(iter: Iter<N, U, R>, attributes: F) -> Self {
+        Self { iter, attributes }
+    }
+}
+
+impl<const N: usize, U, R, F, T> Iterator for Scatter<N, U, R, F>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    F: FnMut(&mut R, Point<N>) -> T,
+{
+    type Item = (Point<N>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next()?;
+        let attributes = (self.attributes)(&mut self.iter.rng, point);
+
+        Some((point, attributes))
+    }
+}
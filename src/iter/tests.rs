@@ -16,7 +16,6 @@ fn adding_points() {
     iter.add_point(point);
 
     assert!(iter.active.contains(&point));
-
 }
 
 #[test]
@@ -103,7 +102,7 @@ fn in_space() {
 fn distant_point_has_no_neighbors() {
     let mut iter = Poisson::<2>::new().iter();
     // Flush the k-d tree
-    iter.sampled = KdTree::new();
+    iter.sampled = DefaultIndex::with_spacing(0.1);
 
     // Add test point
     iter.add_point([0.9, 0.9]);
@@ -117,7 +116,7 @@ fn distant_point_has_no_neighbors() {
 fn point_has_neighbors() {
     let mut iter = Poisson::<2>::new().iter();
     // Flush the k-d tree
-    iter.sampled = KdTree::new();
+    iter.sampled = DefaultIndex::with_spacing(0.1);
 
     // Add test point
     iter.add_point([0.2, 0.2]);
@@ -125,3 +124,506 @@ fn point_has_neighbors() {
     assert!(iter.in_neighborhood([0.2, 0.2])); // Same point is a neighbor
     assert!(iter.in_neighborhood([0.2005, 0.2])); // Close point is a neighbor
 }
+
+#[test]
+fn first_point_is_respected() {
+    let first_point = [0.25, 0.75];
+    let mut iter = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_first_point(first_point)
+        .iter();
+
+    assert_eq!(iter.to_world(iter.active[0]), first_point);
+
+    // The first generated point must have been spawned around our first point
+    let point = iter.next().unwrap();
+    let r = ((point[0] - first_point[0]).powi(2) + (point[1] - first_point[1]).powi(2)).sqrt();
+    assert!(iter.distribution.radius <= r);
+    assert!(r < iter.distribution.radius * 2.);
+}
+
+#[test]
+fn initial_point_emitted() {
+    let first_point = [0.5, 0.5];
+    let poisson = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_first_point(first_point)
+        .with_initial_point_emitted(true);
+    let radius_sq = poisson.radius.powi(2);
+    let points = poisson.generate();
+
+    assert_eq!(points[0], first_point);
+    // No void around the initial point, but no crowding either
+    assert!(points[1..]
+        .iter()
+        .all(|p| (p[0] - first_point[0]).powi(2) + (p[1] - first_point[1]).powi(2) >= radius_sq));
+}
+
+#[test]
+fn random_initial_point_emitted_when_valid() {
+    let (mut valid, mut invalid) = (false, false);
+
+    for seed in 0..50 {
+        let mut iter = Poisson2D::new()
+            .with_seed(seed)
+            .with_initial_point_emitted(true)
+            .iter();
+        let first_point = iter.active[0];
+        let next = iter.next();
+
+        // The random first point straddles the origin, so it may fall outside of our space
+        if iter.in_space(first_point) {
+            valid = true;
+            assert_eq!(next, Some(first_point));
+            assert!(iter.in_neighborhood(first_point));
+        } else {
+            invalid = true;
+            assert_ne!(next, Some(first_point));
+            assert!(!iter.in_neighborhood(first_point));
+        }
+    }
+
+    assert!(valid && invalid, "Both paths should be exercised");
+}
+
+#[test]
+fn invalid_initial_point_not_emitted() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_first_point([5.0, 5.0])
+        .with_initial_point_emitted(true)
+        .generate();
+
+    assert!(points.is_empty());
+}
+
+/// Two disjoint islands
+fn islands([x, y]: Point<2>, _: &()) -> bool {
+    (0.0..0.3).contains(&x) && (0.0..1.0).contains(&y)
+        || (0.7..1.0).contains(&x) && (0.0..1.0).contains(&y)
+}
+
+#[test]
+fn multiple_first_points() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ())
+        .with_first_points(&[[0.15, 0.5], [0.85, 0.5]])
+        .generate();
+
+    assert!(points.iter().any(|p| p[0] < 0.5));
+    assert!(points.iter().any(|p| p[0] > 0.5));
+}
+
+#[test]
+fn reseeding_fills_disjoint_regions() {
+    let without = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ())
+        .with_first_point([0.15, 0.5])
+        .generate();
+    assert!(!without.is_empty());
+    assert!(without.iter().all(|p| p[0] < 0.5));
+
+    let with = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ())
+        .with_first_point([0.15, 0.5])
+        .with_reseeding(1000, [0.0, 0.0], [1.0, 1.0])
+        .generate();
+    assert!(with.iter().any(|p| p[0] > 0.5));
+}
+
+#[test]
+fn reseeding_respects_bounds() {
+    // Neither island contains the first point, and only the right one is within the box
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ())
+        .with_first_point([0.5, 0.5])
+        .with_reseeding(1000, [0.5, 0.0], [1.0, 1.0])
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|p| p[0] > 0.5));
+}
+
+#[test]
+fn reseeding_is_fused() {
+    let mut iter = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ())
+        .with_first_point([0.15, 0.5])
+        .with_reseeding(10, [0.0, 0.0], [1.0, 1.0])
+        .iter();
+
+    while iter.next().is_some() {}
+
+    for _ in 0..100 {
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn existing_points_larger_radius() {
+    let mut iter = Poisson::<2>::new().iter();
+    // Flush the k-d tree
+    iter.sampled = DefaultIndex::with_spacing(0.1);
+
+    iter.load_existing(&[[0.5, 0.5]], 0.3);
+    iter.add_point([0.1, 0.1]);
+
+    // Existing points keep their larger radius...
+    assert!(iter.in_neighborhood([0.5, 0.75]));
+    assert!(!iter.in_neighborhood([0.5, 0.85]));
+    // ...while generated points keep the distribution's own radius
+    assert!(iter.in_neighborhood([0.1, 0.15]));
+    assert!(!iter.in_neighborhood([0.1, 0.25]));
+}
+
+#[test]
+fn densify_respects_existing_points() {
+    let mut iter = Poisson2D::new().with_seed(0xBADBEEF).iter();
+    let coarse: Vec<_> = iter.by_ref().collect();
+
+    iter.densify(0.05);
+    let fine: Vec<_> = iter.collect();
+
+    assert!(!fine.is_empty());
+    let all: Vec<_> = coarse.iter().chain(fine.iter()).collect();
+    for (i, a) in all.iter().enumerate() {
+        for b in all[(i + 1)..].iter() {
+            let dist = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+            assert!(dist >= 0.05);
+        }
+    }
+}
+
+#[test]
+fn parents_precede_children() {
+    let points: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .iter_with_parent()
+        .collect();
+
+    // Points grown from the unemitted first point have no parent in the output, including the
+    // very first one returned
+    assert_eq!(points[0].0, None);
+    assert!(points.iter().filter(|(parent, _)| parent.is_some()).count() > points.len() / 2);
+    for (i, (parent, point)) in points.iter().enumerate() {
+        let Some(parent) = *parent else { continue };
+        assert!(parent < i);
+
+        // Children lie within the annulus around their parents
+        let parent = points[parent].1;
+        let r = ((point[0] - parent[0]).powi(2) + (point[1] - parent[1]).powi(2)).sqrt();
+        assert!((0.1..0.2).contains(&r));
+    }
+}
+
+#[test]
+fn spacing_is_distance_to_nearest_earlier_point() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .generate_with_spacing();
+
+    assert_eq!(points[0].1, None);
+    for (i, (point, spacing)) in points.iter().enumerate().skip(1) {
+        let nearest = points[..i]
+            .iter()
+            .map(|(other, _)| {
+                ((point[0] - other[0]).powi(2) + (point[1] - other[1]).powi(2)).sqrt()
+            })
+            .fold(Float::MAX, Float::min);
+
+        let spacing = spacing.expect("Every later point should have a spacing");
+        assert!(spacing >= 0.1);
+        assert!((spacing - nearest).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn shuffled_output() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let natural = poisson.generate();
+    let shuffled = poisson.clone().with_shuffled_output(true).generate();
+
+    // Deterministic, and the same points, but not in the same order
+    assert_eq!(
+        shuffled,
+        poisson.clone().with_shuffled_output(true).generate()
+    );
+    assert_ne!(natural, shuffled);
+    let mut sorted = shuffled.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut expected = natural.clone();
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(sorted, expected);
+
+    // Parents still refer to the right points
+    let points: Vec<_> = poisson
+        .with_shuffled_output(true)
+        .iter_with_parent()
+        .collect();
+    for (parent, point) in points.iter() {
+        let Some(parent) = *parent else { continue };
+        let parent = points[parent].1;
+        let r = ((point[0] - parent[0]).powi(2) + (point[1] - parent[1]).powi(2)).sqrt();
+        assert!((0.1..0.2).contains(&r));
+    }
+}
+
+#[test]
+fn progressive_output() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let natural = poisson.generate();
+    let progressive = poisson.clone().with_progressive_output(true).generate();
+
+    // Deterministic, and the same points
+    assert_eq!(
+        progressive,
+        poisson.clone().with_progressive_output(true).generate()
+    );
+    let mut sorted = progressive.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut expected = natural;
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(sorted, expected);
+
+    // Every prefix is spread across the space, far more than the radius apart
+    let min_distance = |points: &[Point<2>]| {
+        let mut min = Float::INFINITY;
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                min = min.min(((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt());
+            }
+        }
+        min
+    };
+    assert!(min_distance(&progressive[..4]) > 0.3);
+    assert!(min_distance(&progressive[..16]) > 0.15);
+    assert!(progressive[..4].iter().any(|[x, _]| *x < 0.5));
+    assert!(progressive[..4].iter().any(|[x, _]| *x >= 0.5));
+}
+
+#[test]
+fn selection_strategies() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.05);
+    let distance =
+        |a: Point<2>, b: Point<2>| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+    // Mean distance between consecutive points
+    let locality = |points: &[Point<2>]| {
+        points
+            .windows(2)
+            .map(|w| distance(w[0], w[1]))
+            .sum::<Float>()
+            / points.len() as Float
+    };
+
+    let random = poisson.generate();
+    let oldest = poisson
+        .clone()
+        .with_selection(SelectionStrategy::Oldest)
+        .generate();
+    let newest = poisson
+        .clone()
+        .with_selection(SelectionStrategy::Newest)
+        .generate();
+
+    assert_eq!(
+        random,
+        poisson
+            .clone()
+            .with_selection(SelectionStrategy::Random)
+            .generate()
+    );
+    for points in [&random, &oldest, &newest] {
+        assert!(crate::stats::verify_min_distance(points, 0.05));
+        assert!(points.len() > 200);
+    }
+
+    // Growing from the oldest point returns points further and further from the first one
+    let first = oldest[0];
+    let early = oldest[..50]
+        .iter()
+        .map(|&p| distance(p, first))
+        .sum::<Float>();
+    let late = oldest[oldest.len() - 50..]
+        .iter()
+        .map(|&p| distance(p, first))
+        .sum::<Float>();
+    assert!(early * 3.0 < late);
+
+    // Growing from the newest point returns each point close to the one before it
+    assert!(locality(&newest) < locality(&random));
+    assert!(locality(&newest) < 0.2);
+}
+
+#[test]
+fn annulus_bounds_candidates() {
+    let poisson = Poisson3D::new().with_annulus(1.2, 1.5);
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    for _ in 0..1000 {
+        let point = random_point_around(&poisson, 0.1, &mut rng, [0.5, 0.5, 0.5]);
+        let distance = point
+            .iter()
+            .map(|x| (x - 0.5).powi(2))
+            .sum::<Float>()
+            .sqrt();
+        assert!((0.12 - 1e-6..=0.15 + 1e-6).contains(&distance));
+    }
+}
+
+#[test]
+fn annulus_changes_packing() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.05);
+    let default = poisson.generate();
+    let tight = poisson.clone().with_annulus(1.0, 1.1).generate();
+    let loose = poisson.clone().with_annulus(1.6, 2.0).generate();
+
+    assert_eq!(default, poisson.clone().with_annulus(1.0, 2.0).generate());
+    for points in [&tight, &loose] {
+        assert!(crate::stats::verify_min_distance(points, 0.05));
+    }
+    assert!(tight.len() > default.len());
+    assert!(loose.len() < default.len());
+}
+
+#[test]
+fn volume_uniform_candidates() {
+    // Half of the volume between 1 and 2 lies within the cube root of 4.5
+    let median = 4.5_f64.cbrt() as Float;
+    let below = |poisson: &Poisson3D| {
+        let mut rng = Rand::seed_from_u64(0xBADBEEF);
+        (0..10_000)
+            .filter(|_| {
+                let point = random_point_around(poisson, 1.0, &mut rng, [0.0; 3]);
+                point.iter().map(|x| x * x).sum::<Float>().sqrt() < median
+            })
+            .count()
+    };
+
+    let by_volume = Poisson3D::new().with_volume_uniform(true);
+    assert!((4_800..5_200).contains(&below(&by_volume)));
+    assert!(below(&Poisson3D::new()) > 6_000);
+
+    let points = by_volume.with_seed(0xBADBEEF).with_radius(0.1).generate();
+    assert!(crate::stats::verify_min_distance(&points, 0.1));
+}
+
+#[test]
+fn adaptive_samples() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.02);
+    let (points, stats) = poisson.generate_with_stats();
+    let (adaptive, adaptive_stats) = poisson
+        .clone()
+        .with_adaptive_samples(Some((2, 30)))
+        .generate_with_stats();
+
+    assert!(crate::stats::verify_min_distance(&adaptive, 0.02));
+    // A third fewer candidates, for only a few fewer points
+    assert!(adaptive_stats.candidates * 3 < stats.candidates * 2);
+    assert!(adaptive.len() > points.len() * 95 / 100);
+
+    // Fixed at the most samples, nothing changes
+    let fixed = poisson.clone().with_adaptive_samples(Some((30, 30)));
+    assert_eq!(fixed.generate(), points);
+    assert_eq!(
+        fixed
+            .with_adaptive_samples(None)
+            .generate_with_stats()
+            .1
+            .candidates,
+        stats.candidates
+    );
+}
+
+#[test]
+#[should_panic]
+fn adaptive_samples_must_be_ordered() {
+    let _ = Poisson2D::new().with_adaptive_samples(Some((10, 5)));
+}
+
+#[test]
+#[should_panic]
+fn annulus_must_be_ordered() {
+    let _ = Poisson2D::new().with_annulus(2.0, 1.0);
+}
+
+/// Check that directions are unit vectors, evenly spread across every axis
+fn check_directions<const N: usize>() {
+    let mut rng = Rand::seed_from_u64(0xBADBEEF);
+    let mut mean = [0.0; N];
+    let mut positive = [0; N];
+
+    for _ in 0..10_000 {
+        let direction: [Float; N] = random_direction(&mut rng);
+        let length = direction.iter().map(|x| x * x).sum::<Float>().sqrt();
+        assert!((length - 1.0).abs() < 1e-4);
+
+        for i in 0..N {
+            mean[i] += direction[i] / 10_000.0;
+            positive[i] += (direction[i] > 0.0) as usize;
+        }
+    }
+
+    for i in 0..N {
+        assert!(mean[i].abs() < 0.02, "{:?}", mean);
+        assert!((4_800..5_200).contains(&positive[i]), "{:?}", positive);
+    }
+}
+
+#[test]
+fn random_directions_are_uniform() {
+    check_directions::<2>();
+    check_directions::<3>();
+    check_directions::<4>();
+}
+
+/// Returns true if the point lies within the unit square starting at `offset`
+fn in_offset_square(point: [Float; 2], offset: &Float) -> bool {
+    point.iter().all(|&x| (*offset..*offset + 1.0).contains(&x))
+}
+
+#[test]
+fn large_coordinates_keep_spacing() {
+    let offset = 65_536.0;
+    let radius = 0.05;
+    let points = Poisson::<2, Float>::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(radius)
+        .with_validate(in_offset_square, offset)
+        .with_reseeding(10, [offset; 2], [offset + 1.0; 2])
+        .generate();
+
+    assert!(points.len() > 100);
+    for (i, a) in points.iter().enumerate() {
+        assert!(in_offset_square(*a, &offset));
+        for b in &points[..i] {
+            let distance = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+            assert!(distance >= radius, "{:?} and {:?} are too close", a, b);
+        }
+    }
+}
+
+#[cfg(not(feature = "single_precision"))]
+#[test]
+fn large_coordinates_are_generated_locally() {
+    // Generation happens relative to the first point, so moving it far away only moves the output
+    let offset = 1e9;
+    let near = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_first_point([0.5, 0.5])
+        .generate();
+    let far = Poisson::<2, Float>::new()
+        .with_seed(0xBADBEEF)
+        .with_first_point([offset + 0.5; 2])
+        .with_validate(in_offset_square, offset)
+        .generate();
+
+    assert_eq!(near.len(), far.len());
+    for (a, b) in near.iter().zip(far.iter()) {
+        assert!((a[0] - (b[0] - offset)).abs() < 1e-6);
+        assert!((a[1] - (b[1] - offset)).abs() < 1e-6);
+    }
+}
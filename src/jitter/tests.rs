@@ -0,0 +1,87 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::stats::verify_min_distance;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn one_point_per_cell() {
+    let points = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.25)
+        .jittered_grid()
+        .generate();
+
+    assert_eq!(points.len(), 64);
+    let mut cells: Vec<_> = points
+        .iter()
+        .map(|p| p.map(|x| (x / 0.25).floor() as usize))
+        .collect();
+    cells.sort_unstable();
+    cells.dedup();
+    assert_eq!(cells.len(), 64);
+}
+
+#[test]
+fn min_distance_is_enforced() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.05)
+        .jittered_grid()
+        .with_min_distance(true)
+        .generate();
+
+    assert_eq!(points.len(), 100);
+    assert!(verify_min_distance(&points, 0.05));
+}
+
+#[test]
+fn grid_covers_reseeding_box() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(|_, _| true, ())
+        .with_reseeding(1, [-1.0, 2.0], [1.05, 3.0])
+        .jittered_grid()
+        .generate();
+
+    // Partial cells along the edge are skipped
+    assert_eq!(points.len(), 200);
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (-1.0..1.0).contains(&x) && (2.0..3.0).contains(&y)));
+}
+
+#[test]
+fn jitter_is_deterministic() {
+    let grid = Poisson2D::new().with_seed(0xBADBEEF).jittered_grid();
+
+    assert_eq!(grid.generate(), grid.generate());
+}
+
+#[test]
+fn jitter_respects_space() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(|[x, _], _| x < 0.5, ())
+        .jittered_grid()
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|[x, _]| *x < 0.5));
+}
+
+#[test]
+fn empty_box_gives_no_points() {
+    let mut points = Poisson2D::new()
+        .with_reseeding(1, [0.0, 0.0], [0.05, 1.0])
+        .jittered_grid()
+        .sample();
+
+    assert_eq!(points.next(), None);
+    assert_eq!(points.next(), None);
+}
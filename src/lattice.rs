@@ -0,0 +1,91 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Quantizing points onto an integer lattice
+
+use crate::{Float, Point};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// How the distance between two cells of an integer grid is measured
+///
+/// See [`Poisson::generate_grid_points`][crate::Poisson::generate_grid_points].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridMetric {
+    /// The largest difference along any axis, so that diagonal steps count as one cell
+    Chebyshev,
+    /// The straight-line distance between the cells
+    Euclidean,
+}
+
+impl GridMetric {
+    /// Returns true if the cells are closer than `min` to each other
+    fn closer<const N: usize>(self, a: [i64; N], b: [i64; N], min: i64) -> bool {
+        match self {
+            Self::Chebyshev => a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < min),
+            Self::Euclidean => {
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| (a - b).pow(2))
+                    .sum::<i64>()
+                    < min.pow(2)
+            }
+        }
+    }
+}
+
+/// Round points to the closest cell of a grid, keeping only cells at least `min` cells apart
+///
+/// Points are considered in order, and any whose cell is too close to one already kept is
+/// dropped. Kept cells are bucketed by blocks of `min` cells, so only the adjacent blocks need to
+/// be checked for each point.
+pub(crate) fn quantize<const N: usize>(
+    points: &[Point<N>],
+    cell_size: Float,
+    min: i64,
+    metric: GridMetric,
+) -> Vec<[i64; N]> {
+    let min = min.max(1);
+    let mut blocks: HashMap<[i64; N], Vec<[i64; N]>> = HashMap::new();
+    let mut cells = Vec::with_capacity(points.len());
+
+    for point in points {
+        let mut cell = [0; N];
+        for (c, p) in cell.iter_mut().zip(point.iter()) {
+            *c = (p / cell_size).round() as i64;
+        }
+        let block = cell.map(|c| c.div_euclid(min));
+
+        let too_close = adjacent(block).any(|neighbor| {
+            blocks.get(&neighbor).map_or(false, |kept| {
+                kept.iter().any(|&k| metric.closer(cell, k, min))
+            })
+        });
+        if !too_close {
+            blocks.entry(block).or_default().push(cell);
+            cells.push(cell);
+        }
+    }
+
+    cells
+}
+
+/// The block itself and all blocks adjacent to it, including diagonally
+fn adjacent<const N: usize>(block: [i64; N]) -> impl Iterator<Item = [i64; N]> {
+    (0..3_usize.pow(N as u32)).map(move |n| {
+        let mut neighbor = block;
+        let mut n = n;
+        for c in neighbor.iter_mut() {
+            *c += (n % 3) as i64 - 1;
+            n /= 3;
+        }
+
+        neighbor
+    })
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+/// Check that no two cells are closer than `min` cells
+fn check_spacing<const N: usize>(cells: &[[i64; N]], min: i64, metric: GridMetric) {
+    for (i, &a) in cells.iter().enumerate() {
+        for &b in &cells[..i] {
+            assert!(
+                !metric.closer(a, b, min),
+                "{:?} and {:?} are too close",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn metrics() {
+    assert!(GridMetric::Chebyshev.closer([0, 0], [2, 2], 3));
+    assert!(!GridMetric::Chebyshev.closer([0, 0], [3, 1], 3));
+    assert!(GridMetric::Euclidean.closer([0, 0], [2, 2], 3));
+    assert!(!GridMetric::Euclidean.closer([0, 0], [3, 0], 3));
+    assert!(!GridMetric::Euclidean.closer([0, 0], [2, 3], 3));
+
+    // Diagonal steps are shorter in Chebyshev distance
+    assert!(GridMetric::Chebyshev.closer([0, 0], [3, 3], 4));
+    assert!(!GridMetric::Euclidean.closer([0, 0], [3, 3], 4));
+}
+
+#[test]
+fn duplicate_cells_are_dropped() {
+    let points = [[1.2, 0.9], [0.8, 1.4], [2.4, 1.0], [-1.2, 0.6]];
+    let cells = quantize(&points, 1.0, 0, GridMetric::Euclidean);
+
+    assert_eq!(cells, vec![[1, 1], [2, 1], [-1, 1]]);
+}
+
+#[test]
+fn grid_points_keep_spacing() {
+    for metric in [GridMetric::Chebyshev, GridMetric::Euclidean] {
+        let cells = Poisson2D::new()
+            .with_seed(0xBADBEEF)
+            .with_radius(0.03)
+            .generate_grid_points(0.01, metric);
+        assert!(cells.len() > 100);
+        check_spacing(&cells, 3, metric);
+
+        let cells = Poisson3D::new()
+            .with_seed(0xBADBEEF)
+            .with_radius(0.2)
+            .generate_grid_points(0.1, metric);
+        assert!(!cells.is_empty());
+        check_spacing(&cells, 2, metric);
+    }
+}
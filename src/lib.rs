@@ -25,9 +25,48 @@
 //!    double-precision `f64` to single-precision `f32`. Distributions generated with the
 //!    `single_precision` feature are *not* required nor expected to match those generated without
 //!    it. This also changes the default PRNG; see [`Poisson`] for details.
-//!  * `derive_serde` automatically derives Serde's Serialize and Deserialize traits for `Poisson`.
-//!    This relies on the [`serde_arrays`][sa] crate to allow (de)serializing the const generic arrays
-//!    used by `Poisson`.
+//!  * `derive_serde` derives Serde's Serialize and Deserialize traits for [`GenerationResult`], so
+//!    that generated distributions can be saved along with the settings that produced them. The
+//!    `serde` feature enables it too.
+//!  * `arrow` adds conversion of distributions to Apache [Arrow][ar] record batches, with one column
+//!    per axis, and writing them to Parquet files. This feature requires Rust 1.70 or later.
+//!  * `async` adds generating distributions as a [`Stream`][st] of points, which yields to the
+//!    executor every so often so that large distributions can be generated within async tasks
+//!    without blocking others; see [`Poisson::stream`].
+//!  * `bevy` adds integration with the [Bevy][bv] game engine: conversions to Bevy's vectors, and
+//!    a plugin that generates distributions in the background and streams them into the ECS. See
+//!    the [`bevy`](crate::bevy) module. This feature requires a recent Rust, as does Bevy.
+//!  * `bytemuck` adds [`PodPoint`](crate::pod::PodPoint), which implements [`bytemuck`][bm]'s
+//!    `Pod` trait, and casts of generated points to it and to bytes, for uploading distributions
+//!    to GPU buffers without copying them. See the [`pod`](crate::pod) module.
+//!  * `cgmath` adds conversions of points to [cgmath][cg]'s points and vectors, for engines and
+//!    codebases built on it. See the [`cgmath`](crate::cgmath) module.
+//!  * `cli` builds the `fast-poisson` binary, which generates distributions from the command line
+//!    and writes them as CSV, JSON, PLY, or SVG; run `fast-poisson --help` for its options.
+//!    Arguments are parsed with [`clap`][cl].
+//!  * `ffi` adds C bindings, for use from C, C++, or native plugins for engines such as Unity; see
+//!    the [`ffi`](crate::ffi) module, which describes building them as a shared library, and
+//!    `include/fast_poisson.h`.
+//!  * `image` adds rasterizing 2D distributions into images, rendering them to PNG files, and
+//!    stippling images. This feature requires a recent Rust, as does the `image` crate.
+//!  * `kiddo` is enabled by default, and uses the [`kiddo`][kd] crate's k-d tree as the spatial
+//!    index during generation. It is also required to generate distributions as k-d trees.
+//!    Without it the simple grid built into this crate is used instead; see the
+//!    [`spatial`](crate::spatial) module.
+//!  * `ndarray` adds generating distributions as [`ndarray`][nd] arrays, with a row per point,
+//!    for use with scientific code built on it; see [`Poisson::generate_array`].
+//!  * `python` adds Python bindings through [PyO3][py], exposing `Poisson2D` and `Poisson3D`
+//!    classes which generate numpy arrays. Build the module with [maturin][mt]; see the
+//!    [`python`](crate::python) module. This feature requires a recent Rust, as does PyO3.
+//!  * `rayon` adds generating distributions in parallel with [rayon][ry], and generates batches of
+//!    distributions in parallel.
+//!  * `simd` tests candidate points against their neighbors several at a time, using the
+//!    [`wide`][wd] crate for portable SIMD, with a single spatial query per active point rather
+//!    than one per candidate. This speeds up generation, especially with many samples per point,
+//!    and produces the same distributions as without it.
+//!  * `tracing` instruments generation with the [`tracing`][tr] crate: each iterator runs within a
+//!    span, and events are emitted when generation starts and ends, as well as every 1000 points
+//!    with the number of points in the spatial index.
 //!
 //! # Examples
 //!
@@ -124,17 +163,131 @@
 //! [Tulleken]: http://devmag.org.za/2009/05/03/poisson-disk-sampling/
 //! [const generics]: https://blog.rust-lang.org/2021/03/25/Rust-1.51.0.html#const-generics-mvp
 //! [small_rng]: https://docs.rs/rand/0.8.3/rand/rngs/struct.SmallRng.html
-//! [sa]: https://crates.io/crates/serde_arrays
+//! [tr]: https://crates.io/crates/tracing
+//! [ar]: https://arrow.apache.org/
+//! [bv]: https://bevyengine.org/
+//! [bm]: https://crates.io/crates/bytemuck
+//! [cg]: https://crates.io/crates/cgmath
+//! [cl]: https://crates.io/crates/clap
+//! [py]: https://pyo3.rs/
+//! [mt]: https://www.maturin.rs/
+//! [wd]: https://crates.io/crates/wide
+//! [ry]: https://crates.io/crates/rayon
+//! [kd]: https://crates.io/crates/kiddo
+//! [nd]: https://crates.io/crates/ndarray
+//! [st]: https://docs.rs/futures-core/0.3/futures_core/stream/trait.Stream.html
 
-use std::{fmt::Debug, marker::PhantomData};
+use std::{
+    fmt::Debug,
+    io::{self, Write},
+    marker::PhantomData,
+    sync::mpsc::SyncSender,
+    thread::{self, JoinHandle},
+    time::Instant,
+};
 
+#[cfg(feature = "kiddo")]
 use kiddo::KdTree;
-use rand::{Rng, SeedableRng};
+use rand::{Rng, RngCore, SeedableRng};
 #[cfg(test)]
 mod tests;
 
+mod animation;
+pub use animation::Animation;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+mod boundary;
+pub use boundary::Boundary;
+pub mod builder;
+pub use builder::PoissonBuilder;
+mod ccvt;
+#[cfg(feature = "cgmath")]
+pub mod cgmath;
+mod chunks;
+pub use chunks::Chunks;
+#[cfg(feature = "arrow")]
+mod columnar;
+mod coverage;
+pub use coverage::Coverage;
+mod dart;
+pub use dart::{DartThrowing, Darts};
+mod density;
+use density::Density;
+pub mod domain;
+pub use domain::Domain;
+mod dynamic;
+pub use dynamic::PoissonDyn;
+mod elimination;
+mod error;
+pub use error::PoissonError;
+mod estimate;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod hex;
+pub use hex::{Hexes, JitteredHex};
+#[cfg(feature = "kiddo")]
+mod index;
+#[cfg(feature = "kiddo")]
+pub use index::PointIndex;
 mod iter;
-pub use iter::{Iter, Point};
+pub use iter::{Iter, Point, Scatter, SelectionStrategy, WithParent, WithSpacing};
+mod jitter;
+pub use jitter::{Jittered, JitteredGrid};
+mod lattice;
+pub use lattice::GridMetric;
+mod mapped;
+pub use mapped::{Mapped, MappedIter};
+mod memo;
+use memo::Memo;
+mod observer;
+pub use observer::{Event, Observer};
+mod order;
+pub use order::SpatialOrder;
+mod outline;
+mod packing;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod phyllotaxis;
+pub use phyllotaxis::{Florets, Phyllotaxis};
+#[cfg(feature = "bytemuck")]
+pub mod pod;
+#[cfg(feature = "python")]
+pub mod python;
+mod quasi;
+pub use quasi::CandidateSequence;
+mod quota;
+use quota::Quotas;
+mod result;
+pub use result::GenerationResult;
+mod sampler;
+pub use sampler::Sampler;
+mod set;
+pub use set::PoissonSet;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod spatial;
+use spatial::SpatialIndex;
+pub mod stats;
+#[cfg(feature = "image")]
+mod stipple;
+pub use stats::GenerationStats;
+#[cfg(feature = "async")]
+mod stream;
+#[cfg(feature = "async")]
+pub use stream::PointStream;
+mod seed;
+pub use seed::Jump;
+use seed::JumpFn;
+mod tiles;
+pub use tiles::TileSet;
+mod tiling;
+use tiling::Tiling;
+mod transform;
+use transform::Affine;
+mod unbounded;
+pub use unbounded::Unbounded;
+mod weights;
 
 /// [`Poisson`] disk distribution in 2 dimensions
 pub type Poisson2D = Poisson<2>;
@@ -198,13 +351,53 @@ where
 {
     validate: fn([Float; N], &U) -> bool,
     validate_user_data: U,
+    /// Draws a first point from the user data, when it is a [`Domain`]
+    sample_first: Option<fn(&U, &mut dyn RngCore) -> Point<N>>,
 
     /// Radius around each point that must remain empty
     radius: Float,
     /// Seed to use for the internal RNG
     seed: Option<u64>,
+    /// Index of the stream of the RNG to use, and how to jump from one stream to the next
+    stream: Option<(u64, JumpFn<R>)>,
     /// Number of samples to generate and test around each point
     num_samples: u32,
+    /// Fewest and most samples to generate around each point, if adapting to the acceptance rate
+    adaptive_samples: Option<[u32; 2]>,
+    /// Distances, as multiples of the radius, between which samples are generated around a point
+    annulus: [Float; 2],
+    /// Fraction of the radius by which candidates may be closer to their neighbors, if lucky
+    radius_tolerance: Float,
+    /// Whether samples are spread evenly by volume within the annulus, rather than by distance
+    volume_uniform: bool,
+    /// Points from which generation grows outward; one is chosen randomly if unspecified
+    first_points: Vec<Point<N>>,
+    /// Whether the first points are included in the output
+    initial_point_emitted: bool,
+    /// Number of attempts to find a fresh starting point once the active list is exhausted
+    reseed_attempts: u32,
+    /// Box, as `[min, max]`, from which fresh starting points are drawn
+    reseed_bounds: [Point<N>; 2],
+    /// Box, as `[min, max]`, that every point must lie within, if any
+    bounds: Option<[Point<N>; 2]>,
+    /// What happens to candidates outside the box
+    boundary: Boundary,
+    /// The number of points required within each cell of a grid over the box, if any
+    quotas: Option<Quotas<N>>,
+    /// Whether the output is returned in random order
+    shuffled_output: bool,
+    /// Whether the output is returned in progressive order
+    progressive_output: bool,
+    /// How the next active point to sample around is chosen
+    selection: SelectionStrategy,
+    /// Where the candidates around each active point come from
+    candidate_sequence: CandidateSequence,
+    /// Density varying the radius across space, if any
+    density: Option<Density<N>>,
+    /// Transform mapping generated points into world space, if any
+    transform: Option<Affine<N>>,
+    /// The points generated by [`generate_cached`](Self::generate_cached), if any
+    memo: Memo<N>,
     /// Marker for our RNG
     _rng: PhantomData<R>,
 }
@@ -226,147 +419,2921 @@ where
 
     /// Specify the point validation function
     pub fn with_validate(mut self, func: fn([Float; N], &U) -> bool, user_data: U) -> Self {
+        self.memo.clear();
         self.validate = func;
         self.validate_user_data = user_data;
 
         self
     }
 
+    /// Specify the space to sample as a [`Domain`]
+    ///
+    /// The domain replaces the validation function, and becomes its user data; a validation
+    /// function specified afterwards must likewise take a domain of the same type. Points are
+    /// restricted to the domain's bounding box, which also serves as the
+    /// [reseeding](Self::with_reseeding) box, and generation starts from the domain's
+    /// [initial point](Domain::initial_point) unless a [first point](Self::with_first_point) is
+    /// given.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use fast_poisson::domain::Annulus;
+    ///
+    /// let ring = Poisson2D::new()
+    ///     .with_domain(Annulus::new([0.0, 0.0], 5.0, 10.0))
+    ///     .with_radius(0.5)
+    ///     .generate();
+    ///
+    /// assert!(ring.iter().all(|[x, y]| (25.0..100.0).contains(&(x * x + y * y))));
+    /// ```
+    #[must_use]
+    pub fn with_domain<D>(self, domain: D) -> Poisson<N, D, R>
+    where
+        D: Domain<N> + Default + Clone,
+    {
+        let [min, max] = domain.bounding_box();
+
+        let mut poisson = self.into_space(|point, domain: &D| domain.contains(point), domain);
+        poisson.sample_first = Some(|domain: &D, rng| domain.initial_point(rng));
+        poisson.bounds = Some([min, max]);
+        poisson.reseed_bounds = [min, max];
+
+        poisson
+    }
+
+    /// Specify the space to sample as the convex hull of a set of points
+    ///
+    /// The hull is computed from the points, in any number of dimensions, and sampled as a
+    /// [`ConvexHull`](domain::ConvexHull) [domain](Self::with_domain). This resamples a region
+    /// whose boundary is only known as a point cloud, such as from a scan or a simulation.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// // The corners of a scanned tetrahedron, along with points from within it
+    /// let scan = [
+    ///     [0.0, 0.0, 0.0],
+    ///     [2.0, 0.0, 0.0],
+    ///     [0.2, 0.3, 0.1],
+    ///     [0.0, 2.0, 0.0],
+    ///     [0.0, 0.0, 2.0],
+    /// ];
+    /// let poisson = Poisson3D::new().with_convex_hull(&scan).with_radius(0.2);
+    ///
+    /// let points = poisson.generate();
+    /// assert!(points.iter().all(|&[x, y, z]| x + y + z <= 2.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the points are degenerate, such that their hull has no volume: there must be at
+    /// least `N + 1` points, not all within the same hyperplane.
+    #[must_use]
+    pub fn with_convex_hull(self, points: &[Point<N>]) -> Poisson<N, domain::ConvexHull<N>, R> {
+        self.with_domain(domain::ConvexHull::new(points))
+    }
+
+    /// Move every setting but the validation function and its user data into a new distribution
+    pub(crate) fn into_space<V>(
+        self,
+        validate: fn(Point<N>, &V) -> bool,
+        user_data: V,
+    ) -> Poisson<N, V, R>
+    where
+        V: Default + Clone,
+    {
+        Poisson {
+            validate,
+            validate_user_data: user_data,
+            sample_first: None,
+            radius: self.radius,
+            seed: self.seed,
+            stream: self.stream,
+            num_samples: self.num_samples,
+            adaptive_samples: self.adaptive_samples,
+            annulus: self.annulus,
+            radius_tolerance: self.radius_tolerance,
+            volume_uniform: self.volume_uniform,
+            first_points: self.first_points,
+            initial_point_emitted: self.initial_point_emitted,
+            reseed_attempts: self.reseed_attempts,
+            reseed_bounds: self.reseed_bounds,
+            bounds: self.bounds,
+            boundary: self.boundary,
+            quotas: self.quotas,
+            shuffled_output: self.shuffled_output,
+            progressive_output: self.progressive_output,
+            selection: self.selection,
+            candidate_sequence: self.candidate_sequence,
+            density: self.density,
+            transform: self.transform,
+            memo: Memo::default(),
+            _rng: PhantomData,
+        }
+    }
 
     /// Specify the radius around each point
     pub fn with_radius(mut self, radius: Float) -> Self {
         self.set_radius(radius);
 
-        self
+        self
+    }
+
+    /// Specify the radius around each point, if it is positive and finite
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, PoissonError};
+    /// assert!(Poisson2D::new().try_with_radius(0.05).is_ok());
+    /// assert_eq!(
+    ///     Poisson2D::new().try_with_radius(0.0),
+    ///     Err(PoissonError::InvalidRadius(0.0))
+    /// );
+    /// ```
+    ///
+    /// See [`with_radius`][Self::with_radius].
+    pub fn try_with_radius(self, radius: Float) -> Result<Self, PoissonError> {
+        if radius > 0.0 && radius.is_finite() {
+            Ok(self.with_radius(radius))
+        } else {
+            Err(PoissonError::InvalidRadius(radius))
+        }
+    }
+
+    /// Specify the radius such that the distribution has approximately `count` points
+    ///
+    /// The radius is derived from the volume of the space and the typical density of a Poisson
+    /// disk distribution. The volume is measured by sampling the validation function within the
+    /// [reseeding](Self::with_reseeding) box, by default the unit hypercube, so this should be
+    /// called after those have been specified. The chosen radius can be retrieved with
+    /// [`radius`][Self::radius].
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_target_count(500);
+    /// println!("Using radius {}", poisson.radius());
+    ///
+    /// let points = poisson.generate();
+    /// ```
+    ///
+    /// See also [`set_target_count`][Self::set_target_count].
+    #[must_use]
+    pub fn with_target_count(mut self, count: usize) -> Self {
+        self.set_target_count(count);
+
+        self
+    }
+
+    /// Specify the PRNG seed for this distribution
+    ///
+    /// If no seed is specified then the internal PRNG will be seeded from entropy, providingfast_poisson
+    /// non-deterministic and non-repeatable results.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new().with_seed(0xBADBEEF).iter();
+    /// ```
+    ///
+    /// See also [`set_seed`][Self::set_seed].
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.set_seed(seed);
+
+        self
+    }
+
+    /// Specify the PRNG seed as a string, such as a world name entered by a player
+    ///
+    /// This is the same as [`with_seed_bytes`](Self::with_seed_bytes) with the UTF-8 bytes of the
+    /// string, so the same name always gives the same distribution.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let world = Poisson2D::new().with_seed_str("Atlantis");
+    ///
+    /// assert_eq!(world.generate(), Poisson2D::new().with_seed_str("Atlantis").generate());
+    /// assert_eq!(world, Poisson2D::new().with_seed(0xE9C6_CB5E_BE16_925D));
+    /// ```
+    ///
+    /// See also [`set_seed_str`][Self::set_seed_str].
+    #[must_use]
+    pub fn with_seed_str(mut self, seed: &str) -> Self {
+        self.set_seed_str(seed);
+
+        self
+    }
+
+    /// Specify the PRNG seed as arbitrary bytes
+    ///
+    /// The bytes are hashed into the seed with the 64-bit [FNV-1a] hash: starting from
+    /// `0xCBF29CE484222325`, each byte in turn is XORed into the hash, which is then multiplied by
+    /// `0x100000001B3`, wrapping on overflow. This hash is part of the stable API and will not
+    /// change, so other languages and tools can derive the same seeds, and it is equivalent to
+    /// calling [`with_seed`](Self::with_seed) with the resulting hash.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// assert_eq!(
+    ///     Poisson2D::new().with_seed_bytes(b"a"),
+    ///     Poisson2D::new().with_seed(0xAF63_DC4C_8601_EC8C)
+    /// );
+    /// ```
+    ///
+    /// See also [`set_seed_bytes`][Self::set_seed_bytes].
+    ///
+    /// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+    #[must_use]
+    pub fn with_seed_bytes(mut self, seed: &[u8]) -> Self {
+        self.set_seed_bytes(seed);
+
+        self
+    }
+
+    /// Specify which of the PRNG's independent streams to use
+    ///
+    /// Streams are derived from the [seed](Self::with_seed) by jumping the PRNG ahead `index`
+    /// times, each jump skipping far more values than could ever be drawn. Distributions using
+    /// different streams of the same seed are therefore statistically independent, without any
+    /// ad hoc arithmetic on seeds; this suits generating many related distributions, such as one
+    /// per chunk of a map or per layer, from a single master seed. Stream 0 is the same as not
+    /// specifying a stream.
+    ///
+    /// Jumping is cheap, but the cost grows with `index`, so indices are best kept to the
+    /// thousands. Streams are available for every xoshiro PRNG, including the default.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let base = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let layers: Vec<_> = (0..4).map(|i| base.clone().with_stream(i).generate()).collect();
+    ///
+    /// assert_eq!(layers[0], base.generate());
+    /// assert_ne!(layers[1], layers[2]);
+    /// ```
+    ///
+    /// See also [`set_stream`][Self::set_stream].
+    #[must_use]
+    pub fn with_stream(mut self, index: u64) -> Self
+    where
+        R: Jump,
+    {
+        self.set_stream(index);
+
+        self
+    }
+
+    /// Specify the maximum samples to generate around each point
+    ///
+    /// Note that this is not specifying the number of samples in the resulting distribution, but
+    /// rather sets the maximum number of attempts to find a new, valid point around an existing
+    /// point for each iteration of the algorithm.
+    ///
+    /// A higher number may result in better space filling, but may also slow down generation.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let points = Poisson3D::new().with_samples(40).iter();
+    /// ```
+    ///
+    /// See also [`set_samples`][Self::set_samples].
+    #[must_use]
+    pub fn with_samples(mut self, samples: u32) -> Self {
+        self.set_samples(samples);
+
+        self
+    }
+
+    /// Specify the maximum samples to generate around each point, if there is at least one
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, PoissonError};
+    /// assert!(Poisson2D::new().try_with_samples(10).is_ok());
+    /// assert_eq!(
+    ///     Poisson2D::new().try_with_samples(0),
+    ///     Err(PoissonError::NoSamples)
+    /// );
+    /// ```
+    ///
+    /// See [`with_samples`][Self::with_samples].
+    pub fn try_with_samples(self, samples: u32) -> Result<Self, PoissonError> {
+        if samples > 0 {
+            Ok(self.with_samples(samples))
+        } else {
+            Err(PoissonError::NoSamples)
+        }
+    }
+
+    /// Adapt the number of samples around each point to how often they succeed
+    ///
+    /// Early in generation nearly every active point has room for another point around it, but
+    /// later most of them are surrounded, and all of their samples are generated only to be
+    /// rejected. With adaptive samples, the number of samples generated around each point follows
+    /// the fraction of recently visited active points that found room: from `max` while nearly all
+    /// of them do, down to `min` as almost none do. This speeds up dense distributions
+    /// considerably, at the cost of leaving slightly more gaps; the minimum spacing is unaffected.
+    /// A `max` above the usual number of samples also fills space more thoroughly early on.
+    ///
+    /// The number of samples set with [`with_samples`](Self::with_samples) is ignored while
+    /// adaptive samples are in use; pass `None` to return to it. Only the distributions generated
+    /// by [`iter`](Self::iter) and the methods built upon it adapt.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.02);
+    /// let (points, stats) = poisson.generate_with_stats();
+    /// let (adaptive, adaptive_stats) = poisson
+    ///     .with_adaptive_samples(Some((4, 30)))
+    ///     .generate_with_stats();
+    ///
+    /// assert!(adaptive_stats.candidates < stats.candidates);
+    /// assert!(adaptive.len() > points.len() * 9 / 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// See also [`set_adaptive_samples`][Self::set_adaptive_samples].
+    #[must_use]
+    pub fn with_adaptive_samples(mut self, samples: Option<(u32, u32)>) -> Self {
+        self.set_adaptive_samples(samples);
+
+        self
+    }
+
+    /// Specify how far from each point the samples around it are generated
+    ///
+    /// Samples are generated between `inner` and `outer` times the radius away from the point they
+    /// grow from; Bridson's algorithm uses 1 and 2, the default. Bringing `outer` closer to 1 packs
+    /// points more tightly, as each new point is placed about as close to its neighbors as it may
+    /// be, while a larger `inner` leaves larger gaps between points for a looser pattern. The
+    /// minimum distance between points is the radius either way.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let tight = Poisson2D::new().with_seed(0xBADBEEF).with_annulus(1.0, 1.2);
+    /// let loose = Poisson2D::new().with_seed(0xBADBEEF).with_annulus(1.5, 2.5);
+    ///
+    /// assert!(tight.generate().len() > loose.generate().len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= inner <= outer` and `outer > 0`.
+    ///
+    /// See also [`set_annulus`][Self::set_annulus].
+    #[must_use]
+    pub fn with_annulus(mut self, inner: Float, outer: Float) -> Self {
+        self.set_annulus(inner, outer);
+
+        self
+    }
+
+    /// Allow points to be a little closer together than the radius
+    ///
+    /// A candidate closer than the radius to its nearest neighbor, but no closer than `1 -
+    /// tolerance` times the radius, is accepted at random, more likely the less it falls short:
+    /// one falling short by half the tolerance is accepted half of the time. Only a small fraction
+    /// of pairs of points end up closer than the radius, giving a more relaxed and slightly denser
+    /// look than strict Poisson disk sampling, which is often what's wanted artistically. The
+    /// default is 0, enforcing the radius strictly.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let strict = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let relaxed = strict.clone().with_radius_tolerance(0.2);
+    ///
+    /// assert!(relaxed.generate().len() > strict.generate().len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= tolerance < 1`.
+    ///
+    /// See also [`set_radius_tolerance`][Self::set_radius_tolerance].
+    #[must_use]
+    pub fn with_radius_tolerance(mut self, tolerance: Float) -> Self {
+        self.set_radius_tolerance(tolerance);
+
+        self
+    }
+
+    /// Specify whether samples are spread evenly by volume within the annulus
+    ///
+    /// By default the distance of each sample from the point it grows from is uniformly
+    /// distributed, as in most implementations of Bridson's algorithm. There is far more space in
+    /// the outer part of the [annulus](Self::with_annulus) than in the inner part, though, so
+    /// samples crowd towards the inside, increasingly so in higher dimensions. Spreading them
+    /// evenly by area or volume instead, as Bridson intended, fills space slightly more evenly.
+    ///
+    /// This changes the distribution generated for a given seed, which is why it isn't the
+    /// default.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let points = Poisson3D::new().with_volume_uniform(true).generate();
+    /// ```
+    ///
+    /// See also [`set_volume_uniform`][Self::set_volume_uniform].
+    #[must_use]
+    pub fn with_volume_uniform(mut self, volume_uniform: bool) -> Self {
+        self.set_volume_uniform(volume_uniform);
+
+        self
+    }
+
+    /// Specify the point from which the distribution grows outward
+    ///
+    /// By default generation starts from a random point near the origin. Setting the first point
+    /// lets you control where the earliest points of the distribution appear, which is especially
+    /// useful when only a prefix of the output is consumed:
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // The first few points will all be clustered around the center
+    /// let points = Poisson2D::new().with_first_point([0.5, 0.5]).iter().take(5);
+    /// ```
+    ///
+    /// Note that the first point itself is not part of the output, unless requested with
+    /// [`with_initial_point_emitted`][Self::with_initial_point_emitted].
+    ///
+    /// See also [`set_first_point`][Self::set_first_point].
+    #[must_use]
+    pub fn with_first_point(mut self, point: Point<N>) -> Self {
+        self.set_first_point(point);
+
+        self
+    }
+
+    /// Specify several points from which the distribution grows outward
+    ///
+    /// Generation only ever reaches the parts of the domain that are connected to a starting
+    /// point, so if your validation function describes several disjoint regions you can provide a
+    /// starting point in each of them:
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new()
+    ///     // Two islands, separated by a gap between 0.3 and 0.7
+    ///     .with_validate(
+    ///         |[x, y], _| {
+    ///             (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y) && !(0.3..0.7).contains(&x)
+    ///         },
+    ///         (),
+    ///     )
+    ///     .with_first_points(&[[0.15, 0.5], [0.85, 0.5]])
+    ///     .generate();
+    /// ```
+    ///
+    /// See also [`with_reseeding`][Self::with_reseeding] to find such regions automatically, and
+    /// [`set_first_points`][Self::set_first_points].
+    #[must_use]
+    pub fn with_first_points(mut self, points: &[Point<N>]) -> Self {
+        self.set_first_points(points);
+
+        self
+    }
+
+    /// Specify whether the first point is included in the output
+    ///
+    /// By default the point generation starts from is *not* returned (see #36), because it is
+    /// never checked against the validation function and would otherwise leave a void in the
+    /// distribution. When emitted, the first point is validated like any other sample and, if it
+    /// lies within the space, returned before any other point; the rest of the distribution
+    /// maintains the minimum radius around it. A first point that fails validation is still used
+    /// to start generation, but is not returned.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new()
+    ///     .with_first_point([0.5, 0.5])
+    ///     .with_initial_point_emitted(true)
+    ///     .iter();
+    ///
+    /// assert_eq!(points.next(), Some([0.5, 0.5]));
+    /// ```
+    ///
+    /// If several first points were given, all of the valid ones are emitted in the order given;
+    /// it is up to you to ensure they respect the radius among themselves.
+    ///
+    /// See also [`set_initial_point_emitted`][Self::set_initial_point_emitted].
+    #[must_use]
+    pub fn with_initial_point_emitted(mut self, emitted: bool) -> Self {
+        self.set_initial_point_emitted(emitted);
+
+        self
+    }
+
+    /// Specify the box, from `min` to `max`, to sample
+    ///
+    /// By default the unit hypercube is sampled; this samples any other box instead, wherever it
+    /// lies in space. The box includes `min` but not `max`. Generation starts near its center, and
+    /// the [reseeding](Self::with_reseeding) box is set to the same box.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new()
+    ///     .with_bounds([-512.0, -512.0], [512.0, 512.0])
+    ///     .with_radius(16.0)
+    ///     .generate();
+    ///
+    /// assert!(points.iter().any(|[x, _]| *x < 0.0));
+    /// ```
+    ///
+    /// The box replaces the validation function, which by default restricts points to the unit
+    /// hypercube; a [domain](Self::with_domain) is kept instead, so that points must lie within
+    /// both the box and the domain. A validation function specified afterwards further restricts
+    /// points within the box, as points must then both lie within the box and pass validation:
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // A disk within the box
+    /// let points = Poisson2D::new()
+    ///     .with_bounds([-512.0, -512.0], [512.0, 512.0])
+    ///     .with_validate(|[x, y], _| x * x + y * y < 400.0 * 400.0, ())
+    ///     .with_radius(16.0)
+    ///     .generate();
+    /// ```
+    ///
+    /// See also [`set_bounds`][Self::set_bounds].
+    #[must_use]
+    pub fn with_bounds(mut self, min: Point<N>, max: Point<N>) -> Self {
+        self.set_bounds(min, max);
+
+        self
+    }
+
+    /// Specify what happens to candidates that fall outside the box being sampled
+    ///
+    /// Bridson's algorithm rejects candidates outside the box, so points near its edges find fewer
+    /// candidates and the edges end up sparser than the rest of the box. Clamping or reflecting
+    /// candidates back into the box instead fills the edges; moved candidates must still pass
+    /// validation and keep the radius from every other point, like any other. See [`Boundary`]
+    /// for the choices.
+    ///
+    /// ```
+    /// # use fast_poisson::{Boundary, Poisson2D};
+    /// let points = Poisson2D::new()
+    ///     .with_bounds([0.0, 0.0], [10.0, 10.0])
+    ///     .with_radius(0.5)
+    ///     .with_boundary(Boundary::Reflect)
+    ///     .generate();
+    ///
+    /// assert!(points.iter().all(|&[x, y]| (0.0..10.0).contains(&x) && (0.0..10.0).contains(&y)));
+    /// ```
+    ///
+    /// The box is the one given to [`with_bounds`](Self::with_bounds) or
+    /// [`with_domain`](Self::with_domain), or otherwise the [reseeding](Self::with_reseeding)
+    /// box, by default the unit hypercube. This only applies to [`iter`][Self::iter] and the
+    /// methods based upon it.
+    ///
+    /// See also [`set_boundary`][Self::set_boundary].
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.set_boundary(boundary);
+
+        self
+    }
+
+    /// Specify the fewest and the most points within each cell of a grid over the box being
+    /// sampled
+    ///
+    /// The box is divided into `cells[i]` equal parts along each axis `i`, such as one cell per
+    /// tile of a map. Candidates that would put more than `max` points in a cell are rejected as
+    /// though they were outside the space. Once generation would otherwise finish, each cell
+    /// holding fewer than `min` points is searched for more at random, and generation grows onward
+    /// from any that are found, so that every cell is covered even if the validation function
+    /// splits the space into parts never reached by growing from the first point.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // At least one and at most six points on each of 16 map tiles
+    /// let points = Poisson2D::new()
+    ///     .with_bounds([0.0, 0.0], [4.0, 4.0])
+    ///     .with_radius(0.3)
+    ///     .with_quotas([4, 4], 1, 6)
+    ///     .generate();
+    ///
+    /// for tile_x in 0..4 {
+    ///     for tile_y in 0..4 {
+    ///         let count = points
+    ///             .iter()
+    ///             .filter(|[x, y]| (*x as usize, *y as usize) == (tile_x, tile_y))
+    ///             .count();
+    ///         assert!((1..=6).contains(&count));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The minimum is a best effort: a cell is given up on after many failed attempts to find a
+    /// point, so a cell that cannot hold `min` points at the radius, or that lies almost entirely
+    /// outside the space, keeps fewer. The box is the one given to
+    /// [`with_bounds`](Self::with_bounds) or [`with_domain`](Self::with_domain), or otherwise the
+    /// [reseeding](Self::with_reseeding) box, by default the unit hypercube. This only applies to
+    /// [`iter`][Self::iter] and the methods based upon it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no cells along some axis, or if `min` exceeds `max`.
+    ///
+    /// See also [`set_quotas`][Self::set_quotas].
+    #[must_use]
+    pub fn with_quotas(mut self, cells: [usize; N], min: usize, max: usize) -> Self {
+        self.set_quotas(cells, min, max);
+
+        self
+    }
+
+    /// Specify how many attempts to make at finding a fresh starting point
+    ///
+    /// When every active point has been exhausted, the distribution may still have unfilled
+    /// regions that were never reached, for example when the validation function describes
+    /// disjoint islands. With reseeding enabled, random points are drawn uniformly from the box
+    /// `[min, max)`; the first one that is valid and far enough from all existing samples is
+    /// emitted and generation continues from there. Since the validation function is opaque, the
+    /// box must cover every region you want filled: parts of the space outside of it can only be
+    /// reached by growing from a point inside it.
+    ///
+    /// Saturation is not detected directly; rather, generation ends once `attempts` consecutive
+    /// draws have failed. Small regions may therefore still be missed, with a likelihood that
+    /// shrinks as `attempts` grows. A value of 0, the default, disables reseeding.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new()
+    ///     // Two islands, separated by a gap between 0.3 and 0.7
+    ///     .with_validate(
+    ///         |[x, y], _| {
+    ///             (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y) && !(0.3..0.7).contains(&x)
+    ///         },
+    ///         (),
+    ///     )
+    ///     .with_reseeding(1000, [0.0, 0.0], [1.0, 1.0])
+    ///     .generate();
+    /// ```
+    ///
+    /// See also [`set_reseeding`][Self::set_reseeding].
+    #[must_use]
+    pub fn with_reseeding(mut self, attempts: u32, min: Point<N>, max: Point<N>) -> Self {
+        self.set_reseeding(attempts, min, max);
+
+        self
+    }
+
+    /// Specify whether the output is returned in random order
+    ///
+    /// Points are naturally generated near the points they grew from, so any prefix of the output
+    /// is clustered together, which biases any logic that only takes the first few points. When
+    /// shuffled, the entire distribution is generated up front and returned in random order
+    /// instead. The shuffle uses the same seeded RNG, so the order is still deterministic for a
+    /// given seed, and the set of points is unchanged.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // Five points spread across the whole space
+    /// let points = Poisson2D::new().with_shuffled_output(true).iter().take(5);
+    /// ```
+    ///
+    /// Parent indices reported by [`iter_with_parent`][Self::iter_with_parent] refer to positions
+    /// in the shuffled output.
+    ///
+    /// See also [`set_shuffled_output`][Self::set_shuffled_output].
+    #[must_use]
+    pub fn with_shuffled_output(mut self, shuffled: bool) -> Self {
+        self.set_shuffled_output(shuffled);
+
+        self
+    }
+
+    /// Specify whether the output is returned in progressive order
+    ///
+    /// In progressive order, every prefix of the output is itself well spread across the space,
+    /// with the first `k` points about as evenly spaced as any `k` points could be. This suits
+    /// consumers that refine adaptively, such as renderers taking more samples only where needed,
+    /// and unlike a shuffle it can't be recovered cheaply once the points are generated.
+    ///
+    /// The entire distribution is generated up front and ranked by weighted sample elimination,
+    /// which halves the points over and over, keeping those spread furthest apart. The order is
+    /// deterministic for a given seed, and the set of points is unchanged. This takes precedence
+    /// over [shuffled output](Self::with_shuffled_output).
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // The first ten points are spread across the whole space, and far apart
+    /// let points: Vec<_> = Poisson2D::new()
+    ///     .with_seed(0xBADBEEF)
+    ///     .with_progressive_output(true)
+    ///     .iter()
+    ///     .take(10)
+    ///     .collect();
+    /// ```
+    ///
+    /// Parent indices reported by [`iter_with_parent`][Self::iter_with_parent] refer to positions
+    /// in the progressive output.
+    ///
+    /// See also [`set_progressive_output`][Self::set_progressive_output].
+    #[must_use]
+    pub fn with_progressive_output(mut self, progressive: bool) -> Self {
+        self.set_progressive_output(progressive);
+
+        self
+    }
+
+    /// Specify how the next active point to sample around is chosen
+    ///
+    /// Bridson's algorithm picks a random active point, growing the distribution in a ragged front.
+    /// Picking the oldest active point instead grows it in an even front, so that points are
+    /// returned roughly in order of their distance from where generation started, while picking
+    /// the newest grows it as a winding path. The spacing of the points is the same either way,
+    /// but the order they are returned in differs, as does the look of the distribution in subtle
+    /// ways. See [`SelectionStrategy`] for details.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, SelectionStrategy};
+    /// // Each point is returned near the one before it
+    /// let points = Poisson2D::new()
+    ///     .with_selection(SelectionStrategy::Newest)
+    ///     .generate();
+    /// ```
+    ///
+    /// See also [`set_selection`][Self::set_selection].
+    #[must_use]
+    pub fn with_selection(mut self, selection: SelectionStrategy) -> Self {
+        self.set_selection(selection);
+
+        self
+    }
+
+    /// Specify where the candidates generated around each active point come from
+    ///
+    /// Bridson's algorithm places each candidate in a random direction and at a random distance,
+    /// so by chance several may land on the same side of a point while a gap on another side goes
+    /// unexplored. Taking them from a low-discrepancy sequence instead spreads the candidates
+    /// around each point evenly, which fills space more completely when only a few
+    /// [samples](Self::with_samples) are generated around each point. See [`CandidateSequence`]
+    /// for the choices.
+    ///
+    /// ```
+    /// # use fast_poisson::{CandidateSequence, Poisson2D};
+    /// let points = Poisson2D::new()
+    ///     .with_samples(6)
+    ///     .with_candidate_sequence(CandidateSequence::Halton)
+    ///     .generate();
+    /// ```
+    ///
+    /// This only applies to [`iter`][Self::iter] and the methods based upon it.
+    ///
+    /// See also [`set_candidate_sequence`][Self::set_candidate_sequence].
+    #[must_use]
+    pub fn with_candidate_sequence(mut self, sequence: CandidateSequence) -> Self {
+        self.set_candidate_sequence(sequence);
+
+        self
+    }
+
+    /// Specify a density varying the radius across space
+    ///
+    /// `density` gives the desired density of points at each point in space, from 0 to 1. Where
+    /// the density is 1 points are `min_radius` apart, and elsewhere the number of points per unit
+    /// of volume is proportional to the density, up to a radius of `max_radius` where the density
+    /// is 0. This lets you concentrate points where they are needed, for example to follow the
+    /// tone of an image when stippling.
+    ///
+    /// Each new point is kept at least its own radius away from every other point, so points in
+    /// sparse regions may end up closer to their neighbors in denser regions than their own radius.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // Points get denser from left to right
+    /// let points = Poisson2D::new()
+    ///     .with_density(|[x, _]| x, 0.02, 0.2)
+    ///     .generate();
+    /// ```
+    ///
+    /// The density is only used when generating bounded distributions, by
+    /// [`iter`][Self::iter] and the methods based upon it. The radius is set to `min_radius`.
+    ///
+    /// See also [`set_density`][Self::set_density].
+    #[must_use]
+    pub fn with_density<F>(mut self, density: F, min_radius: Float, max_radius: Float) -> Self
+    where
+        F: Fn(Point<N>) -> Float + Send + Sync + 'static,
+    {
+        self.set_density(density, min_radius, max_radius);
+
+        self
+    }
+
+    /// Specify a density varying the radius across space from a grid of values
+    ///
+    /// This is [`with_density`](Self::with_density) for densities that come as raw data, such as
+    /// volumetric scientific data, rather than as a function. `data` holds `dims[0]` by `dims[1]`
+    /// by ... values from 0 to 1, with the first axis varying fastest, and the grid spans the
+    /// [reseeding](Self::with_reseeding) box, so [bounds](Self::with_bounds) should be set
+    /// first. Between the centers of the cells densities are interpolated multilinearly.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// // Densest in the middle of the cube
+    /// let mut data = vec![0.1; 27];
+    /// data[13] = 1.0;
+    ///
+    /// let points = Poisson3D::new()
+    ///     .with_bounds([-1.0; 3], [1.0; 3])
+    ///     .with_density_grid(data, [3, 3, 3], 0.1, 0.4)
+    ///     .generate();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `dims` is 0, or if `data` doesn't hold exactly one value for each
+    /// cell.
+    ///
+    /// See also [`set_density_grid`][Self::set_density_grid].
+    #[must_use]
+    pub fn with_density_grid(
+        mut self,
+        data: Vec<Float>,
+        dims: [usize; N],
+        min_radius: Float,
+        max_radius: Float,
+    ) -> Self {
+        self.set_density_grid(data, dims, min_radius, max_radius);
+
+        self
+    }
+
+    /// Specify an affine transform mapping every generated point into world space
+    ///
+    /// Each point `p` is output as `matrix * p + translation`, where `matrix` is given as rows, so
+    /// that the distribution can be generated in a convenient canonical frame and output rotated,
+    /// scaled, or sheared into place. Points are transformed lazily as they are output, so no
+    /// extra pass over them is needed.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// // The unit square, rotated a quarter turn and moved to the right
+    /// let points = Poisson2D::new()
+    ///     .with_transform([[0.0, -1.0], [1.0, 0.0]], [10.0, 0.0])
+    ///     .generate();
+    ///
+    /// assert!(points.iter().all(|&[x, y]| (9.0..=10.0).contains(&x) && (0.0..1.0).contains(&y)));
+    /// ```
+    ///
+    /// Only output is transformed: the radius, validation function, bounds, first points, and the
+    /// points given to [`merge`](Self::merge) all remain in the canonical frame, as do the points
+    /// of [`chunks`](Self::chunks) and [`generate_set`](Self::generate_set). A transform that
+    /// doesn't preserve distances, such as a scale or shear, changes the spacing of the output
+    /// points accordingly.
+    ///
+    /// See also [`set_transform`][Self::set_transform].
+    #[must_use]
+    pub fn with_transform(mut self, matrix: [[Float; N]; N], translation: Point<N>) -> Self {
+        self.set_transform(matrix, translation);
+
+        self
+    }
+
+    /// Specify the point validation function
+    pub fn set_validate(&mut self, func: fn([Float; N], &U) -> bool, user_data: U) {
+        self.memo.clear();
+        self.validate = func;
+        self.validate_user_data = user_data;
+    }
+
+    /// Specify radius around each point
+    pub fn set_radius(&mut self, radius: Float) {
+        self.memo.clear();
+        self.radius = radius;
+    }
+
+    /// Specify the radius such that the distribution has approximately `count` points
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_target_count(500);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_target_count`][Self::with_target_count] for more details.
+    pub fn set_target_count(&mut self, count: usize) {
+        self.memo.clear();
+        self.radius = estimate::radius(self, count);
+    }
+
+    /// The radius around each point
+    pub fn radius(&self) -> Float {
+        self.radius
+    }
+
+    /// The PRNG seed, if one was specified
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// The index of the PRNG's stream, if one was specified
+    ///
+    /// See [`with_stream`][Self::with_stream].
+    pub fn stream_index(&self) -> Option<u64> {
+        self.stream.map(|(index, _)| index)
+    }
+
+    /// The maximum number of samples generated around each point
+    ///
+    /// With [adaptive samples](Self::with_adaptive_samples) this is the most that are generated.
+    pub fn samples(&self) -> u32 {
+        match self.adaptive_samples {
+            Some([_, max]) => max,
+            None => self.num_samples,
+        }
+    }
+
+    /// The box, as `[min, max]`, that every point lies within, if one was specified
+    ///
+    /// See [`with_bounds`][Self::with_bounds].
+    pub fn bounds(&self) -> Option<[Point<N>; 2]> {
+        self.bounds
+    }
+
+    /// The box, as `[min, max]`, that the space is searched within for fresh starting points
+    ///
+    /// This is also the box within which the volume of the space is measured for estimates. See
+    /// [`with_reseeding`][Self::with_reseeding].
+    pub fn reseed_bounds(&self) -> [Point<N>; 2] {
+        self.reseed_bounds
+    }
+
+    /// Estimate the memory used to generate and collect this distribution, in bytes
+    ///
+    /// This is based on the [estimated number of points](Self::estimate_count) and the memory
+    /// each point takes up while generating, so it is only approximate. It doesn't include the
+    /// overhead of the allocator or the nodes of the spatial index.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let poisson = Poisson3D::new().with_radius(0.01);
+    /// println!("About {} MiB", poisson.memory_estimate() >> 20);
+    /// ```
+    pub fn memory_estimate(&self) -> usize {
+        estimate::memory(self)
+    }
+
+    /// A human-readable report of the effective configuration of this distribution
+    ///
+    /// Each setting is on a line of its own, followed by the estimated number of points and
+    /// memory use. This is meant for displaying and logging; its format may change.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let summary = Poisson2D::new().with_seed(0xBADBEEF).summary();
+    ///
+    /// assert!(summary.contains("radius: 0.1"));
+    /// assert!(summary.contains("seed: 195935983"));
+    /// ```
+    pub fn summary(&self) -> String {
+        let order = if self.progressive_output {
+            "progressive"
+        } else if self.shuffled_output {
+            "shuffled"
+        } else {
+            "generated"
+        };
+
+        let mut lines = vec![
+            format!("dimensions: {N}"),
+            format!("radius: {}", self.radius),
+            match self.seed {
+                Some(seed) => format!("seed: {seed}"),
+                None => "seed: from entropy".to_string(),
+            },
+        ];
+        if let Some(stream) = self.stream_index() {
+            lines.push(format!("stream: {stream}"));
+        }
+        lines.push(match self.adaptive_samples {
+            Some([min, max]) => format!("samples: {min} to {max}, adaptive"),
+            None => format!("samples: {}", self.num_samples),
+        });
+        lines.push(format!(
+            "annulus: {} to {}{}",
+            self.annulus[0],
+            self.annulus[1],
+            if self.volume_uniform {
+                ", by volume"
+            } else {
+                ""
+            }
+        ));
+        if self.radius_tolerance > 0.0 {
+            lines.push(format!("radius tolerance: {}", self.radius_tolerance));
+        }
+        if let Some([min, max]) = self.bounds {
+            lines.push(format!("bounds: {min:?} to {max:?}"));
+        }
+        if self.boundary != Boundary::Reject {
+            lines.push(format!("boundary: {:?}", self.boundary));
+        }
+        if let Some(Quotas { cells, min, max }) = self.quotas {
+            lines.push(format!(
+                "quotas: {min} to {max} points in each of {cells:?} cells"
+            ));
+        }
+        let [min, max] = self.reseed_bounds;
+        lines.push(format!(
+            "reseeding: {} attempts within {min:?} to {max:?}",
+            self.reseed_attempts
+        ));
+        if !self.first_points.is_empty() {
+            lines.push(format!(
+                "first points: {}{}",
+                self.first_points.len(),
+                if self.initial_point_emitted {
+                    ", emitted"
+                } else {
+                    ""
+                }
+            ));
+        }
+        lines.push(format!("selection: {:?}", self.selection));
+        if self.candidate_sequence != CandidateSequence::Random {
+            lines.push(format!("candidates: {:?}", self.candidate_sequence));
+        }
+        lines.push(format!("output order: {order}"));
+        if self.density.is_some() {
+            lines.push("density: varies".to_string());
+        }
+        if self.transform.is_some() {
+            lines.push("transform: affine".to_string());
+        }
+        lines.push(format!("estimated points: {}", self.estimate_count()));
+        lines.push(format!(
+            "estimated memory: {} bytes",
+            self.memory_estimate()
+        ));
+
+        lines.join("\n")
+    }
+
+    /// Specify the PRNG seed for this distribution
+    ///
+    /// If no seed is specified then the internal PRNG will be seeded from entropy, providing
+    /// non-deterministic and non-repeatable results.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_seed(0xBADBEEF);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See also [`with_seed`][Self::with_seed].
+    pub fn set_seed(&mut self, seed: u64) {
+        self.memo.clear();
+        self.seed = Some(seed);
+    }
+
+    /// Specify the PRNG seed as a string
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_seed_str("Atlantis");
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_seed_str`][Self::with_seed_str] for more details.
+    pub fn set_seed_str(&mut self, seed: &str) {
+        self.set_seed_bytes(seed.as_bytes());
+    }
+
+    /// Specify the PRNG seed as arbitrary bytes
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_seed_bytes(&[0xBA, 0xDB, 0xEE, 0xF0]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_seed_bytes`][Self::with_seed_bytes] for more details.
+    pub fn set_seed_bytes(&mut self, seed: &[u8]) {
+        self.set_seed(seed::hash(seed));
+    }
+
+    /// Specify which of the PRNG's independent streams to use
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// poisson.set_stream(3);
+    /// # let points = poisson.generate();
+    /// ```
+    ///
+    /// See [`with_stream`][Self::with_stream] for more details.
+    pub fn set_stream(&mut self, index: u64)
+    where
+        R: Jump,
+    {
+        self.memo.clear();
+        self.stream = Some((index, R::jump));
+    }
+
+    /// Specify the maximum samples to generate around each point
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let mut points = Poisson3D::new();
+    /// points.set_samples(40);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_samples`][Self::with_samples] for more details.
+    pub fn set_samples(&mut self, samples: u32) {
+        self.memo.clear();
+        self.num_samples = samples;
+    }
+
+    /// Adapt the number of samples around each point to how often they succeed
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_adaptive_samples(Some((4, 30)));
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_adaptive_samples`][Self::with_adaptive_samples] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn set_adaptive_samples(&mut self, samples: Option<(u32, u32)>) {
+        self.memo.clear();
+        if let Some((min, max)) = samples {
+            assert!(min <= max, "the fewest samples must not exceed the most");
+        }
+
+        self.adaptive_samples = samples.map(|(min, max)| [min, max]);
+    }
+
+    /// Specify how far from each point the samples around it are generated
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_annulus(1.0, 1.5);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_annulus`][Self::with_annulus] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= inner <= outer` and `outer > 0`.
+    pub fn set_annulus(&mut self, inner: Float, outer: Float) {
+        self.memo.clear();
+        assert!(
+            0.0 <= inner && inner <= outer && outer > 0.0,
+            "the annulus must satisfy 0 <= inner <= outer and outer > 0"
+        );
+
+        self.annulus = [inner, outer];
+    }
+
+    /// Allow points to be a little closer together than the radius
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_radius_tolerance(0.1);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_radius_tolerance`][Self::with_radius_tolerance] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= tolerance < 1`.
+    pub fn set_radius_tolerance(&mut self, tolerance: Float) {
+        self.memo.clear();
+        assert!(
+            (0.0..1.0).contains(&tolerance),
+            "the radius tolerance must satisfy 0 <= tolerance < 1"
+        );
+
+        self.radius_tolerance = tolerance;
+    }
+
+    /// Specify whether samples are spread evenly by volume within the annulus
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let mut points = Poisson3D::new();
+    /// points.set_volume_uniform(true);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_volume_uniform`][Self::with_volume_uniform] for more details.
+    pub fn set_volume_uniform(&mut self, volume_uniform: bool) {
+        self.memo.clear();
+        self.volume_uniform = volume_uniform;
+    }
+
+    /// Specify the point from which the distribution grows outward
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_first_point([0.5, 0.5]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_first_point`][Self::with_first_point] for more details.
+    pub fn set_first_point(&mut self, point: Point<N>) {
+        self.memo.clear();
+        self.first_points = vec![point];
+    }
+
+    /// Specify several points from which the distribution grows outward
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_first_points(&[[0.25, 0.25], [0.75, 0.75]]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_first_points`][Self::with_first_points] for more details.
+    pub fn set_first_points(&mut self, points: &[Point<N>]) {
+        self.memo.clear();
+        self.first_points = points.to_vec();
+    }
+
+    /// Specify whether the first point is included in the output
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_initial_point_emitted(true);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_initial_point_emitted`][Self::with_initial_point_emitted] for more details.
+    pub fn set_initial_point_emitted(&mut self, emitted: bool) {
+        self.memo.clear();
+        self.initial_point_emitted = emitted;
+    }
+
+    /// Specify the box, from `min` to `max`, to sample
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_bounds([-1.0, -1.0], [1.0, 1.0]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_bounds`][Self::with_bounds] for more details.
+    pub fn set_bounds(&mut self, min: Point<N>, max: Point<N>) {
+        self.memo.clear();
+        self.bounds = Some([min, max]);
+        self.reseed_bounds = [min, max];
+        // A domain still describes the space within the box
+        if self.sample_first.is_none() {
+            self.validate = |_, _| true;
+        }
+    }
+
+    /// Specify what happens to candidates that fall outside the box being sampled
+    ///
+    /// ```
+    /// # use fast_poisson::{Boundary, Poisson2D};
+    /// let mut points = Poisson2D::new();
+    /// points.set_boundary(Boundary::Clamp);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_boundary`][Self::with_boundary] for more details.
+    pub fn set_boundary(&mut self, boundary: Boundary) {
+        self.memo.clear();
+        self.boundary = boundary;
+    }
+
+    /// Specify the fewest and the most points within each cell of a grid over the box being
+    /// sampled
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_quotas([2, 2], 1, 20);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_quotas`][Self::with_quotas] for more details.
+    pub fn set_quotas(&mut self, cells: [usize; N], min: usize, max: usize) {
+        assert!(
+            cells.iter().all(|&c| c > 0),
+            "there must be at least one cell along each axis"
+        );
+        assert!(min <= max, "min must not exceed max");
+        self.memo.clear();
+        self.quotas = Some(Quotas { cells, min, max });
+    }
+
+    /// Specify how many attempts to make at finding a fresh starting point
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_reseeding(1000, [0.0, 0.0], [1.0, 1.0]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_reseeding`][Self::with_reseeding] for more details.
+    pub fn set_reseeding(&mut self, attempts: u32, min: Point<N>, max: Point<N>) {
+        self.memo.clear();
+        self.reseed_attempts = attempts;
+        self.reseed_bounds = [min, max];
+    }
+
+    /// Specify whether the output is returned in random order
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_shuffled_output(true);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_shuffled_output`][Self::with_shuffled_output] for more details.
+    pub fn set_shuffled_output(&mut self, shuffled: bool) {
+        self.memo.clear();
+        self.shuffled_output = shuffled;
+    }
+
+    /// Specify whether the output is returned in progressive order
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_progressive_output(true);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_progressive_output`][Self::with_progressive_output] for more details.
+    pub fn set_progressive_output(&mut self, progressive: bool) {
+        self.memo.clear();
+        self.progressive_output = progressive;
+    }
+
+    /// Specify how the next active point to sample around is chosen
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, SelectionStrategy};
+    /// let mut points = Poisson2D::new();
+    /// points.set_selection(SelectionStrategy::Newest);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_selection`][Self::with_selection] for more details.
+    pub fn set_selection(&mut self, selection: SelectionStrategy) {
+        self.memo.clear();
+        self.selection = selection;
+    }
+
+    /// Specify where the candidates generated around each active point come from
+    ///
+    /// ```
+    /// # use fast_poisson::{CandidateSequence, Poisson2D};
+    /// let mut points = Poisson2D::new();
+    /// points.set_candidate_sequence(CandidateSequence::Halton);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_candidate_sequence`][Self::with_candidate_sequence] for more details.
+    pub fn set_candidate_sequence(&mut self, sequence: CandidateSequence) {
+        self.memo.clear();
+        self.candidate_sequence = sequence;
+    }
+
+    /// Estimate the number of points in this distribution without generating it
+    ///
+    /// The estimate is based on the volume of the space and the typical density of a Poisson disk
+    /// distribution in as many dimensions, so it is only approximate; it is nevertheless useful
+    /// to pre-allocate buffers, or to sanity-check a configuration before paying for generation.
+    /// The volume is measured by sampling the validation function within the
+    /// [reseeding](Self::with_reseeding) box, by default the unit hypercube.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.05);
+    ///
+    /// let mut points = Vec::with_capacity(poisson.estimate_count());
+    /// points.extend(poisson.iter());
+    /// ```
+    pub fn estimate_count(&self) -> usize {
+        estimate::count(self)
+    }
+
+    /// Specify a density varying the radius across space
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_density(|[x, _]| x, 0.02, 0.2);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_density`][Self::with_density] for more details.
+    pub fn set_density<F>(&mut self, density: F, min_radius: Float, max_radius: Float)
+    where
+        F: Fn(Point<N>) -> Float + Send + Sync + 'static,
+    {
+        self.memo.clear();
+        self.radius = min_radius;
+        self.density = Some(Density::new(density, min_radius, max_radius));
+    }
+
+    /// Specify a density varying the radius across space from a grid of values
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_density_grid(vec![0.2, 1.0, 0.5, 0.1], [2, 2], 0.02, 0.2);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_density_grid`][Self::with_density_grid] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `dims` is 0, or if `data` doesn't hold exactly one value for each
+    /// cell.
+    pub fn set_density_grid(
+        &mut self,
+        data: Vec<Float>,
+        dims: [usize; N],
+        min_radius: Float,
+        max_radius: Float,
+    ) {
+        let grid = density::grid(data, dims, self.reseed_bounds);
+        self.set_density(grid, min_radius, max_radius);
+    }
+
+    /// Specify an affine transform mapping every generated point into world space
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Poisson2D::new();
+    /// points.set_transform([[2.0, 0.0], [0.0, 2.0]], [-1.0, -1.0]);
+    /// # let points = points.generate();
+    /// ```
+    ///
+    /// See [`with_transform`][Self::with_transform] for more details.
+    pub fn set_transform(&mut self, matrix: [[Float; N]; N], translation: Point<N>) {
+        self.memo.clear();
+        self.transform = Some(Affine::new(matrix, translation));
+    }
+
+    /// A new RNG for generating this distribution, from its seed and stream if they are given
+    pub(crate) fn rng(&self) -> R {
+        // If we were not given a seed, generate one non-deterministically
+        let mut rng = match self.seed {
+            None => R::from_entropy(),
+            Some(seed) => R::seed_from_u64(seed),
+        };
+        if let Some((index, jump)) = self.stream {
+            for _ in 0..index {
+                jump(&mut rng);
+            }
+        }
+
+        rng
+    }
+
+    /// Returns true if the point lies within the bounds, if any, and passes validation
+    pub(crate) fn in_space(&self, point: Point<N>) -> bool {
+        let in_bounds = self.bounds.map_or(true, |[min, max]| {
+            (0..N).all(|i| (min[i]..max[i]).contains(&point[i]))
+        });
+
+        in_bounds && (self.validate)(point, &self.validate_user_data)
+    }
+
+    /// Move a candidate outside the box back into it, as the [boundary](Self::with_boundary)
+    /// specifies
+    pub(crate) fn bounded(&self, point: Point<N>) -> Point<N> {
+        self.boundary.apply(point, self.sampled_box())
+    }
+
+    /// The box being sampled: the bounds if there are any, or else the reseeding box
+    pub(crate) fn sampled_box(&self) -> [Point<N>; 2] {
+        self.bounds.unwrap_or(self.reseed_bounds)
+    }
+
+    /// Map a point generated in the canonical frame into world space
+    pub(crate) fn transformed(&self, point: Point<N>) -> Point<N> {
+        match &self.transform {
+            Some(transform) => transform.apply(point),
+            None => point,
+        }
+    }
+
+    /// The radius around the given point, which may vary with the density
+    pub(crate) fn radius_at(&self, point: Point<N>) -> Float {
+        match &self.density {
+            Some(density) => density.radius(point),
+            None => self.radius,
+        }
+    }
+
+    /// Returns an iterator over the points in this distribution
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let points = Poisson3D::new();
+    ///
+    /// for point in points.iter() {
+    ///     println!("{:?}", point);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> Iter<N, U, R> {
+        Iter::new(self.clone())
+    }
+
+    /// Returns an iterator over the points in this distribution, if it is configured correctly
+    ///
+    /// See [`check`][Self::check] for the configuration that is checked.
+    pub fn try_iter(&self) -> Result<Iter<N, U, R>, PoissonError> {
+        self.check()?;
+
+        Ok(self.iter())
+    }
+
+    /// Check that this distribution is configured correctly
+    ///
+    /// Invalid configurations don't stop a distribution from being generated, but do give empty or
+    /// pathological output: with a radius of zero, for instance, generation never ends. This
+    /// reports the first of these problems:
+    ///
+    /// * The radius must be positive and finite.
+    /// * At least one sample must be generated around each point, whether set with
+    ///   [`with_samples`](Self::with_samples) or as the most [adaptive
+    ///   samples](Self::with_adaptive_samples).
+    /// * The [bounds](Self::with_bounds) must be finite, and their minimum must be below their
+    ///   maximum along every axis.
+    /// * When [reseeding](Self::with_reseeding), the box must be finite, and its minimum may not
+    ///   be above its maximum along any axis.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, PoissonError};
+    /// assert_eq!(Poisson2D::new().check(), Ok(()));
+    /// assert_eq!(
+    ///     Poisson2D::new().with_radius(-1.0).check(),
+    ///     Err(PoissonError::InvalidRadius(-1.0))
+    /// );
+    /// ```
+    pub fn check(&self) -> Result<(), PoissonError> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Err(PoissonError::InvalidRadius(self.radius));
+        }
+
+        let samples = match self.adaptive_samples {
+            None => self.num_samples,
+            Some([_, max]) => max,
+        };
+        if samples == 0 {
+            return Err(PoissonError::NoSamples);
+        }
+
+        if let Some([min, max]) = self.bounds {
+            if let Some(axis) =
+                (0..N).find(|&i| !(min[i].is_finite() && max[i].is_finite() && min[i] < max[i]))
+            {
+                return Err(PoissonError::InvalidBounds(axis));
+            }
+        }
+
+        if self.reseed_attempts > 0 {
+            let [min, max] = self.reseed_bounds;
+            if let Some(axis) =
+                (0..N).find(|&i| !(min[i].is_finite() && max[i].is_finite() && min[i] <= max[i]))
+            {
+                return Err(PoissonError::InvalidReseeding(axis));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the points in this distribution, using the given spatial index to
+    /// test the spacing of new points
+    ///
+    /// The distribution is the same whichever index is used. See the [`spatial`] module for the
+    /// indices provided, or implement [`SpatialIndex`](spatial::SpatialIndex) to use your own.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use fast_poisson::spatial::Grid;
+    ///
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let points: Vec<_> = poisson.iter_with_index::<Grid<2>>().collect();
+    ///
+    /// assert_eq!(points, poisson.generate());
+    /// ```
+    #[must_use]
+    pub fn iter_with_index<I: SpatialIndex<N>>(&self) -> Iter<N, U, R, I> {
+        Iter::new(self.clone())
+    }
+
+    /// Returns an iterator over the points in this distribution, each with a stable identifier
+    ///
+    /// Identifiers are derived from the seed and the order in which points are generated, so the
+    /// same seed always produces the same identifier for the same point. This lets you cache data
+    /// associated with each point and recognize it again when the distribution is regenerated.
+    /// Without a seed, identifiers are still unique within the distribution, but will differ each
+    /// time.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    ///
+    /// let first: Vec<_> = poisson.iter_with_ids().collect();
+    /// let second: Vec<_> = poisson.iter_with_ids().collect();
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn iter_with_ids(&self) -> impl Iterator<Item = (u64, Point<N>)> {
+        let key = self.seed.unwrap_or_else(rand::random);
+
+        self.iter()
+            .enumerate()
+            .map(move |(i, point)| (seed::derive(key, i as u64), point))
+    }
+
+    /// Returns an iterator over the points in this distribution, each with attributes drawn by
+    /// `attributes` from the distribution's own RNG
+    ///
+    /// Because the attributes are drawn from the same seeded RNG stream as the points themselves,
+    /// the entire scatter, points and attributes alike, is reproducible from a single seed. Note
+    /// that this also means the points differ from those generated by [`iter`][Self::iter] with
+    /// the same seed, depending on how many random numbers `attributes` draws.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use rand::Rng;
+    ///
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let trees: Vec<_> = poisson
+    ///     .iter_scatter(|rng, _point| {
+    ///         let rotation: f32 = rng.gen_range(0.0..360.0);
+    ///         let variant: u8 = rng.gen_range(0..4);
+    ///         (rotation, variant)
+    ///     })
+    ///     .collect();
+    /// ```
+    pub fn iter_scatter<F, T>(&self, attributes: F) -> Scatter<N, U, R, F>
+    where
+        F: FnMut(&mut R, Point<N>) -> T,
+    {
+        Scatter::new(self.iter(), attributes)
+    }
+
+    /// Returns an iterator over the points in this distribution, each with the index of its parent
+    ///
+    /// Every point is generated around an existing point, its parent; the index yielded alongside
+    /// each point is the position of its parent in this iterator's output. Points with no parent
+    /// in the output, such as those grown from the (not emitted) first point or found by
+    /// [reseeding](Self::with_reseeding), have `None` instead. Together these form a spawn tree,
+    /// useful for building networks or animating growth.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points: Vec<_> = Poisson2D::new().iter_with_parent().collect();
+    ///
+    /// for (parent, point) in points.iter() {
+    ///     if let Some(parent) = parent {
+    ///         println!("Edge from {:?} to {:?}", points[*parent].1, point);
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn iter_with_parent(&self) -> WithParent<N, U, R> {
+        WithParent::new(self.iter())
+    }
+
+    /// Returns an endless iterator over a distribution growing outward from `center`
+    ///
+    /// The space is unbounded, so the validation function is not used; instead points are returned
+    /// in order of increasing distance from `center`, which is itself the first point. Use an
+    /// adapter such as [`take_while`](Iterator::take_while) to stop generating:
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let in_view = |[x, y]: [_; 2]| x * x + y * y < 25.0;
+    ///
+    /// for point in Poisson2D::new().iter_unbounded([0.0, 0.0]).take_while(|&p| in_view(p)) {
+    ///     println!("{:?}", point);
+    /// }
+    /// ```
+    ///
+    /// See [`Unbounded`] for details.
+    #[must_use]
+    pub fn iter_unbounded(&self, center: Point<N>) -> Unbounded<N, U, R> {
+        Unbounded::new(self.clone(), center)
+    }
+
+    /// Returns a sampler throwing darts into this distribution, rather than using Bridson's
+    /// algorithm
+    ///
+    /// Dart throwing is far slower, but is the reference for the statistical properties of Poisson
+    /// disk distributions, which makes it useful to validate the output of faster samplers. The
+    /// radius, density, validation function, seed, and reseeding box are used; darts are thrown
+    /// within the reseeding box.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let fast = poisson.generate();
+    /// let reference = poisson.dart_throwing().generate();
+    ///
+    /// // Both fill the space about equally well
+    /// assert!(fast.len().abs_diff(reference.len()) < fast.len() / 3);
+    /// ```
+    ///
+    /// See [`DartThrowing`] for details.
+    #[must_use]
+    pub fn dart_throwing(&self) -> DartThrowing<N, U, R> {
+        DartThrowing::new(self.clone())
+    }
+
+    /// Returns a sampler placing one random point within each cell of a grid, rather than using
+    /// Bridson's algorithm
+    ///
+    /// Jittered grids are much cheaper to generate but only approximate a Poisson disk
+    /// distribution, and only guarantee the radius between points when asked to. The radius,
+    /// validation function, seed, and reseeding box are used.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let points = Poisson2D::new()
+    ///     .with_seed(0xBADBEEF)
+    ///     .jittered_grid()
+    ///     .with_min_distance(true)
+    ///     .generate();
+    /// ```
+    ///
+    /// See [`JitteredGrid`] for details.
+    #[must_use]
+    pub fn jittered_grid(&self) -> JitteredGrid<N, U, R> {
+        JitteredGrid::new(self.clone())
+    }
+
+    /// Returns a generator for chunks of `size` along each axis
+    ///
+    /// Chunks are generated deterministically from the seed, and points respect the radius across
+    /// chunk borders; without a seed, chunks are only consistent with others from the same
+    /// [`Chunks`]. See [`Chunks`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is smaller than the radius.
+    #[must_use]
+    pub fn chunks(&self, size: Float) -> Chunks<N, U, R> {
+        Chunks::new(self.clone(), size)
+    }
+
+    /// Generate the points in a single chunk of `size` along each axis
+    ///
+    /// This is a shortcut for [`chunks`][Self::chunks], and is only consistent with other chunks
+    /// if a seed was specified. When generating many chunks, prefer [`Chunks`] so that the work
+    /// shared between neighboring chunks is only done once.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    ///
+    /// let here = poisson.chunk(1.0, [3, 4]);
+    /// let next_door = poisson.chunk(1.0, [4, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is smaller than the radius.
+    #[must_use]
+    pub fn chunk(&self, size: Float, coord: [i64; N]) -> Vec<Point<N>> {
+        self.chunks(size).chunk(coord).to_vec()
+    }
+
+    /// Generate the points in this Poisson distribution, collected into a [`Vec`](std::vec::Vec).
+    ///
+    /// Note that this method does *not* consume the `Poisson`, so you can call it multiple times
+    /// to generate multiple `Vec`s; if you have specified a seed, each one will be identical,
+    /// whereas they will each be unique if you have not (see [`Poisson::set_seed`]).
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut poisson = Poisson2D::new();
+    ///
+    /// let points1 = poisson.generate();
+    /// let points2 = poisson.generate();
+    ///
+    /// // These are not identical because no seed was specified
+    /// assert!(points1.iter().zip(points2.iter()).any(|(a, b)| a != b));
+    ///
+    /// poisson.set_seed(1337);
+    ///
+    /// let points3 = poisson.generate();
+    /// let points4 = poisson.generate();
+    ///
+    /// // These are identical because a seed was specified
+    /// assert!(points3.iter().zip(points4.iter()).all(|(a, b)| a == b));
+    /// ```
+    pub fn generate(&self) -> Vec<Point<N>> {
+        // Size our buffers up front, rather than growing them over and over
+        let capacity = estimate::capacity(self);
+        let mut iter = self.iter();
+        iter.reserve(capacity);
+
+        let mut points = Vec::with_capacity(capacity);
+        points.extend(iter);
+
+        points
+    }
+
+    /// Generate the points in this distribution, remembering them for subsequent calls
+    ///
+    /// A seeded distribution generates the same points every time, so rather than generating them
+    /// all over again, the points are kept the first time and copied on each later call. They are
+    /// forgotten as soon as any setting is changed, and are shared with clones of this
+    /// distribution. Without a seed each call generates new points, exactly like
+    /// [`generate`](Self::generate).
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    ///
+    /// // Only the first call does any work
+    /// let points = poisson.generate_cached();
+    /// assert_eq!(poisson.generate_cached(), points);
+    ///
+    /// poisson.set_radius(0.05);
+    /// assert!(poisson.generate_cached().len() > points.len());
+    /// ```
+    #[must_use]
+    pub fn generate_cached(&mut self) -> Vec<Point<N>> {
+        if self.seed.is_none() {
+            return self.generate();
+        }
+
+        if let Some(points) = self.memo.get() {
+            return points;
+        }
+
+        let points = self.generate();
+        self.memo.set(&points);
+
+        points
+    }
+
+    /// Generate the points in this distribution into `buffer`, returning how many were written
+    ///
+    /// Points are written from the start of `buffer`, in the same order as from
+    /// [`generate`][Self::generate]. If the distribution has more points than fit, generation
+    /// stops once `buffer` is full, so a buffer that's filled completely may not hold every
+    /// point. This lets a fixed buffer be reused, such as every frame of a game loop, without
+    /// allocating new storage for the output each time.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let mut buffer = [[0.0; 2]; 1000];
+    ///
+    /// let count = poisson.generate_into(&mut buffer);
+    /// assert_eq!(buffer[..count], poisson.generate());
+    /// ```
+    pub fn generate_into(&self, buffer: &mut [Point<N>]) -> usize {
+        let mut count = 0;
+        for (slot, point) in buffer.iter_mut().zip(self.iter()) {
+            *slot = point;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Generate the points in this distribution into `points`, replacing its contents
+    ///
+    /// This is the same as [`generate`][Self::generate], except that the capacity of `points` is
+    /// reused, so that once it has grown large enough, repeated generations don't allocate new
+    /// storage for their output.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut points = Vec::new();
+    /// for seed in 0..10 {
+    ///     Poisson2D::new().with_seed(seed).generate_into_vec(&mut points);
+    ///     assert_eq!(points, Poisson2D::new().with_seed(seed).generate());
+    /// }
+    /// ```
+    pub fn generate_into_vec(&self, points: &mut Vec<Point<N>>) {
+        points.clear();
+        self.for_each_point(|point| points.push(point));
+    }
+
+    /// Generate the points in this distribution, bundled with the settings that produced them
+    ///
+    /// If no seed was specified, one is chosen randomly and recorded, so that the distribution
+    /// can always be [regenerated](GenerationResult::regenerate).
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let result = Poisson2D::new().with_seed(0xBADBEEF).generate_result();
+    ///
+    /// assert_eq!(result.seed, 0xBADBEEF);
+    /// assert_eq!(result.dimensions, 2);
+    /// ```
+    #[must_use]
+    pub fn generate_result(&self) -> GenerationResult<N> {
+        let mut seeded = self.clone();
+        seeded.seed = Some(self.seed.unwrap_or_else(rand::random));
+        let points = seeded.generate();
+
+        GenerationResult::new(&seeded, points)
+    }
+
+    /// Generate the points in this distribution, with their coordinates interleaved in a single
+    /// `Vec`
+    ///
+    /// The coordinates of each point follow those of the point before it, as `x0, y0, x1, y1, …`
+    /// in 2D, which is the layout of a vertex or storage buffer of points. The output can
+    /// therefore be copied straight into such a buffer, without repacking it first.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let flat = poisson.generate_flat();
+    ///
+    /// assert_eq!(flat.len(), 2 * poisson.generate().len());
+    /// assert_eq!(flat[..2], poisson.generate()[0]);
+    /// ```
+    #[must_use]
+    pub fn generate_flat(&self) -> Vec<Float> {
+        let mut flat = Vec::with_capacity(estimate::capacity(self) * N);
+        self.for_each_point(|point| flat.extend_from_slice(&point));
+
+        flat
+    }
+
+    /// Generate the points in this distribution as a 2D array, with a row per point
+    ///
+    /// The array has a shape of `(points, N)`, with the coordinates of each point in a row of
+    /// their own. For a column per point instead, take its transpose with
+    /// [`reversed_axes`](ndarray::ArrayBase::reversed_axes), which doesn't copy the data.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// let poisson = Poisson3D::new().with_seed(0xBADBEEF);
+    /// let array = poisson.generate_array();
+    ///
+    /// let points = poisson.generate();
+    /// assert_eq!(array.shape(), [points.len(), 3]);
+    /// assert_eq!(array.row(0).to_vec(), points[0]);
+    ///
+    /// let columns = array.reversed_axes();
+    /// assert_eq!(columns.shape(), [3, points.len()]);
+    /// ```
+    ///
+    /// Requires the `ndarray` feature.
+    #[cfg(feature = "ndarray")]
+    #[must_use]
+    pub fn generate_array(&self) -> ndarray::Array2<Float> {
+        let flat = self.generate_flat();
+        let rows = flat.len() / N;
+
+        ndarray::Array2::from_shape_vec((rows, N), flat)
+            .expect("every point has a coordinate for each axis")
+    }
+
+    /// Generate the points in this distribution, with each axis in a `Vec` of its own
+    ///
+    /// This is the structure-of-arrays layout: the first `Vec` holds the first coordinate of
+    /// every point, and so on. Each can be copied straight into a buffer of its own, such as for
+    /// a shader that reads each axis separately.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let [xs, ys] = poisson.generate_columns();
+    ///
+    /// let points = poisson.generate();
+    /// assert_eq!(xs.len(), points.len());
+    /// assert_eq!([xs[0], ys[0]], points[0]);
+    /// ```
+    #[must_use]
+    pub fn generate_columns(&self) -> [Vec<Float>; N] {
+        let capacity = estimate::capacity(self);
+        let mut columns: [Vec<Float>; N] = std::array::from_fn(|_| Vec::with_capacity(capacity));
+        self.for_each_point(|point| {
+            for (column, x) in columns.iter_mut().zip(point) {
+                column.push(x);
+            }
+        });
+
+        columns
+    }
+
+    /// Call `f` with every point in this distribution, as it is generated
+    ///
+    /// The points are the same, in the same order, as from [`iter`][Self::iter], but they are
+    /// never collected, and generation runs in a single tight loop rather than through the
+    /// iterator. The buffers used during generation are sized up front, as by
+    /// [`generate`][Self::generate], so that they needn't be reallocated as they grow. This suits
+    /// hot loops and memory-constrained targets that process each point once and move on.
+    ///
+    /// Shuffled or progressive output can only begin once every point is generated, so those
+    /// are still collected internally first.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut sum = [0.0, 0.0];
+    /// Poisson2D::new().for_each_point(|[x, y]| {
+    ///     sum[0] += x;
+    ///     sum[1] += y;
+    /// });
+    /// ```
+    pub fn for_each_point<F: FnMut(Point<N>)>(&self, f: F) {
+        let mut iter = self.iter();
+        iter.reserve(estimate::capacity(self));
+
+        iter.for_each_point(f);
+    }
+
+    /// Generate the points in this distribution, if it is configured correctly
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new().try_generate()?;
+    ///
+    /// assert!(Poisson2D::new().with_samples(0).try_generate().is_err());
+    /// # Ok::<(), fast_poisson::PoissonError>(())
+    /// ```
+    ///
+    /// See [`check`][Self::check] for the configuration that is checked.
+    pub fn try_generate(&self) -> Result<Vec<Point<N>>, PoissonError> {
+        self.check()?;
+
+        Ok(self.generate())
+    }
+
+    /// Generate at least `count` points, trying up to `attempts` different distributions
+    ///
+    /// A small space with a large radius holds only a few points, and just how few varies from
+    /// one distribution to the next. This generates distributions until one has at least `count`
+    /// points. Without a seed each attempt is generated from fresh entropy. With a seed the first
+    /// attempt is the same distribution as [`generate`](Self::generate), and later attempts use
+    /// seeds derived from it, so the result is just as deterministic.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, PoissonError};
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.3);
+    ///
+    /// let points = poisson.generate_at_least(9, 100)?;
+    /// assert!(points.len() >= 9);
+    ///
+    /// // No more than 4 points fit in the unit square at this radius
+    /// assert!(matches!(
+    ///     Poisson2D::new().with_radius(0.8).generate_at_least(5, 10),
+    ///     Err(PoissonError::TooFewPoints { required: 5, .. })
+    /// ));
+    /// # Ok::<(), PoissonError>(())
+    /// ```
+    ///
+    /// An error is returned if the configuration is invalid, as with
+    /// [`try_generate`](Self::try_generate), if `attempts` is zero, even when `count` is too, or if
+    /// every attempt generated fewer than `count` points.
+    pub fn generate_at_least(
+        &self,
+        count: usize,
+        attempts: u32,
+    ) -> Result<Vec<Point<N>>, PoissonError> {
+        self.check()?;
+        if attempts == 0 {
+            return Err(PoissonError::NoAttempts);
+        }
+
+        let mut found = 0;
+        for attempt in 0..attempts {
+            let points = match self.seed {
+                Some(seed) if attempt > 0 => {
+                    let mut poisson = self.clone();
+                    poisson.set_seed(seed::derive(seed, attempt as u64));
+                    poisson.generate()
+                }
+                _ => self.generate(),
+            };
+
+            if points.len() >= count {
+                return Ok(points);
+            }
+            found = found.max(points.len());
+        }
+
+        Err(PoissonError::TooFewPoints {
+            required: count,
+            found,
+        })
+    }
+
+    /// Generate exactly `count` points, spread as far apart as possible
+    ///
+    /// This uses weighted sample elimination: several times as many points are generated as
+    /// needed, and then the most crowded point is removed, over and over, until exactly `count`
+    /// remain. The radius is ignored, as the spacing follows from the count instead; the
+    /// validation function, seed, and [reseeding](Self::with_reseeding) box, by default the unit
+    /// hypercube, are used to generate the points to eliminate from. A
+    /// [density](Self::with_density) is not supported.
+    ///
+    /// Fewer points are returned only if the space can't hold `count` points at all, such as when
+    /// it is empty.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let points = Poisson2D::new().with_seed(0xBADBEEF).generate_exact(1000);
+    ///
+    /// assert_eq!(points.len(), 1000);
+    /// ```
+    pub fn generate_exact(&self, count: usize) -> Vec<Point<N>> {
+        let volume = estimate::volume(self);
+        if volume <= 0.0 || count == 0 {
+            return Vec::new();
+        }
+
+        let mut candidates = self.exact_candidates(count);
+        let order = elimination::eliminate(&candidates, volume, count);
+
+        let mut kept = vec![false; candidates.len()];
+        for &i in order.iter().take(count) {
+            kept[i] = true;
+        }
+        let mut kept = kept.into_iter();
+        candidates.retain(|_| kept.next().unwrap_or(false));
+
+        candidates
+            .into_iter()
+            .map(|point| self.transformed(point))
+            .collect()
+    }
+
+    /// Generate several times as many points as `count`, for elimination to choose from
+    fn exact_candidates(&self, count: usize) -> Vec<Point<N>> {
+        let mut poisson = self.clone();
+        poisson.density = None;
+        // Candidates are eliminated by their spacing in the canonical frame
+        poisson.transform = None;
+
+        // The radius is only estimated, so try again with more points if we fall short
+        let mut candidates = Vec::new();
+        for oversampling in [5, 10, 20, 40] {
+            poisson.set_target_count(count.saturating_mul(oversampling));
+            candidates = poisson.generate();
+            if candidates.len() >= count {
+                break;
+            }
+        }
+
+        candidates
+    }
+
+    /// Generate points along the boundary of the space, at least `radius` apart
+    ///
+    /// Points are placed along the outline of the space in 2 dimensions, or its surface in 3 or
+    /// more, evenly but randomly spaced as in a Poisson disk distribution of the boundary itself.
+    /// This is useful for fences, props along a coastline, or stippling contours. The boundary is
+    /// searched for within the [reseeding](Self::with_reseeding) box, by default the unit
+    /// hypercube, and includes the faces of the box wherever the space meets them; features of the
+    /// space much narrower than `radius` may be missed. Every point lies within the space, very
+    /// near to its boundary.
+    ///
+    /// To fill the interior as well, [`merge`](Self::merge) the outline into the distribution:
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let disk = Poisson2D::new()
+    ///     .with_validate(|[x, y], _| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.16, ())
+    ///     .with_radius(0.05);
+    ///
+    /// let outline = disk.generate_outline(0.02);
+    /// let points = disk.merge(&outline, 0.05);
+    ///
+    /// assert!(outline.len() > 60);
+    /// ```
+    ///
+    /// The cost grows with the volume of the reseeding box divided by `radius` to the `N`th power,
+    /// so small radii in many dimensions are expensive.
+    pub fn generate_outline(&self, radius: Float) -> Vec<Point<N>> {
+        outline::outline(self, radius)
+            .into_iter()
+            .map(|point| self.transformed(point))
+            .collect()
+    }
+
+    /// Pack disks, or spheres in 3 dimensions, with radii drawn from a distribution
+    ///
+    /// Rather than keeping every point the same radius apart, each point is the center of a disk
+    /// with its own radius drawn from `sizes`, and no two disks may overlap: the centers of disks
+    /// with radii `r1` and `r2` are at least `r1 + r2` apart. This makes a simple random packer of
+    /// circles or spheres of varying sizes. Returns the center and radius of every disk.
+    ///
+    /// The radius set on this distribution and any [density](Self::with_density) are ignored.
+    /// New disks are placed around existing ones at a distance of the two radii added together,
+    /// scaled by the [annulus](Self::with_annulus); an annulus close to `(1.0, 1.0)` packs disks
+    /// more tightly. Only the centers must lie within the space, and with a
+    /// [transform](Self::with_transform) only the centers are transformed.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use rand::distributions::Uniform;
+    ///
+    /// let disks = Poisson2D::new()
+    ///     .with_annulus(1.0, 1.2)
+    ///     .generate_packing(Uniform::new(0.01, 0.05));
+    ///
+    /// for (i, &([x1, y1], r1)) in disks.iter().enumerate() {
+    ///     for &([x2, y2], r2) in &disks[..i] {
+    ///         assert!((x1 - x2).powi(2) + (y1 - y2).powi(2) >= (r1 + r2).powi(2));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Draws that aren't positive and finite are rejected.
+    pub fn generate_packing<D>(&self, sizes: D) -> Vec<(Point<N>, Float)>
+    where
+        D: rand::distributions::Distribution<Float>,
+    {
+        packing::pack(self, sizes)
+            .into_iter()
+            .map(|(center, radius)| (self.transformed(center), radius))
+            .collect()
+    }
+
+    /// Generate the points in this distribution, handing them to `sink` in batches rather than
+    /// keeping them all in memory
+    ///
+    /// This is meant for distributions far too large to fit in memory, such as hundreds of
+    /// millions of points. The reseeding box, which defaults to the unit box, is divided into
+    /// tiles 16 radii wide, which are generated one at a time in a sweep along the first axis.
+    /// Each tile is generated around its neighbors generated before it, so points maintain the
+    /// radius across tile borders. Once a tile's points are complete they are passed to `sink`,
+    /// and they are forgotten as soon as the sweep has moved past them, so that only a frontier
+    /// about two tiles deep is ever kept in memory.
+    ///
+    /// The distribution is not the same as the one generated by [`generate`][Self::generate].
+    /// Only the radius, number of samples, validation function, and reseeding box are used: tiles
+    /// are seeded at their centers, and every tile is searched for a starting point. A
+    /// [density](Self::with_density) is not supported.
+    ///
+    /// Returns the number of points generated, or the first error returned by `sink`, at which
+    /// point generation stops.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use std::io::Write;
+    ///
+    /// let mut file = Vec::new();
+    /// let count = Poisson2D::new()
+    ///     .with_radius(0.02)
+    ///     .generate_out_of_core(|points| {
+    ///         for [x, y] in points {
+    ///             writeln!(file, "{},{}", x, y)?;
+    ///         }
+    ///         Ok::<_, std::io::Error>(())
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn generate_out_of_core<F, E>(&self, mut sink: F) -> Result<usize, E>
+    where
+        F: FnMut(&[Point<N>]) -> Result<(), E>,
+    {
+        if self.transform.is_none() {
+            return Tiling::new(self).sweep::<R, F, E>(sink);
+        }
+
+        Tiling::new(self).sweep::<R, _, E>(|points| {
+            let world: Vec<_> = points.iter().map(|&p| self.transformed(p)).collect();
+            sink(&world)
+        })
+    }
+
+    /// Generate one distribution for each of the seeds, in the same order
+    ///
+    /// Every distribution shares all of this distribution's settings other than its seed, so this
+    /// is equivalent to generating them one by one with [`with_seed`][Self::with_seed]. With the
+    /// `rayon` feature, the distributions are generated in parallel.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.2);
+    /// let ensemble = poisson.generate_batch(&[1, 2, 3]);
+    ///
+    /// assert_eq!(ensemble.len(), 3);
+    /// assert_eq!(ensemble[1], poisson.with_seed(2).generate());
+    /// ```
+    #[cfg(not(feature = "rayon"))]
+    pub fn generate_batch(&self, seeds: &[u64]) -> Vec<Vec<Point<N>>> {
+        let mut distribution = self.clone();
+
+        seeds
+            .iter()
+            .map(|&seed| {
+                distribution.set_seed(seed);
+                distribution.generate()
+            })
+            .collect()
+    }
+
+    /// Generate one distribution for each of the seeds, in parallel, in the same order
+    ///
+    /// Every distribution shares all of this distribution's settings other than its seed, so this
+    /// is equivalent to generating them one by one with [`with_seed`][Self::with_seed].
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.2);
+    /// let ensemble = poisson.generate_batch(&[1, 2, 3]);
+    ///
+    /// assert_eq!(ensemble.len(), 3);
+    /// assert_eq!(ensemble[1], poisson.with_seed(2).generate());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn generate_batch(&self, seeds: &[u64]) -> Vec<Vec<Point<N>>>
+    where
+        U: Sync,
+        R: Sync,
+    {
+        parallel::generate_batch(self, seeds)
+    }
+
+    /// Generate the points in this distribution in parallel, with the same result for the same
+    /// seed regardless of the number of threads
+    ///
+    /// The reseeding box, which defaults to the unit box, is divided into tiles 16 radii wide.
+    /// Tiles are generated in `2^N` phases, such that no two tiles in the same phase are adjacent;
+    /// within each phase, tiles are generated in parallel, each around the points already
+    /// generated in the adjacent tiles of earlier phases. Points therefore maintain the radius
+    /// across tile borders, and the output depends only on the seed and the distribution's
+    /// settings.
+    ///
+    /// The distribution is not the same as the one generated by [`generate`][Self::generate],
+    /// and is returned tile by tile rather than growing outwards from a single point. Only the
+    /// radius, number of samples, validation function, and reseeding box are used: tiles are
+    /// seeded at their centers, and every tile is searched for a starting point. A
+    /// [density](Self::with_density) is not supported.
+    ///
+    /// Parallelism only pays off once there are several tiles along each axis, so this is meant
+    /// for large distributions.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.02).with_seed(0xBADBEEF);
+    ///
+    /// assert_eq!(
+    ///     poisson.par_generate_deterministic(),
+    ///     poisson.par_generate_deterministic()
+    /// );
+    /// ```
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn par_generate_deterministic(&self) -> Vec<Point<N>>
+    where
+        U: Sync,
+    {
+        parallel::generate_deterministic(self)
+    }
+
+    /// Returns an iterator over the points in this distribution, each with the distance to the
+    /// closest point accepted before it
+    ///
+    /// This is the spacing the point was accepted with, so it is never less than the radius, unless
+    /// a [radius tolerance](Self::with_radius_tolerance) lets it fall short by up to that fraction
+    /// of the radius. The very first point has nothing to be compared with, and so has `None`
+    /// instead.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.1);
+    ///
+    /// for (_point, spacing) in poisson.iter_with_spacing() {
+    ///     if let Some(spacing) = spacing {
+    ///         assert!(spacing >= 0.1);
+    ///     }
+    /// }
+    /// ```
+    pub fn iter_with_spacing(&self) -> WithSpacing<N, U, R> {
+        WithSpacing::new(self.iter())
+    }
+
+    /// Generate the points in this distribution, each with the distance to the closest point
+    /// accepted before it
+    ///
+    /// This is a shortcut to collecting [`iter_with_spacing`][Self::iter_with_spacing] into a
+    /// `Vec`.
+    pub fn generate_with_spacing(&self) -> Vec<(Point<N>, Option<Float>)> {
+        self.iter_with_spacing().collect()
+    }
+
+    /// Generate the points in this distribution, along with statistics about their generation
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let (points, stats) = Poisson2D::new().with_samples(10).generate_with_stats();
+    ///
+    /// println!(
+    ///     "{} points from {} candidates in {:?}: {:.1}% accepted",
+    ///     points.len(),
+    ///     stats.candidates,
+    ///     stats.elapsed,
+    ///     stats.acceptance_rate() * 100.0,
+    /// );
+    /// ```
+    ///
+    /// See [`GenerationStats`] for the statistics gathered.
+    pub fn generate_with_stats(&self) -> (Vec<Point<N>>, GenerationStats) {
+        let start = Instant::now();
+        let mut iter = self.iter();
+        let points = iter.by_ref().collect();
+
+        let mut stats = iter.stats().clone();
+        stats.elapsed = start.elapsed();
+
+        (points, stats)
+    }
+
+    /// Optimize points towards a capacity-constrained Voronoi tessellation of this distribution
+    ///
+    /// This is a post-process for when quality matters more than speed, such as for stippling,
+    /// halftoning, and research into blue noise. The space is represented by `sites_per_point`
+    /// sites for each point, drawn according to the [density](Self::with_density), and each point
+    /// is given an equal share of the sites close to it. For up to `iterations` iterations,
+    /// neighboring points trade sites wherever that brings the sites closer to their points, and
+    /// each point then moves to the centroid of its sites. Unlike Lloyd relaxation, every point
+    /// keeps an equal share of the density, so the points follow it closely without settling into
+    /// a regular grid.
+    ///
+    /// The sites are drawn from the reseeding box and validation function, using the seed. More
+    /// sites give a better result at a higher cost: 64 to 256 per point is usually plenty, while
+    /// research into blue noise may call for 1024 or more. Points are optimized in the space being
+    /// sampled, so any [transform](Self::with_transform) is ignored. Optimized points may end up
+    /// a little closer together than the radius.
+    ///
+    /// Returns the number of iterations run, which is fewer than `iterations` if the points
+    /// converged sooner.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new()
+    ///     .with_seed(0xBADBEEF)
+    ///     .with_density(|[x, _]| 0.2 + 0.8 * x, 0.05, 0.2);
+    ///
+    /// let mut points = poisson.generate();
+    /// let iterations = poisson.optimize_ccvt(&mut points, 64, 20);
+    ///
+    /// assert!(iterations > 0);
+    /// ```
+    pub fn optimize_ccvt(
+        &self,
+        points: &mut [Point<N>],
+        sites_per_point: usize,
+        iterations: u32,
+    ) -> u32 {
+        ccvt::optimize(self, points, sites_per_point, iterations)
+    }
+
+    /// Generate the points in this distribution, along with the neighbors of each point
+    ///
+    /// Returns the points, and for each point the indices of all other points within `distance`
+    /// of it, in ascending order. The neighbors are found with the same spatial index used during
+    /// generation, so there is no need to build another one. A `distance` of twice the radius
+    /// connects each point to its immediate surroundings.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new().with_radius(0.1);
+    /// let (points, neighbors) = poisson.generate_graph(0.2);
+    ///
+    /// for (i, adjacent) in neighbors.iter().enumerate() {
+    ///     for &j in adjacent.iter().filter(|&&j| j > i) {
+    ///         println!("Edge from {:?} to {:?}", points[i], points[j]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Points are in their natural order, even if [shuffled](Self::with_shuffled_output) or
+    /// [progressive](Self::with_progressive_output) output was requested.
+    pub fn generate_graph(&self, distance: Float) -> (Vec<Point<N>>, Vec<Vec<usize>>) {
+        self.iter().to_empty().neighbors(distance)
+    }
+
+    /// Generate the points in this distribution, sorted along a space-filling curve
+    ///
+    /// Points that are close together in the output are also close together in space, which
+    /// makes for cache-friendly downstream processing, such as uploading points in chunks or
+    /// batching them by locality.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, SpatialOrder};
+    /// let points = Poisson2D::new().generate_sorted(SpatialOrder::Hilbert);
+    /// ```
+    pub fn generate_sorted(&self, order: SpatialOrder) -> Vec<Point<N>> {
+        let mut points = self.generate();
+        order::sort(&mut points, order);
+
+        points
+    }
+
+    /// Generate the points in this distribution, rounded to the cells of an integer grid
+    ///
+    /// Each point is mapped to the closest cell of a grid whose cells are `cell_size` wide, so that
+    /// a point at `[x, y]` becomes `[(x / cell_size).round(), (y / cell_size).round()]`. Rounding
+    /// moves points closer together, so cells that end up too close to an earlier one are dropped:
+    /// the remaining cells are at least `radius / cell_size` cells apart, rounded down, when
+    /// measured with `metric`. No two points ever share a cell.
+    ///
+    /// This is ideal for placing things on tile maps, without having to de-duplicate and re-check
+    /// the spacing of rounded points.
+    ///
+    /// ```
+    /// # use fast_poisson::{GridMetric, Poisson2D};
+    /// // Place items on a 100 x 100 tile map, with at least 3 tiles between them in every direction
+    /// let items = Poisson2D::new()
+    ///     .with_radius(0.03)
+    ///     .generate_grid_points(0.01, GridMetric::Chebyshev);
+    ///
+    /// for [x, y] in items {
+    ///     assert!((0..=100).contains(&x) && (0..=100).contains(&y));
+    /// }
+    /// ```
+    pub fn generate_grid_points(&self, cell_size: Float, metric: GridMetric) -> Vec<[i64; N]> {
+        // Forgive rounding errors, so that a radius of three cells isn't taken for 2.999... cells
+        let min = (self.radius / cell_size * (1.0 + 4.0 * Float::EPSILON)).floor() as i64;
+
+        lattice::quantize(&self.generate(), cell_size, min, metric)
     }
 
-    /// Specify the PRNG seed for this distribution
+    /// Generate the points in this distribution, each with an estimate of the volume it represents
     ///
-    /// If no seed is specified then the internal PRNG will be seeded from entropy, providingfast_poisson
-    /// non-deterministic and non-repeatable results.
+    /// The weight of each point approximates the area (or volume) of its Voronoi cell: the region
+    /// of space closer to it than to any other point. It is estimated from the number of points in
+    /// each point's neighborhood, taking into account how much of that neighborhood lies within
+    /// the space, so the weights sum to approximately the total volume of the space. This is
+    /// useful for importance-weighted integration, or to scale stipples for correct tone
+    /// reproduction.
     ///
     /// ```
     /// # use fast_poisson::Poisson2D;
-    /// let points = Poisson2D::new().with_seed(0xBADBEEF).iter();
-    /// ```
+    /// let points = Poisson2D::new().generate_weighted();
     ///
-    /// See also [`set_seed`][Self::set_seed].
-    #[must_use]
-    pub fn with_seed(mut self, seed: u64) -> Self {
-        self.set_seed(seed);
+    /// // Estimate the integral of x * y over the unit square
+    /// let integral = points
+    ///     .iter()
+    ///     .fold(0.0, |sum, ([x, y], weight)| sum + x * y * weight);
+    /// assert!((integral - 0.25).abs() < 0.05);
+    /// ```
+    pub fn generate_weighted(&self) -> Vec<(Point<N>, Float)> {
+        let points = self.generate();
+        let weights = weights::weigh(self, &points);
 
-        self
+        points.into_iter().zip(weights).collect()
     }
 
-    /// Specify the maximum samples to generate around each point
+    /// Analyze how well a set of points covers the space of this distribution
     ///
-    /// Note that this is not specifying the number of samples in the resulting distribution, but
-    /// rather sets the maximum number of attempts to find a new, valid point around an existing
-    /// point for each iteration of the algorithm.
+    /// Reports whether the points are maximal, that is whether no more points could be added
+    /// without violating this distribution's radius, along with the largest empty sphere within
+    /// the space. Bridson's algorithm gives up on a region after a fixed number of samples, so even
+    /// generated distributions may contain the occasional gap; this lets you check for them, for
+    /// example before meshing, where gaps result in degenerate elements.
     ///
-    /// A higher number may result in better space filling, but may also slow down generation.
+    /// The space is searched within the [reseeding](Self::with_reseeding) box, by default the unit
+    /// hypercube. The search is randomized, so the largest gap found is a lower bound; it is
+    /// however thorough enough to reliably find any gap large enough to fit another point.
     ///
     /// ```
-    /// # use fast_poisson::Poisson3D;
-    /// let points = Poisson3D::new().with_samples(40).iter();
-    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let poisson = Poisson2D::new();
+    /// let points = poisson.generate();
     ///
-    /// See also [`set_samples`][Self::set_samples].
-    #[must_use]
-    pub fn with_samples(mut self, samples: u32) -> Self {
-        self.set_samples(samples);
-
-        self
+    /// let coverage = poisson.coverage(&points);
+    /// if !coverage.maximal {
+    ///     println!(
+    ///         "Gap of radius {} around {:?}",
+    ///         coverage.largest_gap_radius, coverage.largest_gap
+    ///     );
+    /// }
+    /// ```
+    pub fn coverage(&self, points: &[Point<N>]) -> Coverage<N> {
+        coverage::analyze(self, points)
     }
 
-    /// Specify the point validation function
-    pub fn set_validate(&mut self, func: fn([Float; N], &U) -> bool, user_data: U) {
-        self.validate = func;
-        self.validate_user_data = user_data;
+    /// Generate the points in this distribution, streaming them to `writer` as CSV
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut file = Vec::new();
+    /// Poisson2D::new().write_csv(&mut file)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// See [`export::write_csv`] for details of the format.
+    pub fn write_csv<W: Write>(&self, writer: W) -> io::Result<()> {
+        export::write_csv(writer, self, self.iter())
     }
 
-
-    /// Specify radius around each point
-    pub fn set_radius(&mut self, radius: Float) {
-        self.radius = radius;
+    /// Generate the points in this distribution, streaming them to `writer` as JSON
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut file = Vec::new();
+    /// Poisson2D::new().write_json(&mut file)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// See [`export::write_json`] for details of the format.
+    pub fn write_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        export::write_json(writer, self, self.iter())
     }
 
-    /// Specify the PRNG seed for this distribution
+    /// Generate the points in this distribution as an Arrow record batch
     ///
-    /// If no seed is specified then the internal PRNG will be seeded from entropy, providing
-    /// non-deterministic and non-repeatable results.
+    /// The batch has one column per axis, named `x`, `y`, `z`, and `w` for up to 4 dimensions, or
+    /// `x0`, `x1`, and so on otherwise. The seed, radius, and number of dimensions are recorded in
+    /// the schema's metadata.
     ///
     /// ```
     /// # use fast_poisson::Poisson2D;
-    /// let mut points = Poisson2D::new();
-    /// points.set_seed(0xBADBEEF);
-    /// # let points = points.generate();
+    /// let batch = Poisson2D::new().to_record_batch()?;
+    /// # Ok::<(), arrow::error::ArrowError>(())
     /// ```
     ///
-    /// See also [`with_seed`][Self::with_seed].
-    pub fn set_seed(&mut self, seed: u64) {
-        self.seed = Some(seed);
+    /// Requires the `arrow` feature.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> Result<arrow::array::RecordBatch, arrow::error::ArrowError> {
+        columnar::record_batch(columnar::schema(self), &self.generate())
     }
 
-    /// Specify the maximum samples to generate around each point
+    /// Generate the points in this distribution as a series of Arrow record batches
     ///
+    /// Each batch holds up to `batch_size` points, and only one batch is held in memory at a
+    /// time, so this is suitable for millions of points. See
+    /// [`to_record_batch`][Self::to_record_batch] for the layout of each batch.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// for batch in Poisson2D::new().record_batches(100) {
+    ///     println!("{} points", batch?.num_rows());
+    /// }
+    /// # Ok::<(), arrow::error::ArrowError>(())
     /// ```
+    ///
+    /// Requires the `arrow` feature.
+    #[cfg(feature = "arrow")]
+    pub fn record_batches(
+        &self,
+        batch_size: usize,
+    ) -> impl Iterator<Item = Result<arrow::array::RecordBatch, arrow::error::ArrowError>> {
+        columnar::record_batches(self, batch_size)
+    }
+
+    /// Generate the points in this distribution, streaming them to `writer` as a Parquet file
+    ///
+    /// Points are written in row groups of up to `batch_size` points each, so that only one batch
+    /// is held in memory at a time. See [`to_record_batch`][Self::to_record_batch] for the
+    /// layout of the columns.
+    ///
+    /// ```no_run
     /// # use fast_poisson::Poisson3D;
-    /// let mut points = Poisson3D::new();
-    /// points.set_samples(40);
-    /// # let points = points.generate();
+    /// let file = std::fs::File::create("points.parquet")?;
+    /// Poisson3D::new().with_radius(0.01).write_parquet(file, 100_000)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     ///
-    /// See [`with_samples`][Self::with_samples] for more details.
-    pub fn set_samples(&mut self, samples: u32) {
-        self.num_samples = samples;
+    /// Requires the `arrow` feature.
+    #[cfg(feature = "arrow")]
+    pub fn write_parquet<W: Write + Send>(
+        &self,
+        writer: W,
+        batch_size: usize,
+    ) -> Result<(), parquet::errors::ParquetError> {
+        columnar::write_parquet(self, writer, batch_size)
     }
 
-    /// Returns an iterator over the points in this distribution
+    /// Merge newly generated points into an existing set of points
+    ///
+    /// The existing points are loaded into the distribution first, so the new points fill the
+    /// space around them. Between any new point and any existing one, the larger of `radius` and
+    /// this distribution's radius is maintained; this makes it easy to layer distributions that
+    /// were generated at different times, or with different radii.
+    ///
+    /// The returned `Vec` contains the existing points, in their original order, followed by the
+    /// newly generated ones. With a [transform](Self::with_transform), the existing points are
+    /// given in the canonical frame and transformed along with the new ones.
     ///
     /// ```
-    /// # use fast_poisson::Poisson3D;
-    /// let points = Poisson3D::new();
+    /// # use fast_poisson::Poisson2D;
+    /// let trees = Poisson2D::new().with_radius(0.2).generate();
+    /// let bushes = Poisson2D::new().with_radius(0.05).merge(&trees, 0.1);
     ///
-    /// for point in points.iter() {
-    ///     println!("{:?}", point);
-    /// }
+    /// assert_eq!(trees[..], bushes[..trees.len()]);
+    /// ```
+    pub fn merge(&self, existing: &[Point<N>], radius: Float) -> Vec<Point<N>> {
+        let mut iter = self.iter();
+        iter.load_existing(existing, radius);
+
+        existing
+            .iter()
+            .map(|&point| self.transformed(point))
+            .chain(iter)
+            .collect()
+    }
+
+    /// Generate nested levels of detail, one for each radius in `radii`
+    ///
+    /// Coarser levels, with larger radii, are subsets of finer ones: generation starts at the
+    /// largest radius, and each finer level keeps every point of the level before it while filling
+    /// the gaps at its own radius. Showing the points of a level and fading in those of the next
+    /// thus never makes existing points jump or disappear.
+    ///
+    /// Levels are returned in the same order as `radii`, which need not be sorted. Within each
+    /// level the points of coarser levels come first, in the same order as in those levels. Any
+    /// radius set on this distribution is ignored, as is a [density](Self::with_density).
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let levels = Poisson2D::new().generate_lod(&[0.2, 0.1, 0.05]);
+    ///
+    /// // Trees, then bushes around the trees, then grass around both
+    /// let (trees, bushes, grass) = (&levels[0], &levels[1], &levels[2]);
+    /// assert_eq!(trees[..], bushes[..trees.len()]);
+    /// assert_eq!(bushes[..], grass[..bushes.len()]);
+    /// ```
+    pub fn generate_lod(&self, radii: &[Float]) -> Vec<Vec<Point<N>>> {
+        let mut coarsest_first: Vec<usize> = (0..radii.len()).collect();
+        coarsest_first.sort_by(|&a, &b| radii[b].total_cmp(&radii[a]));
+
+        let mut levels = vec![Vec::new(); radii.len()];
+        let Some(&coarsest) = coarsest_first.first() else {
+            return levels;
+        };
+
+        let mut poisson = self.clone();
+        poisson.radius = radii[coarsest];
+        poisson.density = None;
+
+        let mut iter = poisson.iter();
+        let mut points = Vec::new();
+        for (level, &i) in coarsest_first.iter().enumerate() {
+            if level > 0 {
+                iter.densify(radii[i]);
+            }
+            points.extend(iter.by_ref());
+            levels[i] = points.clone();
+        }
+
+        levels
+    }
+
+    /// Generate the points in this Poisson distribution into a [`PoissonSet`]
+    ///
+    /// Unlike a `Vec`, the set allows points to be removed and inserted later while maintaining the
+    /// distribution's properties.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let mut set = Poisson2D::new().generate_set();
+    /// let first = set.points()[0];
+    ///
+    /// set.remove(first);
+    /// set.refill();
     /// ```
     #[must_use]
-    pub fn iter(&self) -> Iter<N, U, R> {
-        Iter::new(self.clone())
+    pub fn generate_set(&self) -> PoissonSet<N, U, R> {
+        PoissonSet::new(self.clone())
     }
 
-    /// Generate the points in this Poisson distribution, collected into a [`Vec`](std::vec::Vec).
+    /// Returns an endless iterator over frames of this distribution changing smoothly over time
     ///
-    /// Note that this method does *not* consume the `Poisson`, so you can call it multiple times
-    /// to generate multiple `Vec`s; if you have specified a seed, each one will be identical,
-    /// whereas they will each be unique if you have not (see [`Poisson::set_seed`]).
+    /// Points persist from frame to frame, moving a little each frame, disappearing as they leave
+    /// the space, and appearing as gaps open up, while the radius is maintained in every frame.
+    /// Each frame is a `Vec` of every point with its stable identifier, as from
+    /// [`iter_with_ids`](Self::iter_with_ids). This is useful for animated stippling or particle
+    /// placement, without points popping in and out from frame to frame.
     ///
     /// ```
     /// # use fast_poisson::Poisson2D;
-    /// let mut poisson = Poisson2D::new();
+    /// let frames: Vec<_> = Poisson2D::new().animate().take(10).collect();
+    /// ```
     ///
-    /// let points1 = poisson.generate();
-    /// let points2 = poisson.generate();
+    /// See [`Animation`] for details.
+    #[must_use]
+    pub fn animate(&self) -> Animation<N, U, R> {
+        Animation::new(self.clone())
+    }
+
+    /// Returns a stream of the points in this distribution, for generating within async tasks
     ///
-    /// // These are not identical because no seed was specified
-    /// assert!(points1.iter().zip(points2.iter()).any(|(a, b)| a != b));
+    /// The stream returns the same points as [`iter`][Self::iter], but yields to the executor
+    /// after every so many points, so that generating a large distribution doesn't keep other
+    /// tasks from running.
     ///
-    /// poisson.set_seed(1337);
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use futures::StreamExt;
     ///
-    /// let points3 = poisson.generate();
-    /// let points4 = poisson.generate();
+    /// # futures::executor::block_on(async {
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let points: Vec<_> = poisson.stream().collect().await;
     ///
-    /// // These are identical because a seed was specified
-    /// assert!(points3.iter().zip(points4.iter()).all(|(a, b)| a == b));
+    /// assert_eq!(points, poisson.generate());
+    /// # });
     /// ```
-    pub fn generate(&self) -> Vec<Point<N>> {
-        self.iter().collect()
+    ///
+    /// See [`PointStream`] for details.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn stream(&self) -> PointStream<N, U, R> {
+        PointStream::new(self.iter())
+    }
+
+    /// Generate the points in this distribution on a background thread, sending each through
+    /// `sender` as it is generated
+    ///
+    /// This lets a consumer, such as a renderer or a writer, process points while the rest are
+    /// still being generated. The channel's capacity provides backpressure: once it's full,
+    /// generation waits for the consumer to catch up, so memory use stays bounded however large
+    /// the distribution. Generation stops early if the receiver is dropped.
+    ///
+    /// The returned handle gives the number of points sent once the thread finishes.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use std::sync::mpsc::sync_channel;
+    ///
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    /// let (sender, receiver) = sync_channel(64);
+    /// let producer = poisson.spawn_into(sender);
+    ///
+    /// let points: Vec<_> = receiver.iter().collect();
+    /// assert_eq!(producer.join().unwrap(), points.len());
+    /// assert_eq!(points, poisson.generate());
+    /// ```
+    pub fn spawn_into(&self, sender: SyncSender<Point<N>>) -> JoinHandle<usize>
+    where
+        U: Send + 'static,
+        R: Send + 'static,
+    {
+        let distribution = self.clone();
+        thread::spawn(move || {
+            let mut sent = 0;
+            for point in distribution.iter() {
+                if sender.send(point).is_err() {
+                    break;
+                }
+                sent += 1;
+            }
+            sent
+        })
     }
 
+    /// Generate the points in this distribution, as a k-d tree
+    ///
+    /// The item stored with each point is its index in the output of [`generate`][Self::generate].
+    #[cfg(feature = "kiddo")]
     pub fn generate_kd_tree(&self) -> KdTree<Float, N> {
-        self.iter().to_empty().to_sampled()
+        let mut iter = self.iter();
+        iter.reserve(estimate::capacity(self));
+
+        iter.to_empty().to_sampled()
+    }
+
+    /// Generate the points in this distribution, indexed for fast read-only queries
+    ///
+    /// The k-d tree returned by [`generate_kd_tree`][Self::generate_kd_tree] is the one built up
+    /// during generation, which is still cheap to modify. If the points will only be queried,
+    /// this index is both faster to query and smaller. Points are identified by their index in
+    /// the output of [`generate`][Self::generate].
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let index = Poisson2D::new().with_seed(0xBADBEEF).generate_immutable_kd_tree();
+    ///
+    /// let (closest, _distance) = index.nearest([0.5, 0.5]).unwrap();
+    /// for (i, _) in index.within(index.points()[closest], 0.15) {
+    ///     println!("{:?} is near {:?}", index.points()[i], index.points()[closest]);
+    /// }
+    /// ```
+    #[cfg(feature = "kiddo")]
+    pub fn generate_immutable_kd_tree(&self) -> PointIndex<N> {
+        PointIndex::new(self.generate())
     }
 
     /// Generate the points in the Poisson distribution, as a [`Vec<T>`](std::vec::Vec).
@@ -406,12 +3373,276 @@ where
     ///
     /// let points: Vec<Point> = Poisson2D::new().to_vec();
     /// ```
+    ///
+    /// To output another type without a `From` implementation, or to name the type of the
+    /// distribution itself, see [`with_map`](Self::with_map).
     pub fn to_vec<T>(&self) -> Vec<T>
     where
         T: From<[Float; N]>,
     {
         self.iter().map(|point| point.into()).collect()
     }
+
+    /// Map every generated point into a custom type as it is output
+    ///
+    /// This generalizes [`to_vec`](Self::to_vec): the returned [`Mapped`] distribution iterates
+    /// over and generates values of type `T` directly, mapping each point lazily after any
+    /// [transform](Self::with_transform). The map is shared between clones and iterators, so it
+    /// must be `Send + Sync`.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let labels: Vec<String> = Poisson2D::new()
+    ///     .with_seed(0xBADBEEF)
+    ///     .with_map(|[x, y]| format!("{x:.2},{y:.2}"))
+    ///     .iter()
+    ///     .take(3)
+    ///     .collect();
+    ///
+    /// assert_eq!(labels.len(), 3);
+    /// ```
+    ///
+    /// Configure the distribution before mapping it; it can still be reached through
+    /// [`Mapped::distribution`].
+    #[must_use]
+    pub fn with_map<T, F>(self, map: F) -> Mapped<N, T, U, R>
+    where
+        F: Fn(Point<N>) -> T + Send + Sync + 'static,
+    {
+        Mapped::new(self, map)
+    }
+}
+
+impl<U, R> Poisson<2, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Returns a sampler placing points along a golden-angle spiral, rather than using Bridson's
+    /// algorithm
+    ///
+    /// The spiral of a sunflower head gives an even spread of points with a distinctive,
+    /// deterministic look. The radius, validation function, seed, and reseeding box are used.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    ///
+    /// // Code consuming a sampler works the same with any pattern
+    /// fn count<S: Sampler<2>>(sampler: &S) -> usize {
+    ///     sampler.sample().count()
+    /// }
+    ///
+    /// assert!(count(&poisson.phyllotaxis()) > 0);
+    /// assert!(count(&poisson) > 0);
+    /// ```
+    ///
+    /// See [`Phyllotaxis`] for details.
+    #[must_use]
+    pub fn phyllotaxis(&self) -> Phyllotaxis<U, R> {
+        Phyllotaxis::new(self.clone())
+    }
+
+    /// Returns a sampler displacing each point of a hexagonal lattice by a random amount, rather
+    /// than using Bridson's algorithm
+    ///
+    /// Jittered hexagonal lattices are much cheaper to generate, and denser, but look more
+    /// regular than a Poisson disk distribution. The radius, validation function, seed, and
+    /// reseeding box are used.
+    ///
+    /// ```
+    /// # use fast_poisson::{Poisson2D, Sampler};
+    /// let points = Poisson2D::new()
+    ///     .with_seed(0xBADBEEF)
+    ///     .jittered_hex()
+    ///     .with_jitter(0.25)
+    ///     .generate();
+    /// ```
+    ///
+    /// See [`JitteredHex`] for details.
+    #[must_use]
+    pub fn jittered_hex(&self) -> JitteredHex<U, R> {
+        JitteredHex::new(self.clone())
+    }
+
+    /// Generate a set of Wang tiles for covering an unbounded plane
+    ///
+    /// Tiles are `size` wide and high, and use this distribution's radius, seed, and number of
+    /// samples; the validation function is not used. A tile is generated for each combination of
+    /// `colors` corner colors, so `colors` should be kept small: 2 or 3 are usually plenty.
+    ///
+    /// See [`TileSet`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than 6 times the radius, or if `colors` is 0.
+    #[must_use]
+    pub fn generate_tiles(&self, size: Float, colors: u32) -> TileSet {
+        TileSet::new(self, size, colors)
+    }
+
+    /// Generate the points in this distribution, rendered as an SVG image
+    ///
+    /// Each point is drawn as a dot, optionally surrounded by the circle of this distribution's
+    /// radius that no other point may enter, which makes it easy to judge parameter choices by
+    /// eye. The image covers the [reseeding](Self::with_reseeding) box, by default the unit square.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use fast_poisson::export::SvgOptions;
+    ///
+    /// let options = SvgOptions {
+    ///     scale: 800.0,
+    ///     exclusion_circles: true,
+    ///     ..Default::default()
+    /// };
+    /// let svg = Poisson2D::new().to_svg(&options);
+    /// ```
+    pub fn to_svg(&self, options: &export::SvgOptions) -> String {
+        export::svg(self, self.iter(), options)
+    }
+
+    /// Generate the points in this distribution, rasterized into an image
+    ///
+    /// Each point is drawn as a dot, optionally surrounded by the circle of this distribution's
+    /// radius, on top of a mask showing which parts of the image are inside the space according
+    /// to the validation function. This makes for quick visual debugging of validation functions
+    /// and density maps. The image covers the [reseeding](Self::with_reseeding) box, by default
+    /// the unit square, with the minimum in the top left corner.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use fast_poisson::export::RasterOptions;
+    ///
+    /// let options = RasterOptions {
+    ///     exclusion_circles: true,
+    ///     ..Default::default()
+    /// };
+    /// let image = Poisson2D::new().to_image(256, 256, &options);
+    /// ```
+    ///
+    /// Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn to_image(
+        &self,
+        width: u32,
+        height: u32,
+        options: &export::RasterOptions,
+    ) -> image::RgbImage {
+        export::rasterize(self, self.iter(), width, height, options)
+    }
+
+    /// Stipple an image with about `point_budget` points
+    ///
+    /// The image is converted to grayscale, and points are distributed with a density
+    /// proportional to the darkness of each pixel, such that drawing a dot for each point
+    /// reproduces the tone of the image. The radius is chosen to produce about `point_budget`
+    /// points in total; the lightest parts of the image are sparsely covered rather than left
+    /// empty. Points are in pixel coordinates, from `[0, 0]` up to the width and height of the
+    /// image.
+    ///
+    /// Optionally, the points are then improved by `relaxation` iterations of Lloyd's algorithm,
+    /// weighted by darkness, which makes the spacing more regular and the edges in the image
+    /// crisper at the cost of some of the randomness.
+    ///
+    /// Only the seed and the number of samples of this distribution are used.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// use image::{DynamicImage, GrayImage, Luma};
+    ///
+    /// // A dark disk on a light background
+    /// let image = DynamicImage::ImageLuma8(GrayImage::from_fn(64, 64, |x, y| {
+    ///     let inside = (x as i32 - 32).pow(2) + (y as i32 - 32).pow(2) < 400;
+    ///     Luma([if inside { 32 } else { 224 }])
+    /// }));
+    ///
+    /// let stipples = Poisson2D::new().stipple(&image, 200, 2);
+    /// ```
+    ///
+    /// Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn stipple(
+        &self,
+        image: &image::DynamicImage,
+        point_budget: usize,
+        relaxation: u32,
+    ) -> Vec<Point<2>> {
+        stipple::stipple(self, image, point_budget, relaxation)
+    }
+
+    /// Generate the points in this distribution, rendering them to a PNG file at `path`
+    ///
+    /// This is a shortcut to saving the image created by [`to_image`][Self::to_image] with the
+    /// default options.
+    ///
+    /// ```no_run
+    /// # use fast_poisson::Poisson2D;
+    /// Poisson2D::new().render_png("points.png", 512, 512)?;
+    /// # Ok::<(), image::ImageError>(())
+    /// ```
+    ///
+    /// Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn render_png<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        width: u32,
+        height: u32,
+    ) -> image::ImageResult<()> {
+        self.to_image(width, height, &Default::default())
+            .save_with_format(path, image::ImageFormat::Png)
+    }
+}
+
+impl<U, R> Poisson<3, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Generate the points in this distribution, writing them to `writer` as a PLY point cloud
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// use fast_poisson::export::PointCloudAttributes;
+    ///
+    /// let mut file = Vec::new();
+    /// Poisson3D::new().write_ply(&mut file, PointCloudAttributes::default())?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// See [`export::write_ply`] for details of the format.
+    pub fn write_ply<W: Write>(
+        &self,
+        writer: W,
+        attributes: export::PointCloudAttributes,
+    ) -> io::Result<()> {
+        export::write_ply(writer, self, self.iter_with_spacing(), attributes)
+    }
+
+    /// Generate the points in this distribution, streaming them to `writer` as an XYZ point cloud
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson3D;
+    /// use fast_poisson::export::PointCloudAttributes;
+    ///
+    /// let mut file = Vec::new();
+    /// let attributes = PointCloudAttributes {
+    ///     id: true,
+    ///     ..Default::default()
+    /// };
+    /// Poisson3D::new().write_xyz(&mut file, attributes)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// See [`export::write_xyz`] for details of the format.
+    pub fn write_xyz<W: Write>(
+        &self,
+        writer: W,
+        attributes: export::PointCloudAttributes,
+    ) -> io::Result<()> {
+        export::write_xyz(writer, self.iter_with_spacing(), attributes)
+    }
 }
 
 /// Note that without a specified seed, a cloned `Poisson` will *not* generate
@@ -427,10 +3658,30 @@ where
         Self {
             validate: self.validate,
             validate_user_data: self.validate_user_data.clone(),
+            sample_first: self.sample_first,
             radius: self.radius,
             seed: self.seed,
+            stream: self.stream,
             num_samples: self.num_samples,
-            _rng: PhantomData::default(),
+            adaptive_samples: self.adaptive_samples,
+            annulus: self.annulus,
+            radius_tolerance: self.radius_tolerance,
+            volume_uniform: self.volume_uniform,
+            first_points: self.first_points.clone(),
+            initial_point_emitted: self.initial_point_emitted,
+            reseed_attempts: self.reseed_attempts,
+            reseed_bounds: self.reseed_bounds,
+            bounds: self.bounds,
+            boundary: self.boundary,
+            quotas: self.quotas,
+            shuffled_output: self.shuffled_output,
+            progressive_output: self.progressive_output,
+            selection: self.selection,
+            candidate_sequence: self.candidate_sequence,
+            density: self.density.clone(),
+            transform: self.transform,
+            memo: self.memo.clone(),
+            _rng: PhantomData,
         }
     }
 }
@@ -446,7 +3697,25 @@ where
             && other.seed.is_some()
             && self.radius == other.radius
             && self.seed == other.seed
+            && self.stream.map(|(index, _)| index) == other.stream.map(|(index, _)| index)
             && self.num_samples == other.num_samples
+            && self.adaptive_samples == other.adaptive_samples
+            && self.annulus == other.annulus
+            && self.radius_tolerance == other.radius_tolerance
+            && self.volume_uniform == other.volume_uniform
+            && self.first_points == other.first_points
+            && self.initial_point_emitted == other.initial_point_emitted
+            && self.reseed_attempts == other.reseed_attempts
+            && self.reseed_bounds == other.reseed_bounds
+            && self.bounds == other.bounds
+            && self.boundary == other.boundary
+            && self.quotas == other.quotas
+            && self.shuffled_output == other.shuffled_output
+            && self.progressive_output == other.progressive_output
+            && self.selection == other.selection
+            && self.candidate_sequence == other.candidate_sequence
+            && self.density == other.density
+            && self.transform == other.transform
     }
 }
 
@@ -457,10 +3726,30 @@ where
 {
     fn default() -> Self {
         Self {
-            validate: |p, _|{ p.iter().all(|&n| n >= 0.0 && n < 1.0) },
+            validate: |p, _| p.iter().all(|n| (0.0..1.0).contains(n)),
+            sample_first: None,
             radius: 0.1,
             seed: None,
+            stream: None,
             num_samples: 30,
+            adaptive_samples: None,
+            annulus: [1.0, 2.0],
+            radius_tolerance: 0.0,
+            volume_uniform: false,
+            first_points: Vec::new(),
+            initial_point_emitted: false,
+            reseed_attempts: 0,
+            reseed_bounds: [[0.0; N], [1.0; N]],
+            bounds: None,
+            boundary: Boundary::Reject,
+            quotas: None,
+            shuffled_output: false,
+            progressive_output: false,
+            selection: SelectionStrategy::Random,
+            candidate_sequence: CandidateSequence::Random,
+            density: None,
+            transform: None,
+            memo: Memo::default(),
             _rng: Default::default(),
             validate_user_data: Default::default(),
         }
@@ -481,7 +3770,7 @@ where
 }
 
 impl<const N: usize, U, R> IntoIterator for &Poisson<N, U, R>
-where 
+where
     U: Default + Clone,
     R: Rng + SeedableRng,
 {
@@ -0,0 +1,190 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Distributions outputting a custom point type
+
+use crate::{Iter, Point, Poisson, Rand};
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// A function mapping each point into the output type
+type Map<const N: usize, T> = Arc<dyn Fn(Point<N>) -> T + Send + Sync>;
+
+/// A distribution whose points are mapped into a custom type as they are output
+///
+/// The type is fully named by the dimensions, the output type, and the usual parameters of
+/// [`Poisson`], so a library can hand out distributions of its own point type without exposing
+/// arrays anywhere in its API.
+///
+/// ```
+/// use fast_poisson::{Mapped, Poisson2D};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub struct WorldPos {
+///     pub x: f32,
+///     pub z: f32,
+/// }
+///
+/// pub fn trees(seed: u64) -> Mapped<2, WorldPos> {
+///     Poisson2D::new()
+///         .with_seed(seed)
+///         .with_bounds([0.0, 0.0], [100.0, 100.0])
+///         .with_radius(5.0)
+///         .with_map(|[x, z]| WorldPos {
+///             x: x as f32,
+///             z: z as f32,
+///         })
+/// }
+///
+/// let forest: Vec<WorldPos> = trees(0xBADBEEF).generate();
+/// assert!(forest.iter().all(|tree| (0.0..100.0).contains(&tree.x)));
+/// ```
+///
+/// See [`Poisson::with_map`].
+pub struct Mapped<const N: usize, T, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution being mapped
+    distribution: Poisson<N, U, R>,
+    /// Function mapping each point into the output type
+    map: Map<N, T>,
+}
+
+impl<const N: usize, T, U, R> Mapped<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Map the points of the specified distribution
+    pub(crate) fn new<F>(distribution: Poisson<N, U, R>, map: F) -> Self
+    where
+        F: Fn(Point<N>) -> T + Send + Sync + 'static,
+    {
+        Self {
+            distribution,
+            map: Arc::new(map),
+        }
+    }
+
+    /// Returns the distribution being mapped
+    pub fn distribution(&self) -> &Poisson<N, U, R> {
+        &self.distribution
+    }
+
+    /// Returns an iterator over the mapped points
+    pub fn iter(&self) -> MappedIter<N, T, U, R> {
+        MappedIter {
+            iter: self.distribution.iter(),
+            map: self.map.clone(),
+        }
+    }
+
+    /// Generate the mapped points, as a [`Vec`]
+    ///
+    /// This is identical to [`Poisson::generate`], followed by the map.
+    pub fn generate(&self) -> Vec<T> {
+        self.distribution
+            .generate()
+            .into_iter()
+            .map(|point| (self.map)(point))
+            .collect()
+    }
+}
+
+impl<const N: usize, T, U, R> Clone for Mapped<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    fn clone(&self) -> Self {
+        Self {
+            distribution: self.distribution.clone(),
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<const N: usize, T, U, R> fmt::Debug for Mapped<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    Poisson<N, U, R>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mapped")
+            .field("distribution", &self.distribution)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize, T, U, R> IntoIterator for Mapped<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = T;
+    type IntoIter = MappedIter<N, T, U, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MappedIter {
+            iter: self.distribution.into_iter(),
+            map: self.map,
+        }
+    }
+}
+
+impl<const N: usize, T, U, R> IntoIterator for &Mapped<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = T;
+    type IntoIter = MappedIter<N, T, U, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the points of a [`Mapped`] distribution
+pub struct MappedIter<const N: usize, T, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The iterator over the points being mapped
+    iter: Iter<N, U, R>,
+    /// Function mapping each point into the output type
+    map: Map<N, T>,
+}
+
+impl<const N: usize, T, U, R> Iterator for MappedIter<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|point| (self.map)(point))
+    }
+}
+
+impl<const N: usize, T, U, R> FusedIterator for MappedIter<N, T, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    Iter<N, U, R>: FusedIterator,
+{
+}
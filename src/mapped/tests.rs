@@ -0,0 +1,62 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Mapped, Poisson2D, Poisson3D};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pos {
+    x: Float,
+    y: Float,
+}
+
+fn mapped() -> Mapped<2, Pos> {
+    Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_map(|[x, y]| Pos { x, y })
+}
+
+#[test]
+fn mapped_points_match_the_distribution() {
+    let mapped = mapped();
+    let expected: Vec<Pos> = mapped
+        .distribution()
+        .generate()
+        .into_iter()
+        .map(|[x, y]| Pos { x, y })
+        .collect();
+
+    assert!(!expected.is_empty());
+    assert_eq!(mapped.generate(), expected);
+    assert_eq!(mapped.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(mapped.clone().into_iter().collect::<Vec<_>>(), expected);
+    assert_eq!((&mapped).into_iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn map_follows_the_transform() {
+    let points = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .with_transform(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            [10.0, 0.0, 0.0],
+        )
+        .with_map(|[x, _, _]| x)
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|x| (10.0..11.0).contains(x)));
+}
+
+#[test]
+fn mapped_distributions_can_be_shared_between_threads() {
+    let mapped = mapped();
+    let expected = mapped.generate();
+
+    let handle = std::thread::spawn(move || mapped.generate());
+
+    assert_eq!(handle.join().unwrap(), expected);
+}
@@ -0,0 +1,46 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Remembering generated points, so that seeded distributions needn't be generated twice
+
+use crate::Point;
+use std::fmt;
+use std::sync::Arc;
+
+/// The points generated by a distribution, once they have been generated
+///
+/// The points are shared between clones of the distribution, as it is cloned for every iterator.
+/// They are only ever set through a mutable reference, rather than behind a lock, so that the
+/// distribution can still be used as a key in maps.
+#[derive(Default, Clone)]
+pub(crate) struct Memo<const N: usize>(Option<Arc<Vec<Point<N>>>>);
+
+impl<const N: usize> Memo<N> {
+    /// Returns a copy of the remembered points, if there are any
+    pub(crate) fn get(&self) -> Option<Vec<Point<N>>> {
+        self.0.as_deref().cloned()
+    }
+
+    /// Remember the points generated by the distribution
+    pub(crate) fn set(&mut self, points: &[Point<N>]) {
+        self.0 = Some(Arc::new(points.to_vec()));
+    }
+
+    /// Forget the remembered points, as they no longer match the distribution
+    pub(crate) fn clear(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl<const N: usize> fmt::Debug for Memo<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(points) => write!(f, "Memo({} points)", points.len()),
+            None => f.write_str("Memo(empty)"),
+        }
+    }
+}
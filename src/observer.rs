@@ -0,0 +1,86 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Observing each step of generation, to visualize or debug it
+
+use crate::Point;
+use std::sync::mpsc::Sender;
+
+#[cfg(test)]
+mod tests;
+
+/// A step taken by Bridson's algorithm while generating a distribution
+///
+/// Points lie in the space being sampled, before any [transform](crate::Poisson::with_transform)
+/// is applied to the output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event<const N: usize> {
+    /// A candidate was generated around an active point, or anywhere in the reseeding box when
+    /// `around` is `None`
+    Candidate {
+        /// The candidate
+        point: Point<N>,
+        /// The active point it was generated around
+        around: Option<Point<N>>,
+    },
+    /// The candidate was rejected because it lies outside the bounds or fails validation
+    OutOfDomain(Point<N>),
+    /// The candidate was rejected because it is too close to another point
+    TooClose(Point<N>),
+    /// A point was added to the distribution and became active
+    Accepted(Point<N>),
+    /// An active point was retired, as no more points could be found around it
+    Retired(Point<N>),
+}
+
+/// Receives every [`Event`] while generating a distribution
+///
+/// This is implemented for closures taking an event, and for the sending half of a channel of
+/// events, so that another thread can animate the algorithm as it runs. Events arrive in the order
+/// they happen; every candidate is followed by either its rejection or its acceptance.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// use fast_poisson::Event;
+/// use std::sync::mpsc::channel;
+///
+/// // Why are so few points found?
+/// let (sender, receiver) = channel();
+/// let points: Vec<_> = Poisson2D::new()
+///     .with_validate(|[x, y], _| (0.0..1.0).contains(&x) && (0.0..0.1).contains(&y), ())
+///     .iter()
+///     .with_observer(sender)
+///     .collect();
+///
+/// let outside = receiver
+///     .try_iter()
+///     .filter(|event| matches!(event, Event::OutOfDomain(_)))
+///     .count();
+/// assert!(outside > points.len());
+/// ```
+///
+/// See [`Iter::with_observer`](crate::Iter::with_observer).
+pub trait Observer<const N: usize> {
+    /// Handle the next event
+    fn event(&mut self, event: Event<N>);
+}
+
+impl<const N: usize, F> Observer<N> for F
+where
+    F: FnMut(Event<N>),
+{
+    fn event(&mut self, event: Event<N>) {
+        self(event);
+    }
+}
+
+/// Events are sent for as long as the receiver is listening, and dropped afterwards
+impl<const N: usize> Observer<N> for Sender<Event<N>> {
+    fn event(&mut self, event: Event<N>) {
+        let _ = self.send(event);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Event;
+use crate::Poisson2D;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+
+#[test]
+fn events_match_stats() {
+    let (sender, receiver) = channel();
+    let mut iter = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_reseeding(10, [0.0, 0.0], [1.0, 1.0])
+        .iter()
+        .with_observer(sender);
+    let points: Vec<_> = iter.by_ref().collect();
+    let stats = iter.stats().clone();
+    drop(iter);
+
+    let events: Vec<_> = receiver.iter().collect();
+    let count = |f: fn(&Event<2>) -> bool| events.iter().filter(|e| f(e)).count() as u64;
+
+    assert_eq!(
+        count(|e| matches!(e, Event::Candidate { .. })),
+        stats.candidates
+    );
+    assert_eq!(
+        count(|e| matches!(e, Event::OutOfDomain(_))),
+        stats.out_of_domain
+    );
+    assert_eq!(count(|e| matches!(e, Event::TooClose(_))), stats.too_close);
+    // Every accepted point is reported, and retired along with the first point
+    let accepted: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::Accepted(point) => Some(*point),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(accepted, points);
+    assert_eq!(
+        count(|e| matches!(e, Event::Retired(_))),
+        points.len() as u64 + 1
+    );
+}
+
+#[test]
+fn each_candidate_is_resolved() {
+    let (sender, receiver) = channel();
+    let _: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .iter()
+        .with_observer(sender)
+        .collect();
+
+    let mut candidate = None;
+    for event in receiver.iter() {
+        match event {
+            Event::Candidate { point, around } => {
+                assert!(candidate.is_none());
+                assert!(around.is_some());
+                candidate = Some(point);
+            }
+            Event::OutOfDomain(point) | Event::TooClose(point) | Event::Accepted(point) => {
+                assert_eq!(candidate.take(), Some(point));
+            }
+            Event::Retired(_) => assert!(candidate.is_none()),
+        }
+    }
+}
+
+#[test]
+fn closures_observe() {
+    let points = Poisson2D::new().with_seed(0xBADBEEF).generate();
+    let accepted = Arc::new(AtomicUsize::new(0));
+    let counter = accepted.clone();
+    let observed: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .iter()
+        .with_observer(move |event| {
+            if let Event::Accepted(_) = event {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .collect();
+
+    // Observing doesn't change the outcome
+    assert_eq!(observed, points);
+    assert_eq!(accepted.load(Ordering::SeqCst), points.len());
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Sorting points along space-filling curves
+
+use crate::{Float, Point};
+
+#[cfg(test)]
+mod tests;
+
+/// A space-filling curve along which points can be ordered
+///
+/// Points that are close to each other along either curve are also close to each other in space,
+/// so processing points in this order is more cache-friendly than the natural output order.
+///
+/// See [`Poisson::generate_sorted`][crate::Poisson::generate_sorted].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialOrder {
+    /// The Z-order curve, which is cheap to compute but makes occasional long jumps
+    Morton,
+    /// The Hilbert curve, which only ever steps to an adjacent cell
+    Hilbert,
+}
+
+/// Sort points along the given space-filling curve
+///
+/// The curve is fit to the bounding box of the points.
+pub(crate) fn sort<const N: usize>(points: &mut [Point<N>], order: SpatialOrder) {
+    if points.is_empty() {
+        return;
+    }
+
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in points.iter() {
+        for i in 0..N {
+            min[i] = min[i].min(point[i]);
+            max[i] = max[i].max(point[i]);
+        }
+    }
+
+    // Use as many bits per axis as fit in the key
+    let bits = (128 / N.max(1)).clamp(1, 32) as u32;
+    let cells = ((1u64 << bits) - 1) as Float;
+
+    points.sort_by_cached_key(|point| {
+        let mut cell = [0u32; N];
+        for i in 0..N {
+            let extent = max[i] - min[i];
+            if extent > 0.0 {
+                cell[i] = ((point[i] - min[i]) / extent * cells) as u32;
+            }
+        }
+
+        key(cell, bits, order)
+    });
+}
+
+/// Compute the position of a cell along the curve
+///
+/// Each coordinate of the cell must fit within `bits` bits.
+pub(crate) fn key<const N: usize>(mut cell: [u32; N], bits: u32, order: SpatialOrder) -> u128 {
+    if order == SpatialOrder::Hilbert {
+        hilbert_transpose(&mut cell, bits);
+    }
+
+    // Interleave the bits of each coordinate, most significant first
+    let mut key = 0u128;
+    for bit in (0..bits).rev() {
+        for c in cell.iter() {
+            key = (key << 1) | ((c >> bit) & 1) as u128;
+        }
+    }
+
+    key
+}
+
+/// Transform coordinates in place such that interleaving their bits yields their Hilbert index
+///
+/// This is John Skilling's algorithm, from "Programming the Hilbert curve" (2004).
+fn hilbert_transpose<const N: usize>(x: &mut [u32; N], bits: u32) {
+    if N == 0 {
+        return;
+    }
+
+    let m = 1u32 << (bits - 1);
+
+    // Inverse undo
+    let mut q = m;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..N {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode
+    for i in 1..N {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = m;
+    while q > 1 {
+        if x[N - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for c in x.iter_mut() {
+        *c ^= t;
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+/// Every cell of a small grid, sorted along the curve
+fn curve<const N: usize>(bits: u32, order: SpatialOrder) -> Vec<[u32; N]> {
+    let side = 1u32 << bits;
+    let mut cells: Vec<[u32; N]> = (0..side.pow(N as u32))
+        .map(|mut i| {
+            let mut cell = [0; N];
+            for c in cell.iter_mut() {
+                *c = i % side;
+                i /= side;
+            }
+            cell
+        })
+        .collect();
+    cells.sort_by_key(|&cell| key(cell, bits, order));
+
+    cells
+}
+
+#[test]
+fn hilbert_steps_to_adjacent_cells() {
+    fn check<const N: usize>(bits: u32) {
+        let cells = curve::<N>(bits, SpatialOrder::Hilbert);
+        for pair in cells.windows(2) {
+            let steps: u32 = (0..N).map(|i| pair[0][i].abs_diff(pair[1][i])).sum();
+            assert_eq!(steps, 1, "{:?} to {:?}", pair[0], pair[1]);
+        }
+    }
+
+    check::<2>(3);
+    check::<3>(2);
+    check::<4>(2);
+}
+
+#[test]
+fn morton_interleaves_bits() {
+    assert_eq!(key([0b01, 0b10], 2, SpatialOrder::Morton), 0b0110);
+    assert_eq!(
+        curve::<2>(1, SpatialOrder::Morton),
+        vec![[0, 0], [0, 1], [1, 0], [1, 1]]
+    );
+}
+
+#[test]
+fn sorted_points_are_local() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let natural = poisson.generate();
+
+    let length = |points: &[Point<2>]| -> Float {
+        points
+            .windows(2)
+            .map(|p| ((p[0][0] - p[1][0]).powi(2) + (p[0][1] - p[1][1]).powi(2)).sqrt())
+            .sum()
+    };
+
+    for order in [SpatialOrder::Morton, SpatialOrder::Hilbert] {
+        let sorted = poisson.generate_sorted(order);
+        assert_eq!(sorted.len(), natural.len());
+        assert!(natural.iter().all(|point| sorted.contains(point)));
+
+        // Every point is close to the next, much like a space-filling curve
+        assert!(length(&sorted) < 0.2 * sorted.len() as Float);
+    }
+
+    assert!(
+        length(&poisson.generate_sorted(SpatialOrder::Hilbert))
+            < length(&poisson.generate_sorted(SpatialOrder::Morton))
+    );
+}
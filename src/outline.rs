@@ -0,0 +1,105 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Poisson disk sampling along the boundary of the space
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of grid steps per radius at which the boundary is searched for
+const STEPS_PER_RADIUS: Float = 4.0;
+
+/// Number of bisections refining each point found on the boundary
+const BISECTIONS: u32 = 20;
+
+/// Place points along the boundary of the space, at least `radius` apart
+///
+/// The reseeding box is covered by a grid, a little larger than the box so that its own faces are
+/// found too. Wherever an edge of the grid crosses the boundary, the crossing is refined by
+/// bisection, giving a dense set of points on the boundary; these are then visited in random
+/// order, keeping each that is far enough from every point kept before it.
+pub(crate) fn outline<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    radius: Float,
+) -> Vec<Point<N>>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let mut rng = distribution.rng();
+
+    let step = radius / STEPS_PER_RADIUS;
+    let [min, max] = distribution.reseed_bounds;
+    let nodes: [usize; N] = std::array::from_fn(|i| ((max[i] - min[i]) / step).ceil() as usize + 3);
+    let node = |index: [usize; N]| -> Point<N> {
+        std::array::from_fn(|i| min[i] + (index[i] as Float - 1.0) * step)
+    };
+
+    let mut crossings = Vec::new();
+    let mut index = [0; N];
+    loop {
+        let here = node(index);
+        let inside = distribution.in_space(here);
+        for axis in (0..N).filter(|&axis| index[axis] + 1 < nodes[axis]) {
+            let mut next = here;
+            next[axis] += step;
+            if distribution.in_space(next) != inside {
+                crossings.push(bisect(distribution, here, next, inside));
+            }
+        }
+
+        // Step to the next node, like an odometer
+        let Some(axis) = (0..N).find(|&i| index[i] + 1 < nodes[i]) else {
+            break;
+        };
+        index[axis] += 1;
+        index[..axis].fill(0);
+    }
+
+    crossings.shuffle(&mut rng);
+
+    let mut kept: DefaultIndex<N> = SpatialIndex::with_spacing(radius);
+    let mut points = Vec::new();
+    for point in crossings {
+        if !kept.any_within(point, radius.powi(2)) {
+            kept.insert(point, points.len() as u64);
+            points.push(point);
+        }
+    }
+
+    points
+}
+
+/// Find where the boundary crosses the segment from `a` to `b`, returning the nearest point found
+/// that lies within the space
+fn bisect<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    a: Point<N>,
+    b: Point<N>,
+    a_inside: bool,
+) -> Point<N>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let (mut inside, mut outside) = if a_inside { (a, b) } else { (b, a) };
+
+    for _ in 0..BISECTIONS {
+        let mid = std::array::from_fn(|i| (inside[i] + outside[i]) / 2.0);
+        if distribution.in_space(mid) {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+
+    inside
+}
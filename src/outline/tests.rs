@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Poisson2D, Poisson3D};
+
+fn disk() -> Poisson2D {
+    Poisson2D::new()
+        .with_validate(|[x, y], _| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.16, ())
+        .with_seed(0xBADBEEF)
+}
+
+#[test]
+fn points_lie_on_circle() {
+    let outline = disk().generate_outline(0.05);
+
+    for [x, y] in outline.iter() {
+        let distance = ((x - 0.5).powi(2) + (y - 0.5).powi(2)).sqrt();
+        assert!((distance - 0.4).abs() < 1e-4, "{distance}");
+    }
+    assert!(crate::stats::verify_min_distance(&outline, 0.05));
+
+    // The circumference is about 2.5, and points are between one and two radii apart
+    assert!((25..=50).contains(&outline.len()), "{}", outline.len());
+}
+
+#[test]
+fn square_includes_edges_of_box() {
+    let outline = Poisson2D::new().with_seed(0xBADBEEF).generate_outline(0.1);
+
+    let near_edge = |x: Float| !(1e-4..=1.0 - 1e-4).contains(&x);
+    assert!(outline
+        .iter()
+        .all(|&[x, y]| (near_edge(x) || near_edge(y)) && (0.0..1.0).contains(&x)));
+    assert!(outline.iter().any(|&[x, _]| x < 1e-4));
+    assert!(outline.iter().any(|&[_, y]| y > 1.0 - 1e-4));
+    assert!(crate::stats::verify_min_distance(&outline, 0.1));
+}
+
+#[test]
+fn interior_merges_around_outline() {
+    let disk = disk().with_radius(0.05);
+    let outline = disk.generate_outline(0.03);
+    let points = disk.merge(&outline, 0.05);
+
+    assert_eq!(outline[..], points[..outline.len()]);
+    assert!(points.len() > outline.len() + 100);
+    for new in points[outline.len()..].iter() {
+        for old in outline.iter() {
+            let dist = ((new[0] - old[0]).powi(2) + (new[1] - old[1]).powi(2)).sqrt();
+            assert!(dist >= 0.05);
+        }
+    }
+}
+
+#[test]
+fn surface_of_sphere() {
+    let outline = Poisson3D::new()
+        .with_validate(
+            |p, _| p.iter().map(|x| (x - 0.5).powi(2)).sum::<Float>() < 0.09,
+            (),
+        )
+        .with_seed(0xBADBEEF)
+        .generate_outline(0.1);
+
+    for p in outline.iter() {
+        let distance = p.iter().map(|x| (x - 0.5).powi(2)).sum::<Float>().sqrt();
+        assert!((distance - 0.3).abs() < 1e-4);
+    }
+    assert!(outline.len() > 20);
+}
@@ -0,0 +1,143 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Random packing of disks or spheres of varying sizes
+
+use crate::iter::random_point_around;
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson};
+use rand::distributions::Distribution;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// Attempts to find a first center within the space when none is given
+const FIRST_ATTEMPTS: u32 = 64;
+
+/// Pack disks with radii drawn from `sizes` into the space, so that no two overlap
+///
+/// This is Bridson's algorithm with the radius of each sample drawn anew: around each active disk,
+/// candidates are generated at a distance of the two radii added together, scaled by the annulus,
+/// and kept if they overlap no other disk. Returns the center and radius of every disk.
+pub(crate) fn pack<const N: usize, U, R, D>(
+    distribution: &Poisson<N, U, R>,
+    sizes: D,
+) -> Vec<(Point<N>, Float)>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+    D: Distribution<Float>,
+{
+    let mut rng = distribution.rng();
+    // Radii that aren't positive and finite can't be packed, so such draws are simply rejected
+    let draw = |rng: &mut R| Some(sizes.sample(rng)).filter(|r| r.is_finite() && *r > 0.0);
+
+    let mut packing = Packing {
+        disks: Vec::new(),
+        index: None,
+        largest: 0.0,
+    };
+    let mut active = Vec::new();
+
+    let mut first = distribution.first_points.clone();
+    if first.is_empty() {
+        first.extend(first_center(distribution, &mut rng));
+    }
+    for center in first {
+        let Some(radius) = draw(&mut rng) else {
+            continue;
+        };
+        if packing.fits(center, radius) {
+            active.push(packing.disks.len());
+            packing.add(center, radius);
+        }
+    }
+
+    while !active.is_empty() {
+        let i = rng.gen_range(0..active.len());
+        let (parent, parent_radius) = packing.disks[active[i]];
+
+        let mut found = false;
+        for _ in 0..distribution.num_samples {
+            let Some(radius) = draw(&mut rng) else {
+                continue;
+            };
+            let center =
+                random_point_around(distribution, parent_radius + radius, &mut rng, parent);
+            if distribution.in_space(center) && packing.fits(center, radius) {
+                active.push(packing.disks.len());
+                packing.add(center, radius);
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            active.swap_remove(i);
+        }
+    }
+
+    packing.disks
+}
+
+/// A random center within the space to start packing from
+fn first_center<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    rng: &mut R,
+) -> Option<Point<N>>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    if let Some(sample) = distribution.sample_first {
+        return Some(sample(&distribution.validate_user_data, rng));
+    }
+
+    let [min, max] = distribution.reseed_bounds;
+    (0..FIRST_ATTEMPTS)
+        .map(|_| std::array::from_fn(|i| min[i] + (max[i] - min[i]) * rng.gen::<Float>()))
+        .find(|&center| distribution.in_space(center))
+}
+
+/// The disks packed so far
+struct Packing<const N: usize> {
+    /// The center and radius of every disk
+    disks: Vec<(Point<N>, Float)>,
+    /// Index of the disks' centers, sized by the first disk once there is one
+    index: Option<DefaultIndex<N>>,
+    /// The largest radius of any disk
+    largest: Float,
+}
+
+impl<const N: usize> Packing<N> {
+    /// Returns true if a disk of `radius` at `center` overlaps none of the others
+    fn fits(&self, center: Point<N>, radius: Float) -> bool {
+        let Some(index) = &self.index else {
+            return true;
+        };
+
+        // Any overlapping disk's center lies within our radius and the largest radius
+        index
+            .within_squared(center, (radius + self.largest).powi(2))
+            .into_iter()
+            .all(|(j, squared_distance)| {
+                let other = self.disks[j as usize].1;
+                squared_distance >= (radius + other).powi(2)
+            })
+    }
+
+    /// Add a disk
+    fn add(&mut self, center: Point<N>, radius: Float) {
+        let index = self
+            .index
+            .get_or_insert_with(|| SpatialIndex::with_spacing(2.0 * radius));
+        index.insert(center, self.disks.len() as u64);
+        self.disks.push((center, radius));
+        self.largest = self.largest.max(radius);
+    }
+}
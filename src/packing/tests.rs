@@ -0,0 +1,73 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Point, Poisson2D, Poisson3D};
+use rand::distributions::Uniform;
+
+/// Check that no two disks overlap
+fn assert_disjoint<const N: usize>(disks: &[(Point<N>, Float)]) {
+    for (i, (a, ra)) in disks.iter().enumerate() {
+        for (b, rb) in disks[..i].iter() {
+            let squared: Float = a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum();
+            assert!(squared >= (ra + rb).powi(2));
+        }
+    }
+}
+
+#[test]
+fn disks_do_not_overlap() {
+    let disks = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .generate_packing(Uniform::new(0.01, 0.05));
+
+    assert_disjoint(&disks);
+    assert!(disks
+        .iter()
+        .all(|&([x, y], r)| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y) && r < 0.05));
+
+    // Sizes vary, rather than all being the same
+    let smallest = disks.iter().map(|d| d.1).fold(Float::INFINITY, Float::min);
+    let largest = disks.iter().map(|d| d.1).fold(0.0, Float::max);
+    assert!(smallest < 0.015 && largest > 0.045);
+}
+
+#[test]
+fn tighter_annulus_packs_more_densely() {
+    let covered = |inner, outer| {
+        Poisson2D::new()
+            .with_seed(0xBADBEEF)
+            .with_annulus(inner, outer)
+            .generate_packing(Uniform::new(0.01, 0.03))
+            .iter()
+            .map(|(_, r)| std::f64::consts::PI as Float * r * r)
+            .sum::<Float>()
+    };
+
+    let loose = covered(1.0, 2.0);
+    let tight = covered(1.0, 1.1);
+    assert!(tight > loose * 1.1, "{tight} vs {loose}");
+    assert!(tight > 0.6);
+}
+
+#[test]
+fn spheres() {
+    let disks = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .generate_packing(Uniform::new(0.05, 0.1));
+
+    assert!(disks.len() > 50);
+    assert_disjoint(&disks);
+}
+
+#[test]
+fn invalid_sizes_are_rejected() {
+    let disks = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .generate_packing(Uniform::new(-1.0, 0.0));
+
+    assert!(disks.is_empty());
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Parallel generation with rayon
+
+use crate::tiling::{self, Tiling};
+use crate::{Point, Poisson};
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Generate the distribution in parallel, with the same result regardless of the number of threads
+///
+/// The reseeding box is divided into tiles, which are generated in `2^N` phases. Within each
+/// phase tiles are generated in parallel, each around the points of its neighbors from earlier
+/// phases, and each from its own seed derived from the distribution's seed.
+pub(crate) fn generate_deterministic<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+) -> Vec<Point<N>>
+where
+    U: Default + Clone + Sync,
+    R: Rng + SeedableRng,
+{
+    let tiling = Tiling::new(distribution);
+    let tiles = tiling.tiles();
+
+    let mut generated: HashMap<[i64; N], Vec<Point<N>>> = HashMap::new();
+    let mut output = Vec::new();
+    for current in 0..(1 << N) {
+        let phase_tiles: Vec<_> = tiles
+            .iter()
+            .copied()
+            .filter(|&tile| tiling::phase(tile) == current)
+            .collect();
+
+        let points: Vec<_> = phase_tiles
+            .par_iter()
+            .map(|&coord| {
+                // Earlier phases have already been generated, so we generate around them
+                let existing: Vec<_> = tiling::neighbors(coord)
+                    .filter_map(|neighbor| generated.get(&neighbor))
+                    .flatten()
+                    .copied()
+                    .collect();
+
+                tiling.generate::<R>(coord, &existing)
+            })
+            .collect();
+
+        for (coord, points) in phase_tiles.into_iter().zip(points) {
+            output.extend(points.iter().map(|&point| distribution.transformed(point)));
+            generated.insert(coord, points);
+        }
+    }
+
+    output
+}
+
+/// Generate one distribution for each seed, in parallel
+pub(crate) fn generate_batch<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    seeds: &[u64],
+) -> Vec<Vec<Point<N>>>
+where
+    U: Default + Clone + Sync,
+    R: Rng + SeedableRng + Sync,
+{
+    seeds
+        .par_iter()
+        .map_init(
+            || distribution.clone(),
+            |distribution, &seed| {
+                distribution.set_seed(seed);
+                distribution.generate()
+            },
+        )
+        .collect()
+}
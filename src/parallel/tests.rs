@@ -0,0 +1,65 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{stats, Float, Poisson2D};
+
+#[test]
+fn independent_of_thread_count() {
+    let poisson = Poisson2D::new().with_radius(0.02).with_seed(0xBADBEEF);
+    let on_threads = |threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(|| poisson.par_generate_deterministic())
+    };
+
+    let single = on_threads(1);
+    assert_eq!(single, on_threads(4));
+    assert_eq!(single, on_threads(7));
+}
+
+#[test]
+fn maintains_radius_across_tiles() {
+    let poisson = Poisson2D::new().with_radius(0.02).with_seed(0xBADBEEF);
+    let points = poisson.par_generate_deterministic();
+
+    assert!(stats::verify_min_distance(&points, 0.02));
+    assert!(points
+        .iter()
+        .all(|p| (0.0..1.0).contains(&p[0]) && (0.0..1.0).contains(&p[1])));
+
+    // About as dense as serial generation
+    let serial = poisson.generate().len() as Float;
+    assert!((points.len() as Float / serial - 1.0).abs() < 0.1);
+}
+
+#[test]
+fn respects_validation() {
+    let points = Poisson2D::new()
+        .with_radius(0.02)
+        .with_seed(0xBADBEEF)
+        .with_validate(|[x, y], _| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.16, ())
+        .par_generate_deterministic();
+
+    assert!(!points.is_empty());
+    assert!(points
+        .iter()
+        .all(|[x, y]| (x - 0.5).powi(2) + (y - 0.5).powi(2) < 0.16));
+}
+
+#[test]
+fn batch_matches_serial() {
+    let poisson = Poisson2D::new().with_radius(0.1);
+    let seeds: Vec<u64> = (0..20).collect();
+    let batch = poisson.generate_batch(&seeds);
+
+    assert_eq!(batch.len(), seeds.len());
+    for (points, &seed) in batch.iter().zip(seeds.iter()) {
+        assert_eq!(*points, poisson.clone().with_seed(seed).generate());
+    }
+}
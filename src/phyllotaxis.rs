@@ -0,0 +1,178 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Golden-angle spirals, as in the arrangement of seeds in a sunflower head
+
+use crate::{Float, Point, Poisson, Rand, Sampler};
+use rand::{Rng, SeedableRng};
+use std::iter::FusedIterator;
+
+#[cfg(test)]
+mod tests;
+
+/// A little under the closest any two florets come to each other, relative to the spiral's scale
+///
+/// The closest pair are the first and fourth florets, about 1.546 apart; every other pair is
+/// further apart, tending towards about 1.9 further out from the center.
+const MIN_SPACING: Float = 1.54;
+
+/// A sampler placing points along a golden-angle spiral
+///
+/// Following Vogel's model of a sunflower head, each point is rotated from the last by the golden
+/// angle, about 137.5°, and pushed outward so that every point covers the same area. The result is
+/// evenly spread and deterministic, with the distinctive interlocking spirals of phyllotaxis
+/// rather than the noisy look of a Poisson disk distribution. Every point is at least the radius
+/// from every other.
+///
+/// The spiral is centered on the [reseeding](Poisson::with_reseeding) box, by default the unit
+/// square, and rotated by a random angle; points outside the box are skipped, as are points
+/// failing the validation function. A [density](Poisson::with_density) is not supported.
+///
+/// ```
+/// # use fast_poisson::{Poisson2D, Sampler};
+/// let points = Poisson2D::new()
+///     .with_seed(0xBADBEEF)
+///     .phyllotaxis()
+///     .generate();
+///
+/// assert!(points.iter().all(|&[x, y]| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y)));
+/// ```
+///
+/// See [`Poisson::phyllotaxis`].
+#[derive(Debug)]
+pub struct Phyllotaxis<U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution to approximate
+    distribution: Poisson<2, U, R>,
+}
+
+impl<U, R> Phyllotaxis<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Approximate the specified distribution
+    pub(crate) fn new(distribution: Poisson<2, U, R>) -> Self {
+        Self { distribution }
+    }
+}
+
+impl<U, R> Clone for Phyllotaxis<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    fn clone(&self) -> Self {
+        Self {
+            distribution: self.distribution.clone(),
+        }
+    }
+}
+
+impl<U, R> Sampler<2> for Phyllotaxis<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Iter = Florets<U, R>;
+
+    fn sample(&self) -> Self::Iter {
+        Florets::new(self.distribution.clone())
+    }
+}
+
+/// An iterator over the points of a [`Phyllotaxis`] sampler
+pub struct Florets<U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution being approximated
+    distribution: Poisson<2, U, R>,
+    /// The center of the spiral
+    center: Point<2>,
+    /// The distance of each floret from the center, relative to the square root of its index
+    scale: Float,
+    /// The angle the whole spiral is rotated by
+    rotation: Float,
+    /// The index of the next floret
+    next: usize,
+    /// The number of florets needed to cover the reseeding box
+    count: usize,
+}
+
+impl<U, R> Florets<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Start placing florets within the specified distribution
+    fn new(distribution: Poisson<2, U, R>) -> Self {
+        let mut rng = distribution.rng();
+        let rotation = rng.gen::<Float>() * std::f64::consts::TAU as Float;
+
+        let [min, max] = distribution.reseed_bounds;
+        let center = [0.5 * (min[0] + max[0]), 0.5 * (min[1] + max[1])];
+        let scale = distribution.radius / MIN_SPACING;
+        // Enough florets to reach the corners of the box
+        let reach = 0.5 * ((max[0] - min[0]).powi(2) + (max[1] - min[1]).powi(2)).sqrt();
+        let count = ((reach / scale).powi(2) + 0.5).ceil() as usize;
+
+        Self {
+            distribution,
+            center,
+            scale,
+            rotation,
+            next: 0,
+            count,
+        }
+    }
+}
+
+impl<U, R> Iterator for Florets<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = Point<2>;
+
+    fn next(&mut self) -> Option<Point<2>> {
+        let golden_angle = std::f64::consts::PI * (3.0 - 5f64.sqrt());
+        let [min, max] = self.distribution.reseed_bounds;
+
+        while self.next < self.count {
+            let k = self.next;
+            self.next += 1;
+
+            // Offsetting the index by a half keeps the innermost florets from crowding the center
+            let distance = self.scale * (k as Float + 0.5).sqrt();
+            // Wrapped in double precision, as single precision can't tell far out florets apart
+            let angle = self.rotation + (k as f64 * golden_angle % std::f64::consts::TAU) as Float;
+            let point = [
+                self.center[0] + distance * angle.cos(),
+                self.center[1] + distance * angle.sin(),
+            ];
+
+            let in_box = (0..2).all(|i| (min[i]..max[i]).contains(&point[i]));
+            if in_box && self.distribution.in_space(point) {
+                return Some(self.distribution.transformed(point));
+            }
+        }
+
+        None
+    }
+}
+
+impl<U, R> FusedIterator for Florets<U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::stats::verify_min_distance;
+use crate::{Poisson2D, Sampler};
+
+#[test]
+fn min_distance_is_kept() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.02)
+        .phyllotaxis()
+        .generate();
+
+    assert!(points.len() > 1000);
+    assert!(verify_min_distance(&points, 0.02));
+}
+
+#[test]
+fn spiral_fills_reseeding_box() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.05)
+        .with_validate(|_, _| true, ())
+        .with_reseeding(1, [-1.0, 2.0], [1.0, 3.0])
+        .phyllotaxis()
+        .generate();
+
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (-1.0..1.0).contains(&x) && (2.0..3.0).contains(&y)));
+    // Each corner of the box is reached
+    for corner in [[-1.0, 2.0], [-1.0, 3.0], [1.0, 2.0], [1.0, 3.0]] {
+        assert!(points
+            .iter()
+            .any(|&[x, y]| { (x - corner[0]).abs() < 0.1 && (y - corner[1]).abs() < 0.1 }));
+    }
+}
+
+#[test]
+fn spiral_is_deterministic() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+
+    assert_eq!(
+        poisson.phyllotaxis().generate(),
+        poisson.phyllotaxis().generate()
+    );
+    assert_ne!(
+        poisson.phyllotaxis().generate(),
+        poisson.clone().with_seed(0xC0FFEE).phyllotaxis().generate()
+    );
+}
+
+#[test]
+fn validation_is_respected() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(|[x, _], _| x < 0.5, ())
+        .phyllotaxis()
+        .generate();
+
+    assert!(!points.is_empty());
+    assert!(points.iter().all(|&[x, _]| x < 0.5));
+}
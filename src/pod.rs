@@ -0,0 +1,86 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Plain-old-data points, for uploading distributions to the GPU
+//!
+//! [`PodPoint`] implements [`bytemuck`][bm]'s [`Pod`] and [`Zeroable`] traits, so it can be
+//! used within vertex and instance structs of your own, and the functions here view or convert
+//! generated points without copying them. The bytes are laid out exactly as a buffer of vectors
+//! of `N` floats, such as `vec2<f64>` or `vec3<f32>` with the `single_precision` feature.
+//!
+//! ```
+//! # use fast_poisson::Poisson2D;
+//! use fast_poisson::pod;
+//!
+//! let points = Poisson2D::new().generate();
+//! let bytes: &[u8] = pod::as_bytes(&points);
+//!
+//! assert_eq!(bytes.len(), std::mem::size_of_val(&points[..]));
+//! ```
+//!
+//! Requires the `bytemuck` feature.
+//!
+//! [bm]: https://crates.io/crates/bytemuck
+
+use crate::Point;
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(test)]
+mod tests;
+
+/// A point that is plain old data, and so can be safely viewed as bytes
+///
+/// This has the same layout as [`Point`], an array of `N` floats.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PodPoint<const N: usize>(pub Point<N>);
+
+// SAFETY: An array of floats has no padding and every bit pattern is valid, including all zeroes;
+// `PodPoint` is `repr(C)` with the array as its only field, so the same is true of it.
+unsafe impl<const N: usize> Zeroable for PodPoint<N> {}
+unsafe impl<const N: usize> Pod for PodPoint<N> {}
+
+impl<const N: usize> From<Point<N>> for PodPoint<N> {
+    fn from(point: Point<N>) -> Self {
+        Self(point)
+    }
+}
+
+impl<const N: usize> From<PodPoint<N>> for Point<N> {
+    fn from(point: PodPoint<N>) -> Self {
+        point.0
+    }
+}
+
+/// View points as plain-old-data points, without copying them
+#[must_use]
+pub fn cast_slice<const N: usize>(points: &[Point<N>]) -> &[PodPoint<N>] {
+    bytemuck::cast_slice(points)
+}
+
+/// View points as bytes, such as to upload them to a GPU buffer, without copying them
+#[must_use]
+pub fn as_bytes<const N: usize>(points: &[Point<N>]) -> &[u8] {
+    bytemuck::cast_slice(points)
+}
+
+/// Convert generated points into plain-old-data points, reusing their allocation
+///
+/// ```
+/// # use fast_poisson::Poisson3D;
+/// use fast_poisson::pod::{self, PodPoint};
+///
+/// let points = Poisson3D::new().generate();
+/// let count = points.len();
+/// let pod: Vec<PodPoint<3>> = pod::cast_vec(points);
+///
+/// assert_eq!(pod.len(), count);
+/// ```
+#[must_use]
+pub fn cast_vec<const N: usize>(points: Vec<Point<N>>) -> Vec<PodPoint<N>> {
+    bytemuck::allocation::cast_vec(points)
+}
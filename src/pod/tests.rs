@@ -0,0 +1,33 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Float, Poisson2D};
+use std::mem::size_of;
+
+#[test]
+fn same_layout_as_points() {
+    assert_eq!(size_of::<PodPoint<3>>(), size_of::<Point<3>>());
+    assert_eq!(size_of::<PodPoint<3>>(), 3 * size_of::<Float>());
+}
+
+#[test]
+fn casts_preserve_points() {
+    let points = Poisson2D::new().with_seed(0xBADBEEF).generate();
+
+    let pod = cast_slice(&points);
+    assert!(pod.iter().zip(points.iter()).all(|(a, b)| a.0 == *b));
+
+    let bytes = as_bytes(&points);
+    assert_eq!(bytes.len(), points.len() * 2 * size_of::<Float>());
+    let first = Float::from_ne_bytes(bytes[..size_of::<Float>()].try_into().unwrap());
+    assert_eq!(first, points[0][0]);
+
+    let converted = cast_vec(points.clone());
+    let back: Vec<Point<2>> = converted.into_iter().map(Point::from).collect();
+    assert_eq!(back, points);
+}
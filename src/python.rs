@@ -0,0 +1,103 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Python bindings
+//!
+//! Build the Python module with [maturin](https://www.maturin.rs/), e.g. `maturin develop`, then:
+//!
+//! ```python
+//! from fast_poisson import Poisson2D
+//!
+//! points = Poisson2D(radius=0.05, seed=42).generate()
+//! print(points.shape)  # (n, 2)
+//! ```
+//!
+//! Distributions are returned as numpy arrays with one row per point.
+
+use crate::{Float, Poisson};
+use numpy::{PyArray1, PyArray2, PyArrayMethods};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Create a distribution from the arguments given in Python
+fn distribution<const N: usize>(
+    radius: Float,
+    seed: Option<u64>,
+    samples: u32,
+) -> PyResult<Poisson<N>> {
+    if radius.is_nan() || radius <= 0.0 {
+        return Err(PyValueError::new_err("radius must be positive"));
+    }
+
+    let mut poisson = Poisson::<N>::new()
+        .with_radius(radius)
+        .with_samples(samples);
+    if let Some(seed) = seed {
+        poisson.set_seed(seed);
+    }
+
+    Ok(poisson)
+}
+
+/// Generate a distribution as an array with one row per point
+fn generate<'py, const N: usize>(
+    py: Python<'py>,
+    poisson: &Poisson<N>,
+) -> PyResult<Bound<'py, PyArray2<Float>>> {
+    let coordinates: Vec<Float> = poisson.iter().flatten().collect();
+    let rows = coordinates.len() / N;
+
+    PyArray1::from_vec(py, coordinates).reshape([rows, N])
+}
+
+/// Poisson disk distribution in the 2-dimensional unit square
+#[pyclass(name = "Poisson2D", module = "fast_poisson")]
+pub struct PyPoisson2D(Poisson<2>);
+
+#[pymethods]
+impl PyPoisson2D {
+    #[new]
+    #[pyo3(signature = (radius = 0.1, seed = None, samples = 30))]
+    fn new(radius: Float, seed: Option<u64>, samples: u32) -> PyResult<Self> {
+        distribution(radius, seed, samples).map(Self)
+    }
+
+    /// Generate the points as an array of shape `(n, 2)`
+    fn generate<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<Float>>> {
+        generate(py, &self.0)
+    }
+}
+
+/// Poisson disk distribution in the 3-dimensional unit cube
+#[pyclass(name = "Poisson3D", module = "fast_poisson")]
+pub struct PyPoisson3D(Poisson<3>);
+
+#[pymethods]
+impl PyPoisson3D {
+    #[new]
+    #[pyo3(signature = (radius = 0.1, seed = None, samples = 30))]
+    fn new(radius: Float, seed: Option<u64>, samples: u32) -> PyResult<Self> {
+        distribution(radius, seed, samples).map(Self)
+    }
+
+    /// Generate the points as an array of shape `(n, 3)`
+    fn generate<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<Float>>> {
+        generate(py, &self.0)
+    }
+}
+
+/// The `fast_poisson` Python module
+#[pymodule]
+fn fast_poisson(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyPoisson2D>()?;
+    module.add_class::<PyPoisson3D>()?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+use pyo3::types::IntoPyDict;
+
+#[test]
+fn arguments() {
+    Python::initialize();
+    Python::attach(|py| {
+        let poisson = PyPoisson3D::new(0.2, Some(42), 10).unwrap();
+        assert_eq!(
+            poisson.0,
+            Poisson3D::new()
+                .with_radius(0.2)
+                .with_seed(42)
+                .with_samples(10)
+        );
+
+        let error = PyPoisson2D::new(0.0, None, 30).err().unwrap();
+        assert!(error.is_instance_of::<PyValueError>(py));
+        assert!(PyPoisson2D::new(Float::NAN, None, 30).is_err());
+    });
+}
+
+#[test]
+fn module_exports_classes() {
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "fast_poisson").unwrap();
+        fast_poisson(&module).unwrap();
+
+        let poisson = module
+            .getattr("Poisson2D")
+            .unwrap()
+            .call((), Some(&[("seed", 7)].into_py_dict(py).unwrap()))
+            .unwrap();
+        let poisson = poisson.cast::<PyPoisson2D>().unwrap().borrow();
+        assert_eq!(poisson.0, Poisson2D::new().with_seed(7));
+    });
+}
@@ -0,0 +1,111 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Low-discrepancy sequences for placing candidates
+
+use crate::Float;
+use rand::Rng;
+
+#[cfg(test)]
+mod tests;
+
+/// Where the candidates generated around each active point come from
+///
+/// See [`Poisson::with_candidate_sequence`](crate::Poisson::with_candidate_sequence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CandidateSequence {
+    /// Independent random directions and distances, as in Bridson's algorithm
+    ///
+    /// Candidates may by chance bunch up on one side of an active point, leaving a gap on the
+    /// other side unexplored.
+    #[default]
+    Random,
+    /// Successive points of a Halton sequence, randomly rotated for each active point
+    ///
+    /// The candidates around each active point are spread evenly over the directions and
+    /// distances available, so gaps are less likely to be missed, particularly with few samples.
+    Halton,
+}
+
+/// A Halton sequence with a random rotation, restarted for each active point
+///
+/// The `i`th value along each dimension is the radical inverse of `i` in that dimension's base,
+/// the first primes. Adding a random offset, modulo 1, keeps consecutive runs of the sequence just
+/// as evenly spread while making the runs around different points independent.
+#[derive(Debug, Clone)]
+pub(crate) struct Halton {
+    /// The base of each dimension
+    bases: Vec<u32>,
+    /// The random offset of each dimension
+    offsets: Vec<Float>,
+    /// The index of the next point in the sequence
+    index: u32,
+    /// The current point
+    point: Vec<Float>,
+}
+
+impl Halton {
+    /// Create a sequence of points with the given number of dimensions
+    pub(crate) fn new(dimensions: usize) -> Self {
+        Self {
+            bases: primes(dimensions),
+            offsets: vec![0.0; dimensions],
+            index: 0,
+            point: vec![0.0; dimensions],
+        }
+    }
+
+    /// Start the sequence over, with a new random rotation
+    pub(crate) fn restart<R: Rng>(&mut self, rng: &mut R) {
+        for offset in self.offsets.iter_mut() {
+            *offset = rng.gen();
+        }
+        self.index = 0;
+    }
+
+    /// The next point in the sequence, within the unit hypercube
+    pub(crate) fn next_point(&mut self) -> &[Float] {
+        for ((value, &base), offset) in self.point.iter_mut().zip(&self.bases).zip(&self.offsets) {
+            let rotated = radical_inverse(self.index, base) + offset;
+            *value = if rotated >= 1.0 {
+                rotated - 1.0
+            } else {
+                rotated
+            };
+        }
+        self.index += 1;
+
+        &self.point
+    }
+}
+
+/// Mirror the digits of `index` in `base` about the radix point
+fn radical_inverse(mut index: u32, base: u32) -> Float {
+    let mut inverse = 0.0;
+    let mut scale = 1.0 / base as Float;
+    while index > 0 {
+        inverse += (index % base) as Float * scale;
+        index /= base;
+        scale /= base as Float;
+    }
+
+    inverse
+}
+
+/// The first `count` primes
+fn primes(count: usize) -> Vec<u32> {
+    let mut primes: Vec<u32> = Vec::with_capacity(count);
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes.iter().all(|&p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+
+    primes
+}
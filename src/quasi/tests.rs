@@ -0,0 +1,81 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::stats::verify_min_distance;
+use crate::{Poisson, Poisson2D, Poisson3D};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
+
+#[test]
+fn radical_inverses() {
+    assert_eq!(radical_inverse(0, 2), 0.0);
+    assert_eq!(radical_inverse(1, 2), 0.5);
+    assert_eq!(radical_inverse(3, 2), 0.75);
+    assert_eq!(radical_inverse(6, 2), 0.375);
+    assert!((radical_inverse(5, 3) - 7.0 / 9.0).abs() < 1e-6);
+}
+
+#[test]
+fn first_primes() {
+    assert_eq!(primes(6), [2, 3, 5, 7, 11, 13]);
+}
+
+#[test]
+fn rotated_sequence_stays_in_unit_cube() {
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0xBADBEEF);
+    let mut halton = Halton::new(3);
+    halton.restart(&mut rng);
+    let first = halton.next_point().to_vec();
+
+    for _ in 0..100 {
+        assert!(halton.next_point().iter().all(|x| (0.0..1.0).contains(x)));
+    }
+
+    // Each restart rotates the sequence anew
+    halton.restart(&mut rng);
+    assert_ne!(halton.next_point(), first);
+}
+
+#[test]
+fn halton_candidates_keep_radius() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_candidate_sequence(CandidateSequence::Halton)
+        .generate();
+    assert!(verify_min_distance(&points, 0.1));
+
+    let points = Poisson3D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.2)
+        .with_candidate_sequence(CandidateSequence::Halton)
+        .generate();
+    assert!(verify_min_distance(&points, 0.2));
+
+    let points = Poisson::<4>::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.3)
+        .with_candidate_sequence(CandidateSequence::Halton)
+        .generate();
+    assert!(points.len() > 10);
+    assert!(verify_min_distance(&points, 0.3));
+}
+
+#[test]
+fn halton_fills_more_with_few_samples() {
+    let (mut random, mut halton) = (0, 0);
+    for seed in 0..10 {
+        let poisson = Poisson2D::new().with_seed(seed).with_samples(4);
+        random += poisson.generate().len();
+        halton += poisson
+            .with_candidate_sequence(CandidateSequence::Halton)
+            .generate()
+            .len();
+    }
+
+    assert!(halton > random, "{halton} vs {random}");
+}
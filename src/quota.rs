@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Minimum and maximum numbers of points within each cell of a grid over the space
+
+use crate::{Float, Point};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of attempts to find each point a cell is short of its minimum
+pub(crate) const QUOTA_ATTEMPTS: u32 = 1000;
+
+/// The number of points required within each cell of a grid dividing the box being sampled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Quotas<const N: usize> {
+    /// The number of cells along each axis
+    pub(crate) cells: [usize; N],
+    /// The fewest points each cell should hold
+    pub(crate) min: usize,
+    /// The most points each cell may hold
+    pub(crate) max: usize,
+}
+
+impl<const N: usize> Quotas<N> {
+    /// The total number of cells
+    pub(crate) fn len(&self) -> usize {
+        self.cells.iter().product()
+    }
+
+    /// The index of the cell of the box `[min, max)` containing the point, if it lies within it
+    ///
+    /// Cells are numbered with the first axis varying fastest.
+    pub(crate) fn cell(&self, point: Point<N>, [min, max]: [Point<N>; 2]) -> Option<usize> {
+        let mut index = 0;
+        let mut stride = 1;
+        for i in 0..N {
+            if !(min[i]..max[i]).contains(&point[i]) {
+                return None;
+            }
+            let cell = ((point[i] - min[i]) / (max[i] - min[i]) * self.cells[i] as Float) as usize;
+            index += cell.min(self.cells[i] - 1) * stride;
+            stride *= self.cells[i];
+        }
+
+        Some(index)
+    }
+
+    /// The part of the box `[min, max)` covered by the cell with the given index
+    pub(crate) fn cell_bounds(&self, mut index: usize, [min, max]: [Point<N>; 2]) -> [Point<N>; 2] {
+        let mut lower = [0.0; N];
+        let mut upper = [0.0; N];
+        for i in 0..N {
+            let cell = index % self.cells[i];
+            index /= self.cells[i];
+
+            let size = (max[i] - min[i]) / self.cells[i] as Float;
+            lower[i] = min[i] + cell as Float * size;
+            upper[i] = if cell + 1 == self.cells[i] {
+                max[i]
+            } else {
+                min[i] + (cell + 1) as Float * size
+            };
+        }
+
+        [lower, upper]
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Quotas;
+use crate::stats::verify_min_distance;
+use crate::{Point, Poisson2D};
+
+const BOX: [Point<2>; 2] = [[0.0, 0.0], [4.0, 2.0]];
+
+/// Two islands, in opposite corners of the unit square
+fn islands([x, y]: Point<2>, _: &()) -> bool {
+    let low = (0.0..0.3).contains(&x) && (0.0..0.3).contains(&y);
+    let high = (0.7..1.0).contains(&x) && (0.7..1.0).contains(&y);
+
+    low || high
+}
+
+#[test]
+fn cells_are_numbered_with_the_first_axis_fastest() {
+    let quotas = Quotas {
+        cells: [4, 2],
+        min: 0,
+        max: 1,
+    };
+
+    assert_eq!(quotas.len(), 8);
+    assert_eq!(quotas.cell([0.5, 0.5], BOX), Some(0));
+    assert_eq!(quotas.cell([3.5, 0.5], BOX), Some(3));
+    assert_eq!(quotas.cell([0.5, 1.5], BOX), Some(4));
+    assert_eq!(quotas.cell([3.9, 1.9], BOX), Some(7));
+    assert_eq!(quotas.cell([4.0, 1.0], BOX), None);
+    assert_eq!(quotas.cell([-0.1, 1.0], BOX), None);
+
+    assert_eq!(quotas.cell_bounds(0, BOX), [[0.0, 0.0], [1.0, 1.0]]);
+    assert_eq!(quotas.cell_bounds(6, BOX), [[2.0, 1.0], [3.0, 2.0]]);
+    assert_eq!(quotas.cell_bounds(7, BOX), [[3.0, 1.0], [4.0, 2.0]]);
+}
+
+#[test]
+fn cells_hold_at_most_the_maximum() {
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.05)
+        .with_quotas([2, 2], 0, 40)
+        .generate();
+
+    assert!(verify_min_distance(&points, 0.05 * 0.999));
+    for quadrant in 0..4 {
+        let count = points
+            .iter()
+            .filter(|&&[x, y]| (x >= 0.5) as usize + 2 * (y >= 0.5) as usize == quadrant)
+            .count();
+        assert_eq!(count, 40);
+    }
+}
+
+#[test]
+fn cells_out_of_reach_are_filled_to_the_minimum() {
+    let poisson = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_validate(islands, ());
+    let high = |points: &[Point<2>]| points.iter().filter(|[x, _]| *x > 0.5).count();
+
+    // Without quotas only the island around the first point is filled
+    let points = poisson.generate();
+    assert!(!points.is_empty());
+    assert_eq!(high(&points), 0);
+
+    // With them both islands are, while the empty cells are given up on
+    let points = poisson.with_quotas([2, 2], 1, usize::MAX).generate();
+    assert!(high(&points) > 1);
+    assert!(points.iter().all(|&point| islands(point, &())));
+    assert!(verify_min_distance(&points, 0.1 * 0.999));
+}
+
+#[test]
+fn quotas_are_part_of_the_configuration() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let quotas = poisson.clone().with_quotas([3, 3], 1, 5);
+
+    assert_ne!(poisson, quotas);
+    assert!(quotas
+        .summary()
+        .contains("quotas: 1 to 5 points in each of [3, 3] cells"));
+}
+
+#[test]
+#[should_panic]
+fn cells_are_required_along_every_axis() {
+    let _ = Poisson2D::new().with_quotas([3, 0], 1, 5);
+}
+
+#[test]
+#[should_panic]
+fn minimum_may_not_exceed_maximum() {
+    let _ = Poisson2D::new().with_quotas([3, 3], 5, 1);
+}
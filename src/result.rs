@@ -0,0 +1,135 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Generated distributions bundled with the settings that produced them
+
+use crate::{stats, Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A generated distribution, along with the seed, radius, and dimensions that produced it and the
+/// version of this crate that generated it
+///
+/// With the `derive_serde` feature this can be serialized and deserialized, so that a saved
+/// distribution describes itself: it can be [checked](Self::is_valid) after loading it, or
+/// [regenerated](Self::regenerate) from the same settings later.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// let poisson = Poisson2D::new();
+/// let result = poisson.generate_result();
+///
+/// assert!(result.is_valid());
+/// assert_eq!(result.regenerate(&poisson), result.points);
+/// ```
+///
+/// See [`Poisson::generate_result`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+pub struct GenerationResult<const N: usize> {
+    /// The points of the distribution
+    #[cfg_attr(feature = "derive_serde", serde(with = "points"))]
+    pub points: Vec<Point<N>>,
+    /// The seed the distribution was generated with
+    pub seed: u64,
+    /// The radius around each point
+    pub radius: Float,
+    /// The number of dimensions
+    pub dimensions: usize,
+    /// The version of this crate that generated the distribution
+    pub version: String,
+}
+
+impl<const N: usize> GenerationResult<N> {
+    /// Bundle the points generated from `distribution`, which must have a seed
+    pub(crate) fn new<U, R>(distribution: &Poisson<N, U, R>, points: Vec<Point<N>>) -> Self
+    where
+        U: Default + Clone,
+        R: Rng + SeedableRng,
+    {
+        Self {
+            points,
+            seed: distribution
+                .seed
+                .expect("distribution is seeded before generating"),
+            radius: distribution.radius,
+            dimensions: N,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Returns true if this is a consistent distribution of `N` dimensions
+    ///
+    /// That is, the radius is positive and finite, and no two points are closer together than
+    /// it. This doesn't account for a [density](Poisson::with_density) or a
+    /// [radius tolerance](Poisson::with_radius_tolerance), both of which allow points to be closer
+    /// together than the base radius, so a distribution generated with either may not be valid.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.dimensions == N
+            && self.radius.is_finite()
+            && self.radius > 0.0
+            && stats::verify_min_distance(&self.points, self.radius)
+    }
+
+    /// Generate `distribution` again with this result's seed and radius
+    ///
+    /// Every other setting is taken from `distribution`, so given the one that produced this
+    /// result, and the same version of this crate, the same points are generated again.
+    #[must_use]
+    pub fn regenerate<U, R>(&self, distribution: &Poisson<N, U, R>) -> Vec<Point<N>>
+    where
+        U: Default + Clone,
+        R: Rng + SeedableRng,
+    {
+        distribution
+            .clone()
+            .with_seed(self.seed)
+            .with_radius(self.radius)
+            .generate()
+    }
+}
+
+/// (De)serialize points as sequences of coordinates, as Serde doesn't support arrays of every
+/// length
+#[cfg(feature = "derive_serde")]
+mod points {
+    use crate::{Float, Point};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S, const N: usize>(
+        points: &[Point<N>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(points.iter().map(|point| point.as_slice()))
+    }
+
+    pub(super) fn deserialize<'de, D, const N: usize>(
+        deserializer: D,
+    ) -> Result<Vec<Point<N>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Vec<Float>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|point| {
+                let len = point.len();
+                point
+                    .try_into()
+                    .map_err(|_| D::Error::invalid_length(len, &"a coordinate for each axis"))
+            })
+            .collect()
+    }
+}
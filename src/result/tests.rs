@@ -0,0 +1,59 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Poisson2D, Poisson3D};
+
+#[test]
+fn describes_distribution() {
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF).with_radius(0.2);
+    let result = poisson.generate_result();
+
+    assert_eq!(result.points, poisson.generate());
+    assert_eq!(result.seed, 0xBADBEEF);
+    assert_eq!(result.radius, 0.2);
+    assert_eq!(result.dimensions, 3);
+    assert_eq!(result.version, env!("CARGO_PKG_VERSION"));
+    assert!(result.is_valid());
+}
+
+#[test]
+fn unseeded_can_be_regenerated() {
+    let poisson = Poisson2D::new();
+    let result = poisson.generate_result();
+
+    assert_eq!(result.regenerate(&poisson), result.points);
+}
+
+#[test]
+fn invalid_results() {
+    let valid = Poisson2D::new().with_seed(0xBADBEEF).generate_result();
+
+    let mut result = valid.clone();
+    result.points.push(result.points[0]);
+    assert!(!result.is_valid());
+
+    let mut result = valid.clone();
+    result.dimensions = 3;
+    assert!(!result.is_valid());
+
+    let mut result = valid;
+    result.radius = Float::NAN;
+    assert!(!result.is_valid());
+}
+
+#[cfg(feature = "derive_serde")]
+#[test]
+fn round_trips_through_json() {
+    let result = Poisson2D::new().with_seed(0xBADBEEF).generate_result();
+    let json = serde_json::to_string(&result).unwrap();
+
+    let loaded: super::GenerationResult<2> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, result);
+
+    // Points of the wrong number of dimensions are rejected
+    assert!(serde_json::from_str::<super::GenerationResult<3>>(&json).is_err());
+}
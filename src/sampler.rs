@@ -0,0 +1,71 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! A common interface to the sampling algorithms
+
+use crate::{Iter, Point, Poisson};
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// An algorithm generating a set of points in `N` dimensions
+///
+/// Every sampler is consumed through an iterator, so code written against this trait can switch
+/// between algorithms without any other changes. [`Poisson`] implements it with Bridson's
+/// algorithm, and you can implement it for your own samplers:
+///
+/// ```
+/// use fast_poisson::{Point, Poisson2D, Sampler};
+///
+/// /// The corners of the unit square
+/// struct Corners;
+///
+/// impl Sampler<2> for Corners {
+///     type Iter = std::array::IntoIter<Point<2>, 4>;
+///
+///     fn sample(&self) -> Self::Iter {
+///         [[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]].into_iter()
+///     }
+/// }
+///
+/// fn count<S: Sampler<2>>(sampler: &S) -> usize {
+///     sampler.sample().filter(|[x, _]| *x < 0.5).count()
+/// }
+///
+/// assert_eq!(count(&Corners), 2);
+/// assert!(count(&Poisson2D::new()) > 0);
+/// ```
+pub trait Sampler<const N: usize> {
+    /// The iterator over the generated points
+    type Iter: Iterator<Item = Point<N>>;
+
+    /// Returns an iterator over the points generated by this sampler
+    fn sample(&self) -> Self::Iter;
+
+    /// Generate all of the points, as a [`Vec`]
+    fn generate(&self) -> Vec<Point<N>> {
+        self.sample().collect()
+    }
+}
+
+/// Bridson's algorithm
+impl<const N: usize, U, R> Sampler<N> for Poisson<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Iter = Iter<N, U, R>;
+
+    fn sample(&self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn generate(&self) -> Vec<Point<N>> {
+        Poisson::generate(self)
+    }
+}
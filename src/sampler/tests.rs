@@ -0,0 +1,23 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson3D;
+
+/// Consume any sampler the same way
+fn first_points<const N: usize, S: Sampler<N>>(sampler: &S, count: usize) -> Vec<Point<N>> {
+    sampler.sample().take(count).collect()
+}
+
+#[test]
+fn bridson_sampler() {
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF).with_radius(0.2);
+    let points = poisson.generate();
+
+    assert_eq!(Sampler::generate(&poisson), points);
+    assert_eq!(first_points(&poisson, 10), points[..10]);
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Helpers for deriving seeds from other seeds, and independent streams from a PRNG
+
+use rand_xoshiro::{
+    Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256PlusPlus,
+    Xoshiro256StarStar,
+};
+
+/// Derive a new seed from `seed` and `value`
+///
+/// This uses the SplitMix64 finalizer, so that nearby inputs produce unrelated outputs.
+pub(crate) fn derive(seed: u64, value: u64) -> u64 {
+    let mut z = seed
+        ^ value
+            .wrapping_add(0x9E37_79B9_7F4A_7C15)
+            .wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Hash arbitrary bytes into a seed, with the 64-bit FNV-1a hash
+///
+/// This must never change, as seeds derived from names are stored by applications.
+pub(crate) fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// A PRNG that can jump far ahead in its sequence, splitting it into independent streams
+///
+/// Each jump skips as many values as could ever be drawn for a distribution, so the values drawn
+/// after a different number of jumps never overlap. See [`Poisson::with_stream`].
+///
+/// This is implemented for every xoshiro generator, which includes the default PRNG.
+///
+/// [`Poisson::with_stream`]: crate::Poisson::with_stream
+pub trait Jump {
+    /// Advance the PRNG to the start of the next stream
+    fn jump(&mut self);
+}
+
+/// Advances a PRNG to the start of its next stream
+pub(crate) type JumpFn<R> = fn(&mut R);
+
+macro_rules! impl_jump {
+    ($($rng:ty),*) => {
+        $(
+            impl Jump for $rng {
+                fn jump(&mut self) {
+                    <$rng>::jump(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_jump!(
+    Xoshiro128Plus,
+    Xoshiro128PlusPlus,
+    Xoshiro128StarStar,
+    Xoshiro256Plus,
+    Xoshiro256PlusPlus,
+    Xoshiro256StarStar
+);
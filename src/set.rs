@@ -0,0 +1,110 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Iter, Point, Poisson, Rand};
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// A generated Poisson disk distribution that can be modified after the fact
+///
+/// Points can be removed from and inserted into the set, after which [`refill`][Self::refill]
+/// regrows samples into any holes while maintaining the minimum radius. This makes it possible to
+/// keep a distribution up to date in dynamic simulations, rather than regenerating it from scratch.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// let mut forest = Poisson2D::new().with_seed(0xBADBEEF).generate_set();
+///
+/// // Chop down every tree in a clearing
+/// forest.remove_within([0.5, 0.5], 0.25);
+///
+/// // ...and let it grow back
+/// let regrown = forest.refill();
+/// assert!(regrown > 0);
+/// ```
+pub struct PoissonSet<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The exhausted iterator holding our samples and spatial index
+    iter: Iter<N, U, R>,
+}
+
+impl<const N: usize, U, R> PoissonSet<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Generate the full distribution into a new set
+    pub(crate) fn new(distribution: Poisson<N, U, R>) -> Self {
+        Self {
+            iter: Iter::new(distribution).to_empty(),
+        }
+    }
+
+    /// The points currently in the set
+    #[must_use]
+    pub fn points(&self) -> &[Point<N>] {
+        self.iter.samples()
+    }
+
+    /// The number of points in the set
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points().len()
+    }
+
+    /// Returns true if the set contains no points
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points().is_empty()
+    }
+
+    /// Insert a point into the set
+    ///
+    /// The point is only inserted if it lies within the distribution's space and is at least the
+    /// minimum radius away from every other point; returns true if it was inserted.
+    pub fn insert(&mut self, point: Point<N>) -> bool {
+        self.iter.insert(point)
+    }
+
+    /// Remove a point from the set
+    ///
+    /// Returns true if the point was found and removed.
+    pub fn remove(&mut self, point: Point<N>) -> bool {
+        self.iter.remove_where(|p| *p == point) > 0
+    }
+
+    /// Remove all points within `radius` of `center`
+    ///
+    /// Returns the number of points removed.
+    pub fn remove_within(&mut self, center: Point<N>, radius: Float) -> usize {
+        let radius = radius.powi(2);
+
+        self.iter.remove_where(|p| {
+            p.iter()
+                .zip(center.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<Float>()
+                < radius
+        })
+    }
+
+    /// Regrow samples into any holes in the set
+    ///
+    /// Generation resumes from every point currently in the set, so holes left by removed points
+    /// are filled in while the minimum radius is maintained. Returns the number of points added.
+    pub fn refill(&mut self) -> usize {
+        let radius = self.iter.radius();
+        self.iter.densify(radius);
+
+        self.iter.by_ref().count()
+    }
+}
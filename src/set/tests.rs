@@ -0,0 +1,50 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::Poisson2D;
+
+#[test]
+fn remove_and_refill() {
+    let mut set = Poisson2D::new().with_seed(0xBADBEEF).generate_set();
+    let original = set.len();
+
+    let removed = set.remove_within([0.5, 0.5], 0.3);
+    assert!(removed > 0);
+    assert_eq!(set.len(), original - removed);
+    assert!(set
+        .points()
+        .iter()
+        .all(|p| (p[0] - 0.5).powi(2) + (p[1] - 0.5).powi(2) >= 0.3 * 0.3));
+
+    let added = set.refill();
+    assert!(added > 0);
+    assert_eq!(set.len(), original - removed + added);
+
+    // The radius is still respected everywhere
+    let points = set.points();
+    for (i, a) in points.iter().enumerate() {
+        for b in points[(i + 1)..].iter() {
+            assert!((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) >= 0.1 * 0.1);
+        }
+    }
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut set = Poisson2D::new().with_seed(0xBADBEEF).generate_set();
+    let point = set.points()[0];
+
+    // Too close to itself
+    assert!(!set.insert(point));
+
+    assert!(set.remove(point));
+    assert!(!set.remove(point));
+    assert!(!set.points().contains(&point));
+
+    assert!(set.insert(point));
+    assert!(set.points().contains(&point));
+}
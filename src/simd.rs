@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Vectorized distance checks
+
+use crate::{Float, Point};
+use wide::CmpLt;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(not(feature = "single_precision"))]
+type Lanes = wide::f64x4;
+#[cfg(feature = "single_precision")]
+type Lanes = wide::f32x8;
+
+/// Number of coordinates processed at once
+const LANES: usize = std::mem::size_of::<Lanes>() / std::mem::size_of::<Float>();
+
+/// A set of points laid out for testing distances to many of them at once
+///
+/// Coordinates are stored axis by axis, `LANES` points at a time. The last group is padded with
+/// infinitely distant points, which are never within any radius.
+pub(crate) struct Neighborhood<const N: usize> {
+    /// Groups of points, with `LANES` coordinates for each axis
+    groups: Vec<[Lanes; N]>,
+}
+
+impl<const N: usize> Neighborhood<N> {
+    pub(crate) fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Point<N>>,
+    {
+        let points: Vec<_> = points.into_iter().collect();
+        let groups = points
+            .chunks(LANES)
+            .map(|group| {
+                std::array::from_fn(|axis| {
+                    let mut lanes = [Float::INFINITY; LANES];
+                    for (lane, point) in lanes.iter_mut().zip(group) {
+                        *lane = point[axis];
+                    }
+
+                    Lanes::new(lanes)
+                })
+            })
+            .collect();
+
+        Self { groups }
+    }
+
+    /// Returns true if any point is closer to `point` than the square root of `radius_sq`
+    pub(crate) fn any_within(&self, point: Point<N>, radius_sq: Float) -> bool {
+        let radius_sq = Lanes::splat(radius_sq);
+        let point = point.map(Lanes::splat);
+
+        self.groups.iter().any(|group| {
+            let mut distance_sq = Lanes::splat(0.0);
+            // Sum in the same order as the spatial index does, for identical results
+            for (lanes, coordinate) in group.iter().zip(point.iter()) {
+                let delta = *lanes - *coordinate;
+                distance_sq += delta * delta;
+            }
+
+            distance_sq.cmp_lt(radius_sq).any()
+        })
+    }
+}
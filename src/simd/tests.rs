@@ -0,0 +1,50 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn matches_scalar_distances() {
+    let points = Poisson3D::new().with_seed(0xBADBEEF).generate();
+    // Not a whole number of lanes, so that padding is exercised
+    let points = &points[..LANES * 5 + 1];
+    let neighborhood = Neighborhood::new(points.iter().copied());
+
+    for probe in Poisson3D::new().with_seed(1337).with_radius(0.05).iter() {
+        for radius in [0.02, 0.05, 0.1] {
+            let expected = points.iter().any(|point| {
+                point
+                    .iter()
+                    .zip(probe.iter())
+                    .map(|(a, b)| (a - b).powi(2))
+                    .sum::<Float>()
+                    < radius * radius
+            });
+
+            assert_eq!(neighborhood.any_within(probe, radius * radius), expected);
+        }
+    }
+}
+
+#[test]
+fn empty_neighborhood() {
+    let neighborhood = Neighborhood::<2>::new([]);
+
+    assert!(!neighborhood.any_within([0.5, 0.5], 1.0));
+}
+
+#[test]
+fn merge_without_radius() {
+    // Existing points aren't part of the output, so can't be tested with SIMD lanes
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .merge(&[[0.5, 0.5]], 0.0);
+
+    assert_eq!(points[0], [0.5, 0.5]);
+    assert!(crate::stats::verify_min_distance(&points, 0.1));
+}
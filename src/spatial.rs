@@ -0,0 +1,278 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Spatial indices used to find the points near a candidate
+//!
+//! Generation only needs to know whether any point lies within the radius of each candidate, and
+//! how far away the closest one is. Any structure that answers those questions can be used in
+//! place of the default k-d tree by implementing [`SpatialIndex`]; see
+//! [`Poisson::iter_with_index`][crate::Poisson::iter_with_index].
+//!
+//! The default index is [kiddo]'s k-d tree when the `kiddo` feature is enabled, as it is by
+//! default, and otherwise the simple [`Grid`] built into this crate.
+//!
+//! [kiddo]: https://crates.io/crates/kiddo
+
+use crate::{Float, Point};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The spatial index used unless another is requested
+#[cfg(feature = "kiddo")]
+pub type DefaultIndex<const N: usize> = kiddo::KdTree<Float, N>;
+
+/// The spatial index used unless another is requested
+#[cfg(not(feature = "kiddo"))]
+pub type DefaultIndex<const N: usize> = Grid<N>;
+
+/// A structure for finding the points near a location
+///
+/// Every point is stored along with an item identifying it; the same point may be stored more than
+/// once with different items. All distances are squared, sparing square roots in the hot path.
+pub trait SpatialIndex<const N: usize> {
+    /// Create an empty index, for points that will usually be at least `spacing` apart
+    ///
+    /// The spacing is only a hint, which an index may use to size its cells.
+    fn with_spacing(spacing: Float) -> Self;
+
+    /// Reserve room for at least `additional` more points
+    ///
+    /// Does nothing by default.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Add a point to the index
+    fn insert(&mut self, point: Point<N>, item: u64);
+
+    /// Remove a point from the index, if it was stored with this item
+    fn delete(&mut self, point: Point<N>, item: u64);
+
+    /// Find the closest point, returning its item and squared distance, or `None` if the index is
+    /// empty
+    fn nearest(&self, point: Point<N>) -> Option<(u64, Float)>;
+
+    /// Find every point closer than the square root of `squared_distance`, in any order, returning
+    /// their items and squared distances
+    fn within_squared(&self, point: Point<N>, squared_distance: Float) -> Vec<(u64, Float)>;
+
+    /// Returns true if any point is closer than the square root of `squared_distance`
+    ///
+    /// By default this checks the closest point, which unlike [`within_squared`][Self::within_squared] needs no
+    /// allocation.
+    fn any_within(&self, point: Point<N>, squared_distance: Float) -> bool {
+        self.nearest(point)
+            .map_or(false, |(_, distance)| distance < squared_distance)
+    }
+}
+
+#[cfg(feature = "kiddo")]
+impl<const N: usize> SpatialIndex<N> for kiddo::KdTree<Float, N> {
+    fn with_spacing(_spacing: Float) -> Self {
+        kiddo::KdTree::new()
+    }
+
+    /// The tree can only be sized up front, so room is only reserved while it is empty
+    fn reserve(&mut self, additional: usize) {
+        if self.size() == 0 {
+            *self = kiddo::KdTree::with_capacity(additional);
+        }
+    }
+
+    fn insert(&mut self, point: Point<N>, item: u64) {
+        self.add(&point, item);
+    }
+
+    fn delete(&mut self, point: Point<N>, item: u64) {
+        self.remove(&point, item);
+    }
+
+    fn nearest(&self, point: Point<N>) -> Option<(u64, Float)> {
+        if self.size() == 0 {
+            return None;
+        }
+
+        let nearest = self.nearest_one::<kiddo::SquaredEuclidean>(&point);
+
+        Some((nearest.item, nearest.distance))
+    }
+
+    fn within_squared(&self, point: Point<N>, squared_distance: Float) -> Vec<(u64, Float)> {
+        self.within_unsorted::<kiddo::SquaredEuclidean>(&point, squared_distance)
+            .into_iter()
+            .filter(|neighbor| neighbor.distance < squared_distance)
+            .map(|neighbor| (neighbor.item, neighbor.distance))
+            .collect()
+    }
+}
+
+/// A uniform grid of cells, each holding the points within it
+///
+/// This is the simplest useful spatial index: with cells about as wide as the spacing between
+/// points, each query only needs to look at a handful of cells. It works well in 2 and 3
+/// dimensions, but the number of neighboring cells grows quickly in higher dimensions, where a k-d
+/// tree is the better choice. Only occupied cells are stored, so the space may be unbounded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<const N: usize> {
+    /// The width of each cell
+    cell_size: Float,
+    /// The points within each occupied cell, along with their items
+    cells: HashMap<[i64; N], Vec<(Point<N>, u64)>>,
+    /// The lowest and highest coordinates of any cell ever occupied
+    bounds: Option<[[i64; N]; 2]>,
+}
+
+impl<const N: usize> Grid<N> {
+    /// The cell containing the point
+    fn cell(&self, point: Point<N>) -> [i64; N] {
+        point.map(|x| (x / self.cell_size).floor() as i64)
+    }
+
+    /// Call `f` with the contents of each occupied cell from `min` to `max`, inclusive
+    fn for_each_cell<F>(&self, min: [i64; N], max: [i64; N], mut f: F)
+    where
+        F: FnMut([i64; N], &[(Point<N>, u64)]),
+    {
+        if (0..N).any(|i| min[i] > max[i]) {
+            return;
+        }
+
+        let mut cell = min;
+        loop {
+            if let Some(points) = self.cells.get(&cell) {
+                f(cell, points);
+            }
+
+            // Step to the next cell, like an odometer
+            let mut i = 0;
+            loop {
+                if i == N {
+                    return;
+                }
+                if cell[i] < max[i] {
+                    cell[i] += 1;
+                    break;
+                }
+                cell[i] = min[i];
+                i += 1;
+            }
+        }
+    }
+}
+
+/// The squared distance between two points
+fn distance_squared<const N: usize>(a: Point<N>, b: Point<N>) -> Float {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+impl<const N: usize> SpatialIndex<N> for Grid<N> {
+    fn with_spacing(spacing: Float) -> Self {
+        let cell_size = if spacing.is_finite() && spacing > 0.0 {
+            spacing
+        } else {
+            1.0
+        };
+
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    fn insert(&mut self, point: Point<N>, item: u64) {
+        let cell = self.cell(point);
+        self.bounds = Some(match self.bounds {
+            None => [cell, cell],
+            Some([min, max]) => [
+                std::array::from_fn(|i| min[i].min(cell[i])),
+                std::array::from_fn(|i| max[i].max(cell[i])),
+            ],
+        });
+        self.cells.entry(cell).or_default().push((point, item));
+    }
+
+    fn delete(&mut self, point: Point<N>, item: u64) {
+        let cell = self.cell(point);
+        if let Some(points) = self.cells.get_mut(&cell) {
+            points.retain(|&(p, i)| p != point || i != item);
+            if points.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    /// Search outwards from the point's cell, one shell of cells at a time
+    ///
+    /// Points beyond the `r`th shell are at least `r` cells away, so we can stop as soon as the
+    /// closest point found so far is closer than that, or once every occupied cell was searched.
+    /// Shells that lie entirely outside of the occupied cells are skipped.
+    fn nearest(&self, point: Point<N>) -> Option<(u64, Float)> {
+        let [min, max] = self.bounds?;
+        let center = self.cell(point);
+        let first = (0..N)
+            .map(|i| (min[i] - center[i]).max(center[i] - max[i]).max(0))
+            .max()
+            .unwrap_or(0);
+        let last = (0..N)
+            .map(|i| (center[i] - min[i]).abs().max((max[i] - center[i]).abs()))
+            .max()
+            .unwrap_or(0);
+
+        let mut best: Option<(u64, Float)> = None;
+        for r in first..=last {
+            let lower = std::array::from_fn(|i| (center[i] - r).max(min[i]));
+            let upper = std::array::from_fn(|i| (center[i] + r).min(max[i]));
+            self.for_each_cell(lower, upper, |cell, points| {
+                let shell = (0..N).map(|i| (cell[i] - center[i]).abs()).max();
+                if shell.unwrap_or(0) != r {
+                    return;
+                }
+                for &(p, item) in points {
+                    let distance = distance_squared(p, point);
+                    if best.map_or(true, |(_, d)| distance < d) {
+                        best = Some((item, distance));
+                    }
+                }
+            });
+
+            let searched = r as Float * self.cell_size;
+            if best.map_or(false, |(_, d)| d <= searched.powi(2)) {
+                break;
+            }
+        }
+
+        best
+    }
+
+    fn within_squared(&self, point: Point<N>, squared_distance: Float) -> Vec<(u64, Float)> {
+        let mut found = Vec::new();
+        let Some([min, max]) = self.bounds else {
+            return found;
+        };
+
+        let distance = squared_distance.sqrt();
+        let lower = std::array::from_fn(|i| {
+            (((point[i] - distance) / self.cell_size).floor() as i64).max(min[i])
+        });
+        let upper = std::array::from_fn(|i| {
+            (((point[i] + distance) / self.cell_size).floor() as i64).min(max[i])
+        });
+        self.for_each_cell(lower, upper, |_, points| {
+            for &(p, item) in points {
+                let distance = distance_squared(p, point);
+                if distance < squared_distance {
+                    found.push((item, distance));
+                }
+            }
+        });
+
+        found
+    }
+}
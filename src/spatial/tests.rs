@@ -0,0 +1,73 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+use rand::{Rng, SeedableRng};
+
+/// Check the queries of an index against a brute force search of the same points
+fn check_index<I: SpatialIndex<3>>() {
+    let mut rng = crate::Rand::seed_from_u64(0xBADBEEF);
+    let mut points: Vec<Point<3>> = (0..500)
+        .map(|_| [rng.gen(), rng.gen(), 2.0 * rng.gen::<Float>() - 1.0])
+        .collect();
+
+    let mut index = I::with_spacing(0.1);
+    assert_eq!(index.nearest([0.5; 3]), None);
+    for (i, &point) in points.iter().enumerate() {
+        index.insert(point, i as u64);
+    }
+    // Remove every other point
+    for i in (0..points.len()).step_by(2) {
+        index.delete(points[i], i as u64);
+        points[i] = [Float::INFINITY; 3];
+    }
+
+    for _ in 0..50 {
+        let query = [rng.gen(), 3.0 * rng.gen::<Float>() - 1.5, 0.0];
+        let mut expected: Vec<(u64, Float)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (i as u64, distance_squared(*p, query)))
+            .filter(|(_, distance)| distance.is_finite())
+            .collect();
+        expected.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        assert_eq!(index.nearest(query), Some(expected[0]));
+
+        let mut within = index.within_squared(query, 0.04);
+        within.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        let close: Vec<_> = expected.iter().copied().filter(|n| n.1 < 0.04).collect();
+        assert_eq!(within, close);
+        assert_eq!(index.any_within(query, 0.04), !close.is_empty());
+    }
+}
+
+#[test]
+fn grid_matches_brute_force() {
+    check_index::<Grid<3>>();
+}
+
+#[test]
+fn default_index_matches_brute_force() {
+    check_index::<DefaultIndex<3>>();
+}
+
+#[test]
+fn grid_distribution_is_identical() {
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF).with_radius(0.15);
+    let points: Vec<_> = poisson.iter_with_index::<Grid<3>>().collect();
+    assert_eq!(points, poisson.generate());
+
+    // Including with a density, reseeding, and points far outside of the first cells
+    let poisson = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_density(|[x, _]| x, 0.05, 0.2)
+        .with_reseeding(10, [-10.0, -10.0], [10.0, -9.0]);
+    let points: Vec<_> = poisson.iter_with_index::<Grid<2>>().collect();
+    assert_eq!(points, poisson.generate());
+}
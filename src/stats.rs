@@ -0,0 +1,268 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Statistics about generated distributions
+//!
+//! Besides the [`GenerationStats`] gathered while generating a distribution, this module provides
+//! functions to verify the properties of a generated set of points after the fact. These use a
+//! spatial index, so they remain fast even for large sets.
+//!
+//! ```
+//! # use fast_poisson::Poisson2D;
+//! use fast_poisson::stats;
+//!
+//! let points = Poisson2D::new().with_radius(0.05).generate();
+//!
+//! assert!(stats::verify_min_distance(&points, 0.05));
+//! let rdf = stats::radial_distribution(&points, 1.0, 0.2, 20);
+//! let histogram = stats::nearest_neighbor_histogram(&points, 0.2, 20);
+//! let spectrum = stats::radial_power_spectrum(&points, 1.0, 32);
+//! ```
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::weights::ball_volume;
+use crate::{Float, Point};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests;
+
+/// Statistics about the generation of a distribution
+///
+/// These are useful for tuning the number of samples and the radius on real workloads: a low
+/// acceptance rate means that many candidates are wasted, whether because they fell outside of the
+/// space or too close to other points.
+///
+/// See [`Poisson::generate_with_stats`][crate::Poisson::generate_with_stats].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationStats {
+    /// Number of candidate points tested
+    pub candidates: u64,
+    /// Number of candidate points accepted into the distribution
+    pub accepted: u64,
+    /// Number of candidate points rejected because they were outside of the space
+    pub out_of_domain: u64,
+    /// Number of candidate points rejected because they were too close to another point
+    pub too_close: u64,
+    /// Number of points retired from the active list after all of their samples were rejected
+    pub retired: u64,
+    /// Time spent generating the distribution
+    pub elapsed: Duration,
+}
+
+impl GenerationStats {
+    /// The fraction of candidate points that were accepted
+    ///
+    /// This is 0 if no candidates were tested at all.
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.candidates == 0 {
+            0.0
+        } else {
+            self.accepted as f64 / self.candidates as f64
+        }
+    }
+}
+
+/// Build a spatial index of the points, with each point's index as its item
+fn index<const N: usize>(points: &[Point<N>], spacing: Float) -> DefaultIndex<N> {
+    let mut tree = DefaultIndex::with_spacing(spacing);
+    for (i, point) in points.iter().enumerate() {
+        tree.insert(*point, i as u64);
+    }
+
+    tree
+}
+
+/// Returns true if no two points are closer than `radius` to each other
+pub fn verify_min_distance<const N: usize>(points: &[Point<N>], radius: Float) -> bool {
+    let tree = index(points, radius);
+
+    points.iter().enumerate().all(|(i, point)| {
+        tree.within_squared(*point, radius.powi(2))
+            .iter()
+            .all(|&(item, _)| item == i as u64)
+    })
+}
+
+/// Compute the radial distribution function of the points
+///
+/// The radial distribution function `g(d)` is the density of points at distance `d` from a point,
+/// relative to the mean density of points in the space. Distances up to `max_distance` are
+/// divided into `bins` equally wide shells, and the value of `g` is returned for each shell. For a
+/// Poisson disk distribution `g` is 0 below the radius, peaks just above it, and then settles
+/// around 1.
+///
+/// `volume` is the volume of the space the points were generated in. Shells are not corrected for
+/// extending beyond the edges of the space, so `g` is somewhat underestimated for distances that
+/// are significant compared to the size of the space.
+pub fn radial_distribution<const N: usize>(
+    points: &[Point<N>],
+    volume: Float,
+    max_distance: Float,
+    bins: usize,
+) -> Vec<Float> {
+    let mut counts = vec![0usize; bins];
+    if points.is_empty() || bins == 0 {
+        return vec![0.0; bins];
+    }
+
+    let tree = index(points, max_distance);
+    let width = max_distance / bins as Float;
+    for (i, point) in points.iter().enumerate() {
+        for (item, distance) in tree.within_squared(*point, max_distance.powi(2)) {
+            if item == i as u64 {
+                continue;
+            }
+
+            let bin = (distance.sqrt() / width) as usize;
+            if bin < bins {
+                counts[bin] += 1;
+            }
+        }
+    }
+
+    let density = points.len() as Float / volume;
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(bin, count)| {
+            let shell =
+                ball_volume(N, (bin + 1) as Float * width) - ball_volume(N, bin as Float * width);
+
+            count as Float / (points.len() as Float * density * shell)
+        })
+        .collect()
+}
+
+/// Count the distances from each point to its nearest neighbor
+///
+/// Distances up to `max_distance` are divided into `bins` equally wide ranges, and the number of
+/// points whose nearest neighbor lies within each range is returned. Points without any neighbor
+/// within `max_distance` are not counted.
+pub fn nearest_neighbor_histogram<const N: usize>(
+    points: &[Point<N>],
+    max_distance: Float,
+    bins: usize,
+) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    let tree = index(points, max_distance);
+    let width = max_distance / bins as Float;
+
+    for (i, point) in points.iter().enumerate() {
+        let nearest = tree
+            .within_squared(*point, max_distance.powi(2))
+            .into_iter()
+            .filter(|&(item, _)| item != i as u64)
+            .map(|(_, distance)| distance)
+            .fold(Float::INFINITY, Float::min);
+
+        let bin = (nearest.sqrt() / width) as usize;
+        if bin < bins {
+            counts[bin] += 1;
+        }
+    }
+
+    counts
+}
+
+/// Compute the periodogram of a 2D set of points
+///
+/// The points are assumed to lie within the square from the origin to `[extent, extent]`. The
+/// power is computed for the `resolution × resolution` frequencies `[u, v] / extent`, with `u` and
+/// `v` ranging from `-resolution / 2` up to, but excluding, `resolution / 2`; the returned rows are
+/// indexed by `v` and columns by `u`, both offset by `resolution / 2`, so the zero frequency is in
+/// the middle.
+///
+/// The power is normalized by the number of points, so that a uniformly random set of points has
+/// a power of about 1 at every frequency but zero. The hallmark of blue noise is a power close to 0
+/// at low frequencies, up to about the [effective Nyquist frequency](effective_nyquist), and close
+/// to 1 above it.
+///
+/// This is `O(resolution² × points)`, so keep the resolution modest for large sets of points.
+pub fn power_spectrum(points: &[Point<2>], extent: Float, resolution: usize) -> Vec<Vec<Float>> {
+    let half = (resolution / 2) as i64;
+    let mut real = vec![vec![0.0; resolution]; resolution];
+    let mut imaginary = vec![vec![0.0; resolution]; resolution];
+
+    for point in points {
+        // exp(-2πi·k·x) for each frequency k along each axis
+        let phasors: Vec<Vec<(Float, Float)>> = point
+            .iter()
+            .map(|&x| {
+                (0..resolution as i64)
+                    .map(|k| {
+                        let angle =
+                            -2.0 * std::f64::consts::PI as Float * (k - half) as Float * x / extent;
+                        (angle.cos(), angle.sin())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for (v, &(vr, vi)) in phasors[1].iter().enumerate() {
+            for (u, &(ur, ui)) in phasors[0].iter().enumerate() {
+                real[v][u] += ur * vr - ui * vi;
+                imaginary[v][u] += ur * vi + ui * vr;
+            }
+        }
+    }
+
+    let count = points.len().max(1) as Float;
+    real.into_iter()
+        .zip(imaginary)
+        .map(|(real, imaginary)| {
+            real.into_iter()
+                .zip(imaginary)
+                .map(|(re, im)| (re * re + im * im) / count)
+                .collect()
+        })
+        .collect()
+}
+
+/// Compute the radially averaged power spectrum of a 2D set of points
+///
+/// This averages the [`power_spectrum`] over rings of frequencies: the `k`-th value is the mean
+/// power of all frequencies `[u, v] / extent` whose magnitude `|[u, v]|` rounds to `k`, for `k` up
+/// to `resolution / 2`.
+pub fn radial_power_spectrum(points: &[Point<2>], extent: Float, resolution: usize) -> Vec<Float> {
+    let spectrum = power_spectrum(points, extent, resolution);
+    let half = resolution / 2;
+
+    let mut sums = vec![0.0; half + 1];
+    let mut counts = vec![0usize; half + 1];
+    for (v, row) in spectrum.iter().enumerate() {
+        for (u, power) in row.iter().enumerate() {
+            let du = u as Float - half as Float;
+            let dv = v as Float - half as Float;
+            let ring = du.hypot(dv).round() as usize;
+            if ring <= half {
+                sums[ring] += power;
+                counts[ring] += 1;
+            }
+        }
+    }
+
+    sums.into_iter()
+        .zip(counts)
+        .map(|(sum, count)| {
+            if count == 0 {
+                0.0
+            } else {
+                sum / count as Float
+            }
+        })
+        .collect()
+}
+
+/// The effective Nyquist frequency of `count` points spread over a space of the given `volume`
+///
+/// This is the Nyquist frequency of a regular grid with the same number of points, `√(count /
+/// volume) / 2` in 2D, or in general the `N`-th root for `N` dimensions. A good blue noise
+/// distribution has very little power below this frequency.
+pub fn effective_nyquist<const N: usize>(count: usize, volume: Float) -> Float {
+    (count as Float / volume).powf(1.0 / N as Float) / 2.0
+}
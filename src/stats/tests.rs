@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+#[test]
+fn min_distance() {
+    let points = Poisson2D::new().with_seed(0xBADBEEF).generate();
+    assert!(verify_min_distance(&points, 0.1));
+    assert!(!verify_min_distance(&points, 0.15));
+
+    assert!(!verify_min_distance(&[[0.0, 0.0], [0.05, 0.0]], 0.1));
+    assert!(verify_min_distance::<2>(&[], 0.1));
+}
+
+#[test]
+fn radial_distribution_of_poisson_disk() {
+    let points = Poisson2D::new()
+        .with_radius(0.05)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let rdf = radial_distribution(&points, 1.0, 0.2, 8);
+
+    // Nothing below the radius, a peak just above it, and then roughly uniform
+    assert_eq!(rdf[0], 0.0);
+    assert_eq!(rdf[1], 0.0);
+    assert!(rdf[2] > 1.2, "{:?}", rdf);
+    assert!(rdf[7] > 0.7 && rdf[7] < 1.3, "{:?}", rdf);
+}
+
+#[test]
+fn nearest_neighbors() {
+    let points = [[0.0, 0.0], [0.12, 0.0], [0.5, 0.5], [0.5, 0.85]];
+    assert_eq!(
+        nearest_neighbor_histogram(&points, 0.4, 4),
+        vec![0, 2, 0, 2]
+    );
+
+    // Points without neighbors are left out
+    assert_eq!(
+        nearest_neighbor_histogram(&points, 0.2, 4),
+        vec![0, 0, 2, 0]
+    );
+}
+
+#[test]
+fn blue_noise_spectrum() {
+    use rand::{Rng, SeedableRng};
+
+    let points = Poisson2D::new()
+        .with_radius(0.05)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let nyquist = effective_nyquist::<2>(points.len(), 1.0);
+    let spectrum = radial_power_spectrum(&points, 1.0, 48);
+
+    // The zero frequency holds all of the points
+    assert!((spectrum[0] - points.len() as Float).abs() < 1e-3);
+    // Little power at low frequencies, and about 1 at high frequencies
+    let low = spectrum[1..(nyquist / 2.0) as usize].iter().sum::<Float>() / (nyquist / 2.0 - 1.0);
+    assert!(low < 0.2, "{:?}", spectrum);
+    assert!((spectrum[24] - 1.0).abs() < 0.5, "{:?}", spectrum);
+
+    // White noise has no such hole
+    let mut rng = crate::Rand::seed_from_u64(0xBADBEEF);
+    let white: Vec<_> = (0..points.len())
+        .map(|_| [rng.gen::<Float>(), rng.gen::<Float>()])
+        .collect();
+    let spectrum = radial_power_spectrum(&white, 1.0, 48);
+    let low = spectrum[1..(nyquist / 2.0) as usize].iter().sum::<Float>() / (nyquist / 2.0 - 1.0);
+    assert!(low > 0.5, "{:?}", spectrum);
+}
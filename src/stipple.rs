@@ -0,0 +1,147 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Stipple images with Poisson disk distributions
+
+use crate::estimate::packing;
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::weights::ball_volume;
+use crate::{Float, Point, Poisson};
+use image::DynamicImage;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// Ratio between the radius in the lightest and the darkest parts of the image
+const RADIUS_RATIO: Float = 16.0;
+
+/// Number of bisection steps used to find the radius matching the point budget
+const BISECTION_STEPS: u32 = 40;
+
+/// The darkness of each pixel of an image, from 0 for white to 1 for black, row by row
+fn darkness(image: &DynamicImage) -> Vec<Float> {
+    image
+        .to_luma8()
+        .pixels()
+        .map(|pixel| 1.0 - pixel.0[0] as Float / 255.0)
+        .collect()
+}
+
+/// The radius at a pixel of the given darkness, for the given minimum radius
+///
+/// This matches the radius chosen by [`Density`](crate::density::Density).
+fn radius(darkness: Float, min_radius: Float) -> Float {
+    let max_radius = min_radius * RADIUS_RATIO;
+    if darkness <= 0.0 {
+        max_radius
+    } else {
+        (min_radius / darkness.sqrt()).min(max_radius)
+    }
+}
+
+/// Find the minimum radius for which about `budget` points are expected
+fn min_radius(darkness: &[Float], budget: usize) -> Float {
+    let expected = |min_radius: Float| -> Float {
+        darkness
+            .iter()
+            .map(|&d| packing(2) / ball_volume(2, radius(d, min_radius) / 2.0))
+            .sum()
+    };
+
+    // The expected count shrinks as the radius grows
+    let (mut low, mut high): (Float, Float) = (1e-3, darkness.len() as Float);
+    for _ in 0..BISECTION_STEPS {
+        let mid = (low * high).sqrt();
+        if expected(mid) > budget as Float {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low * high).sqrt()
+}
+
+/// Stipple an image with about `budget` points
+pub(crate) fn stipple<U, R>(
+    distribution: &Poisson<2, U, R>,
+    image: &DynamicImage,
+    budget: usize,
+    relaxation: u32,
+) -> Vec<Point<2>>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let width = image.width() as usize;
+    let size = [image.width() as Float, image.height() as Float];
+    let darkness = Arc::new(darkness(image));
+    let min_radius = min_radius(&darkness, budget);
+
+    let mut poisson = Poisson::<2, Point<2>, R>::new()
+        .with_validate(
+            |[x, y], size| (0.0..size[0]).contains(&x) && (0.0..size[1]).contains(&y),
+            size,
+        )
+        .with_samples(distribution.num_samples);
+    if let Some(seed) = distribution.seed {
+        poisson.set_seed(seed);
+    }
+    let pixels = darkness.clone();
+    poisson.set_density(
+        move |[x, y]| pixels[y as usize * width + x as usize],
+        min_radius,
+        min_radius * RADIUS_RATIO,
+    );
+
+    let mut points = poisson.generate();
+    for _ in 0..relaxation {
+        relax(&mut points, &darkness, width);
+    }
+
+    points
+}
+
+/// Move each point to the centroid of its Voronoi cell, weighted by darkness
+///
+/// This is a single step of Lloyd's algorithm over the pixels of the image.
+fn relax(points: &mut [Point<2>], darkness: &[Float], width: usize) {
+    if points.is_empty() {
+        return;
+    }
+
+    // Cells as wide as the typical spacing between points, for indices that use them
+    let spacing = (darkness.len() as Float / points.len() as Float).sqrt();
+    let mut tree: DefaultIndex<2> = DefaultIndex::with_spacing(spacing);
+    for (i, point) in points.iter().enumerate() {
+        tree.insert(*point, i as u64);
+    }
+
+    let mut sums = vec![[0.0; 3]; points.len()];
+    for (i, &weight) in darkness.iter().enumerate() {
+        if weight <= 0.0 {
+            continue;
+        }
+
+        let pixel = [(i % width) as Float + 0.5, (i / width) as Float + 0.5];
+        let Some((nearest, _)) = tree.nearest(pixel) else {
+            continue;
+        };
+        let nearest = nearest as usize;
+        sums[nearest][0] += pixel[0] * weight;
+        sums[nearest][1] += pixel[1] * weight;
+        sums[nearest][2] += weight;
+    }
+
+    for (point, [x, y, weight]) in points.iter_mut().zip(sums) {
+        if weight > 0.0 {
+            *point = [x / weight, y / weight];
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+use image::{GrayImage, Luma};
+
+/// A gradient from white on the left to black on the right
+fn gradient() -> DynamicImage {
+    DynamicImage::ImageLuma8(GrayImage::from_fn(64, 64, |x, _| {
+        Luma([255 - (x * 4) as u8])
+    }))
+}
+
+#[test]
+fn follows_tone() {
+    let image = gradient();
+    let points = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .stipple(&image, 300, 0);
+
+    let count = points.len() as Float;
+    assert!((count / 300.0 - 1.0).abs() < 0.25, "{} points", count);
+    assert!(points
+        .iter()
+        .all(|&[x, y]| (0.0..64.0).contains(&x) && (0.0..64.0).contains(&y)));
+
+    // The darker right half has most of the points
+    let dark = points.iter().filter(|p| p[0] >= 32.0).count() as Float;
+    assert!(dark > 2.0 * (count - dark), "{} of {}", dark, count);
+}
+
+#[test]
+fn relaxation_keeps_points() {
+    let image = gradient();
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let points = poisson.stipple(&image, 200, 0);
+    let relaxed = poisson.stipple(&image, 200, 3);
+
+    assert_eq!(points.len(), relaxed.len());
+    assert_ne!(points, relaxed);
+    assert!(relaxed
+        .iter()
+        .all(|&[x, y]| (0.0..64.0).contains(&x) && (0.0..64.0).contains(&y)));
+}
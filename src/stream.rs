@@ -0,0 +1,140 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Asynchronous streams of points, for use within async executors
+
+use crate::{Iter, Point, Rand};
+use futures_core::{FusedStream, Stream};
+use rand::{Rng, SeedableRng};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of points returned before yielding to the executor, by default
+const DEFAULT_BUDGET: usize = 256;
+
+/// A [`Stream`] over the points in the Poisson disk distribution
+///
+/// Points are generated as they are polled, exactly as by [`Iter`], so the stream returns the
+/// same points in the same order. Generating a large distribution takes a while though, so after
+/// every [budget](Self::with_budget) of points the stream yields to the executor, by waking itself
+/// and returning `Pending`, so that other tasks get to run in the meantime.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// # use futures_core::Stream;
+/// # fn is_stream(_: impl Stream) {}
+/// let stream = Poisson2D::new().stream().with_budget(100);
+/// # is_stream(stream);
+/// ```
+///
+/// See [`Poisson::stream`](crate::Poisson::stream).
+pub struct PointStream<const N: usize, U, R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The underlying iterator
+    iter: Iter<N, U, R>,
+    /// Number of points to return before yielding
+    budget: usize,
+    /// Number of points returned since last yielding
+    returned: usize,
+    /// Whether the iterator is exhausted
+    done: bool,
+}
+
+impl<const N: usize, U, R> PointStream<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Stream the points of the iterator
+    pub(crate) fn new(iter: Iter<N, U, R>) -> Self {
+        Self {
+            iter,
+            budget: DEFAULT_BUDGET,
+            returned: 0,
+            done: false,
+        }
+    }
+
+    /// Specify the number of points to return before yielding to the executor
+    ///
+    /// The default is 256. Smaller budgets let other tasks run more often, at the cost of more
+    /// trips through the executor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    ///
+    /// ```
+    /// # use fast_poisson::Poisson2D;
+    /// let stream = Poisson2D::new().stream().with_budget(16);
+    /// ```
+    #[must_use]
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.set_budget(budget);
+        self
+    }
+
+    /// Specify the number of points to return before yielding to the executor
+    ///
+    /// See [`with_budget`][Self::with_budget] for more details.
+    pub fn set_budget(&mut self, budget: usize) {
+        assert!(budget > 0, "budget must be at least one point");
+        self.budget = budget;
+    }
+}
+
+impl<const N: usize, U, R> Stream for PointStream<N, U, R>
+where
+    U: Default + Clone + Unpin,
+    R: Rng + SeedableRng + Unpin,
+{
+    type Item = Point<N>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        if this.returned >= this.budget {
+            // Out of budget: let other tasks run, but ask to be polled again straight away
+            this.returned = 0;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let point = this.iter.next();
+        this.returned += 1;
+        this.done = point.is_none();
+
+        Poll::Ready(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<const N: usize, U, R> FusedStream for PointStream<N, U, R>
+where
+    U: Default + Clone + Unpin,
+    R: Rng + SeedableRng + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
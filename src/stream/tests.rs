@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::Poisson2D;
+use futures::executor::block_on;
+use futures::StreamExt;
+use futures_core::{FusedStream, Stream};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Waker counting how many times it's been woken
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+impl Wake for Counter {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn same_points_as_iter() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let points: Vec<_> = block_on(poisson.stream().with_budget(7).collect());
+
+    assert_eq!(points, poisson.generate());
+}
+
+#[test]
+fn yields_every_budget() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let expected = poisson.generate();
+    let mut stream = poisson.stream().with_budget(10);
+
+    let counter = Arc::new(Counter::default());
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut points = Vec::new();
+    let mut pending = 0;
+    loop {
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(Some(point)) => points.push(point),
+            Poll::Ready(None) => break,
+            Poll::Pending => {
+                // Every yield is preceded by exactly the budget's worth of points
+                assert_eq!(points.len(), (pending + 1) * 10);
+                pending += 1;
+            }
+        }
+    }
+
+    assert_eq!(points, expected);
+    assert_eq!(pending, expected.len() / 10);
+    // Each yield wakes the task again, so it isn't left stranded
+    assert_eq!(counter.0.load(Ordering::SeqCst), pending);
+    assert!(stream.is_terminated());
+}
+
+#[test]
+#[should_panic]
+fn zero_budget() {
+    let _ = Poisson2D::new().stream().with_budget(0);
+}
@@ -117,3 +117,440 @@ fn poisson_equality() {
     // Different dimension, unequal again
     assert_ne!(poisson, poisson2);
 }
+
+#[test]
+fn merge_preserves_spacing() {
+    let existing = Poisson2D::new()
+        .with_radius(0.2)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let merged = Poisson2D::new()
+        .with_radius(0.05)
+        .with_seed(0xCAFEF00D)
+        .merge(&existing, 0.1);
+
+    assert_eq!(existing[..], merged[..existing.len()]);
+    assert!(merged.len() > existing.len());
+
+    for new in merged[existing.len()..].iter() {
+        for old in existing.iter() {
+            let dist = ((new[0] - old[0]).powi(2) + (new[1] - old[1]).powi(2)).sqrt();
+            assert!(dist >= 0.1);
+        }
+    }
+}
+
+#[test]
+fn lod_levels_are_nested() {
+    let radii = [0.05, 0.2, 0.1];
+    let levels = Poisson2D::new().with_seed(0xBADBEEF).generate_lod(&radii);
+
+    assert_eq!(levels.len(), 3);
+    assert_eq!(levels[1][..], levels[2][..levels[1].len()]);
+    assert_eq!(levels[2][..], levels[0][..levels[2].len()]);
+    assert!(levels[1].len() < levels[2].len() && levels[2].len() < levels[0].len());
+
+    for (level, radius) in levels.iter().zip(radii) {
+        assert!(crate::stats::verify_min_distance(level, radius));
+    }
+
+    // The coarsest level is the distribution at that radius
+    let coarse = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .with_radius(0.2)
+        .generate();
+    assert_eq!(levels[1], coarse);
+
+    assert!(Poisson2D::new().generate_lod(&[]).is_empty());
+}
+
+#[test]
+fn bounds_with_negative_coordinates() {
+    let points = Poisson2D::new()
+        .with_bounds([-512.0, -512.0], [512.0, 512.0])
+        .with_radius(32.0)
+        .with_seed(0xBADBEEF)
+        .generate();
+
+    assert!(points
+        .iter()
+        .all(|p| p.iter().all(|x| (-512.0..512.0).contains(x))));
+    assert!(points.iter().any(|&[x, y]| x < -256.0 && y < -256.0));
+    assert!(crate::stats::verify_min_distance(&points, 32.0));
+
+    // The same number of points as the unit square at the same relative radius
+    let unit = Poisson2D::new()
+        .with_radius(1.0 / 32.0)
+        .with_seed(0xBADBEEF)
+        .generate();
+    let ratio = points.len() as Float / unit.len() as Float;
+    assert!(
+        (0.9..1.1).contains(&ratio),
+        "{} vs {}",
+        points.len(),
+        unit.len()
+    );
+
+    // Validation restricts points further within the bounds
+    let disk = Poisson2D::new()
+        .with_bounds([-512.0, -512.0], [512.0, 512.0])
+        .with_validate(|[x, y], _| x * x + y * y < 256.0 * 256.0, ())
+        .with_radius(32.0)
+        .generate();
+    assert!(disk.iter().all(|[x, y]| x * x + y * y < 256.0 * 256.0));
+}
+
+#[test]
+fn transform_maps_output() {
+    let canonical = Poisson2D::new().with_seed(0xBADBEEF);
+    let transformed = canonical
+        .clone()
+        .with_transform([[2.0, 1.0], [0.0, -1.0]], [-3.0, 5.0]);
+
+    let expected: Vec<_> = canonical
+        .generate()
+        .into_iter()
+        .map(|[x, y]| [2.0 * x + y - 3.0, 5.0 - y])
+        .collect();
+    assert_eq!(transformed.generate(), expected);
+
+    // Parents and spacing are unaffected
+    let parents: Vec<_> = transformed.iter_with_parent().map(|(p, _)| p).collect();
+    let canonical_parents: Vec<_> = canonical.iter_with_parent().map(|(p, _)| p).collect();
+    assert_eq!(parents, canonical_parents);
+
+    // Existing points are transformed along with the new ones
+    let existing = [[0.5, 0.5]];
+    assert_eq!(transformed.merge(&existing, 0.1)[0], [-1.5, 4.5]);
+
+    // Shuffled points are still the same points
+    let mut shuffled = transformed.clone().with_shuffled_output(true).generate();
+    shuffled.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut sorted = expected;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(shuffled, sorted);
+}
+
+#[test]
+fn ids_are_stable_and_unique() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let a: Vec<_> = poisson.iter_with_ids().collect();
+    let b: Vec<_> = poisson.iter_with_ids().collect();
+
+    assert_eq!(a, b);
+
+    let mut ids: Vec<_> = a.iter().map(|(id, _)| *id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), a.len());
+
+    // A different seed gives different identifiers
+    let c: Vec<_> = Poisson2D::new().with_seed(1337).iter_with_ids().collect();
+    assert_ne!(a[0].0, c[0].0);
+}
+
+#[test]
+fn scatter_is_deterministic() {
+    use rand::Rng;
+
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let draw = |rng: &mut Rand, _| rng.gen::<u32>();
+
+    let a: Vec<_> = poisson.iter_scatter(draw).collect();
+    let b: Vec<_> = poisson.iter_scatter(draw).collect();
+    assert_eq!(a, b);
+
+    // Attributes share the RNG stream, so drawing them changes the points that follow
+    let points: Vec<_> = a.iter().map(|(p, _)| *p).collect();
+    assert_ne!(points, poisson.generate());
+}
+
+#[test]
+fn graph_matches_brute_force() {
+    let (points, neighbors) = Poisson2D::new().with_seed(0xBADBEEF).generate_graph(0.2);
+
+    assert_eq!(points, Poisson2D::new().with_seed(0xBADBEEF).generate());
+    assert_eq!(points.len(), neighbors.len());
+    for (i, a) in points.iter().enumerate() {
+        let expected: Vec<usize> = points
+            .iter()
+            .enumerate()
+            .filter(|&(j, b)| j != i && (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) <= 0.04)
+            .map(|(j, _)| j)
+            .collect();
+
+        assert_eq!(neighbors[i], expected);
+    }
+}
+
+#[test]
+fn stats_add_up() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let (points, stats) = poisson.generate_with_stats();
+
+    assert_eq!(points, poisson.generate());
+    // Without emitted first points, every point is an accepted candidate
+    assert_eq!(stats.accepted, points.len() as u64);
+    assert_eq!(
+        stats.candidates,
+        stats.accepted + stats.out_of_domain + stats.too_close
+    );
+    assert!(stats.out_of_domain > 0 && stats.too_close > 0);
+    // Every active point is eventually retired, including the first point
+    assert_eq!(stats.retired, points.len() as u64 + 1);
+    assert!(stats.acceptance_rate() > 0.0 && stats.acceptance_rate() < 1.0);
+}
+
+#[test]
+fn batch_generates_each_seed() {
+    let poisson = Poisson2D::new().with_radius(0.2);
+    let batch = poisson.generate_batch(&[1, 2, 1]);
+
+    assert_eq!(batch[0], poisson.clone().with_seed(1).generate());
+    assert_eq!(batch[1], poisson.clone().with_seed(2).generate());
+    assert_eq!(batch[0], batch[2]);
+    assert!(poisson.generate_batch(&[]).is_empty());
+}
+
+#[test]
+fn spawn_into_stops_when_receiver_dropped() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let (sender, receiver) = std::sync::mpsc::sync_channel(4);
+    let producer = poisson.spawn_into(sender);
+
+    let first: Vec<_> = receiver.iter().take(10).collect();
+    drop(receiver);
+
+    assert_eq!(first[..], poisson.generate()[..10]);
+    // Backpressure kept the producer close behind what was received
+    let sent = producer.join().unwrap();
+    assert!((10..=15).contains(&sent), "{sent}");
+}
+
+#[test]
+fn for_each_point_matches_iter() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let mut points = Vec::new();
+    poisson.for_each_point(|point| points.push(point));
+    assert_eq!(points, poisson.generate());
+
+    let shuffled = poisson.with_shuffled_output(true);
+    let mut points = Vec::new();
+    shuffled.for_each_point(|point| points.push(point));
+    assert_eq!(points, shuffled.generate());
+}
+
+#[test]
+fn generate_into_short_buffer() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let points = poisson.generate();
+
+    let mut buffer = [[-1.0; 2]; 10];
+    assert_eq!(poisson.generate_into(&mut buffer), 10);
+    assert_eq!(buffer[..], points[..10]);
+
+    let mut buffer = vec![[-1.0; 2]; points.len() + 5];
+    assert_eq!(poisson.generate_into(&mut buffer), points.len());
+    assert_eq!(buffer[..points.len()], points[..]);
+    // The rest of the buffer is untouched
+    assert!(buffer[points.len()..].iter().all(|&p| p == [-1.0; 2]));
+}
+
+#[test]
+fn generate_into_vec_reuses_capacity() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let mut points = Vec::with_capacity(1000);
+    let storage = points.as_ptr();
+
+    poisson.generate_into_vec(&mut points);
+    assert_eq!(points, poisson.generate());
+    let sparse = poisson.with_radius(0.2);
+    sparse.generate_into_vec(&mut points);
+    assert_eq!(points, sparse.generate());
+    assert_eq!(points.as_ptr(), storage);
+}
+
+#[test]
+fn flat_and_columns_match_points() {
+    let poisson = Poisson3D::new().with_seed(0xBADBEEF);
+    let points = poisson.generate();
+
+    let flat = poisson.generate_flat();
+    assert_eq!(flat.len(), points.len() * 3);
+    for (chunk, point) in flat.chunks_exact(3).zip(points.iter()) {
+        assert_eq!(chunk, point);
+    }
+
+    let columns = poisson.generate_columns();
+    for (axis, column) in columns.iter().enumerate() {
+        assert_eq!(column.len(), points.len());
+        assert!(column.iter().zip(points.iter()).all(|(&x, p)| x == p[axis]));
+    }
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn array_has_row_per_point() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let array = poisson.generate_array();
+    let points = poisson.generate();
+
+    assert_eq!(array.dim(), (points.len(), 2));
+    for (row, point) in array.rows().into_iter().zip(points.iter()) {
+        assert_eq!(row.to_vec(), point);
+    }
+}
+
+#[test]
+fn streams_are_independent_and_repeatable() {
+    let base = Poisson2D::new().with_seed(0xBADBEEF);
+    let first = base.clone().with_stream(1);
+    let second = base.clone().with_stream(2);
+
+    assert_eq!(base.clone().with_stream(0).generate(), base.generate());
+    assert_eq!(first.generate(), first.generate());
+    assert_ne!(first.generate(), base.generate());
+    assert_ne!(first.generate(), second.generate());
+    assert_ne!(first, second);
+    assert_eq!(first, base.with_stream(1));
+}
+
+#[test]
+fn getters_report_configuration() {
+    let poisson = Poisson2D::new()
+        .with_radius(0.05)
+        .with_seed(7)
+        .with_stream(2)
+        .with_samples(12)
+        .with_bounds([-1.0, -1.0], [1.0, 1.0]);
+
+    assert_eq!(poisson.radius(), 0.05);
+    assert_eq!(poisson.seed(), Some(7));
+    assert_eq!(poisson.stream_index(), Some(2));
+    assert_eq!(poisson.samples(), 12);
+    assert_eq!(poisson.bounds(), Some([[-1.0, -1.0], [1.0, 1.0]]));
+    assert_eq!(poisson.reseed_bounds(), [[-1.0, -1.0], [1.0, 1.0]]);
+
+    let defaults = Poisson2D::new();
+    assert_eq!(defaults.seed(), None);
+    assert_eq!(defaults.stream_index(), None);
+    assert_eq!(defaults.bounds(), None);
+}
+
+#[test]
+fn memory_estimate_grows_with_count() {
+    let sparse = Poisson3D::new().with_radius(0.2);
+    let dense = Poisson3D::new().with_radius(0.1);
+
+    assert!(sparse.memory_estimate() > sparse.estimate_count() * 3 * 8);
+    assert!(dense.memory_estimate() > 4 * sparse.memory_estimate());
+}
+
+#[test]
+fn summary_lists_settings() {
+    let summary = Poisson2D::new()
+        .with_seed(7)
+        .with_shuffled_output(true)
+        .with_bounds([0.0, 0.0], [2.0, 1.0])
+        .summary();
+
+    for line in [
+        "dimensions: 2",
+        "radius: 0.1",
+        "seed: 7",
+        "samples: 30",
+        "bounds: [0.0, 0.0] to [2.0, 1.0]",
+        "output order: shuffled",
+    ] {
+        assert!(summary.lines().any(|l| l == line), "{line} in {summary}");
+    }
+    assert!(summary.contains("estimated points: "));
+    assert!(!summary.contains("stream"));
+}
+
+#[test]
+fn radius_tolerance_relaxes_spacing() {
+    let strict = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.02);
+    let relaxed = strict.clone().with_radius_tolerance(0.2);
+    let points = relaxed.generate();
+
+    assert!(points.len() > strict.generate().len());
+    // Some pairs are closer than the radius, but never by more than the tolerance
+    assert!(!stats::verify_min_distance(&points, 0.02));
+    assert!(stats::verify_min_distance(&points, 0.02 * 0.8));
+    assert_eq!(
+        strict.clone().with_radius_tolerance(0.0).generate(),
+        strict.generate()
+    );
+}
+
+#[test]
+#[should_panic]
+fn radius_tolerance_must_be_below_one() {
+    let _ = Poisson2D::new().with_radius_tolerance(1.0);
+}
+
+#[test]
+fn cached_generation_follows_settings() {
+    let mut poisson = Poisson2D::new().with_seed(0xBADBEEF);
+    let points = poisson.generate_cached();
+
+    assert_eq!(points, poisson.generate());
+    assert_eq!(poisson.clone().generate_cached(), points);
+
+    poisson.set_seed(0xC0FFEE);
+    assert_eq!(poisson.generate_cached(), poisson.generate());
+    assert_ne!(poisson.generate_cached(), points);
+
+    let mut validated = poisson.with_validate(
+        |[x, y], _| (0.0..0.5).contains(&x) && (0.0..1.0).contains(&y),
+        (),
+    );
+    assert!(validated.generate_cached().iter().all(|&[x, _]| x < 0.5));
+}
+
+#[test]
+fn generate_at_least_retries_deterministically() {
+    let poisson = Poisson2D::new().with_seed(0xBADBEEF).with_radius(0.3);
+    let first = poisson.generate();
+
+    // The first attempt is the seeded distribution itself
+    assert_eq!(poisson.generate_at_least(first.len(), 1), Ok(first.clone()));
+
+    // Later attempts are derived from the seed
+    let more = poisson.generate_at_least(first.len() + 1, 1000).unwrap();
+    assert!(more.len() > first.len());
+    assert_eq!(poisson.generate_at_least(first.len() + 1, 1000), Ok(more));
+}
+
+#[test]
+fn generate_at_least_retries_unseeded() {
+    let points = Poisson2D::new()
+        .with_radius(0.3)
+        .generate_at_least(10, 1000)
+        .unwrap();
+
+    assert!(points.len() >= 10);
+}
+
+#[test]
+fn seeds_from_bytes_use_fnv_1a() {
+    // Published FNV-1a test vectors, which must never change
+    for (bytes, hash) in [
+        (&b""[..], 0xCBF2_9CE4_8422_2325),
+        (b"a", 0xAF63_DC4C_8601_EC8C),
+        (b"foobar", 0x8594_4171_F739_67E8),
+    ] {
+        assert_eq!(crate::seed::hash(bytes), hash);
+        assert_eq!(Poisson2D::new().with_seed_bytes(bytes).seed(), Some(hash));
+    }
+
+    let mut named = Poisson2D::new();
+    named.set_seed_str("foobar");
+    assert_eq!(named, Poisson2D::new().with_seed_bytes(b"foobar"));
+    assert_eq!(
+        named.generate(),
+        Poisson2D::new().with_seed_str("foobar").generate()
+    );
+    assert_ne!(named, Poisson2D::new().with_seed_str("Foobar"));
+}
@@ -0,0 +1,287 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{seed, Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod tests;
+
+/// A set of corner-colored Wang tiles filled with a Poisson disk distribution
+///
+/// Every corner of a tile is assigned one of a small number of colors, and a tile exists for each
+/// combination of corner colors. Points near a corner or an edge are shared by every tile with the
+/// same colors there, so any two tiles whose shared corners match can be placed side by side
+/// without violating the minimum radius. By choosing corner colors pseudo-randomly for each
+/// lattice vertex, an unbounded plane can be covered aperiodically from a small set of tiles;
+/// [`points_in`][Self::points_in] does exactly that.
+///
+/// ```
+/// # use fast_poisson::Poisson2D;
+/// let tiles = Poisson2D::new().with_seed(0xBADBEEF).generate_tiles(1.0, 2);
+///
+/// // Cover any part of the plane, no matter how far from the origin
+/// let points = tiles.points_in([-1000.0, -1000.0], [-990.0, -990.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TileSet {
+    /// Width and height of each tile
+    size: Float,
+    /// Number of corner colors
+    colors: u32,
+    /// Seed used to color lattice vertices
+    seed: u64,
+    /// Points in each tile, relative to its lower corner, indexed by corner colors
+    tiles: Vec<Vec<Point<2>>>,
+}
+
+/// A region of a tile that is filled independently of the others
+#[derive(Debug, Clone, Default)]
+struct Region {
+    /// Lower corner of the region's bounding box
+    min: Point<2>,
+    /// Upper corner of the region's bounding box
+    max: Point<2>,
+    /// Boxes within the bounding box that are excluded from the region
+    holes: Vec<[Point<2>; 2]>,
+}
+
+impl Region {
+    fn new(min: Point<2>, max: Point<2>) -> Self {
+        Self {
+            min,
+            max,
+            holes: Vec::new(),
+        }
+    }
+
+    fn contains(point: Point<2>, region: &Region) -> bool {
+        in_box(point, region.min, region.max)
+            && !region
+                .holes
+                .iter()
+                .any(|&[min, max]| in_box(point, min, max))
+    }
+}
+
+fn in_box(point: Point<2>, min: Point<2>, max: Point<2>) -> bool {
+    (min[0]..max[0]).contains(&point[0]) && (min[1]..max[1]).contains(&point[1])
+}
+
+fn translate(points: &[Point<2>], by: Point<2>) -> impl Iterator<Item = Point<2>> + '_ {
+    points.iter().map(move |p| [p[0] + by[0], p[1] + by[1]])
+}
+
+impl TileSet {
+    /// Generate a complete tile set
+    ///
+    /// Each corner is surrounded by a square of half-width `2 * radius` and each edge by a strip of
+    /// half-width `radius`; these are filled first, and shared among all tiles, before each tile's
+    /// interior is filled around them.
+    pub(crate) fn new<U, R>(distribution: &Poisson<2, U, R>, size: Float, colors: u32) -> Self
+    where
+        U: Default + Clone,
+        R: Rng + SeedableRng,
+    {
+        let radius = distribution.radius;
+        assert!(
+            size >= 6.0 * radius,
+            "Tiles must be at least 6 times as large as the radius"
+        );
+        assert!(colors > 0, "Tiles need at least one corner color");
+
+        let corner = 2.0 * radius;
+        let edge = radius;
+        let seed = distribution.seed.unwrap_or_else(rand::random);
+        let mut component = 0;
+        let mut fill = |region: Region, existing: &[Point<2>]| -> Vec<Point<2>> {
+            component += 1;
+            let center = [
+                (region.min[0] + region.max[0]) / 2.0,
+                (region.min[1] + region.max[1]) / 2.0,
+            ];
+            let (min, max) = (region.min, region.max);
+
+            Poisson::<2, Region, R>::new()
+                .with_radius(radius)
+                .with_samples(distribution.num_samples)
+                .with_seed(seed::derive(seed, component))
+                .with_validate(Region::contains, region)
+                .with_first_point(center)
+                .with_reseeding(100, min, max)
+                .merge(existing, radius)
+                .split_off(existing.len())
+        };
+
+        // Corners are shared by every tile with the same color there
+        let corners: Vec<_> = (0..colors)
+            .map(|_| fill(Region::new([-corner; 2], [corner; 2]), &[]))
+            .collect();
+
+        // Edges are constrained only by the corners at either end, and are indexed by those colors
+        let mut horizontal = Vec::new();
+        let mut vertical = Vec::new();
+        for a in 0..colors as usize {
+            for b in 0..colors as usize {
+                let existing: Vec<_> = corners[a]
+                    .iter()
+                    .copied()
+                    .chain(translate(&corners[b], [size, 0.0]))
+                    .collect();
+                horizontal.push(fill(
+                    Region::new([corner, -edge], [size - corner, edge]),
+                    &existing,
+                ));
+
+                let existing: Vec<_> = corners[a]
+                    .iter()
+                    .copied()
+                    .chain(translate(&corners[b], [0.0, size]))
+                    .collect();
+                vertical.push(fill(
+                    Region::new([-edge, corner], [edge, size - corner]),
+                    &existing,
+                ));
+            }
+        }
+
+        // Finally fill the interior of each tile around its corners and edges
+        let c = colors as usize;
+        let mut tiles = Vec::with_capacity(c.pow(4));
+        for index in 0..c.pow(4) {
+            let [sw, se, nw, ne] = Self::corners_of(index, c);
+
+            let boundary: Vec<_> = translate(&corners[sw], [0.0, 0.0])
+                .chain(translate(&corners[se], [size, 0.0]))
+                .chain(translate(&corners[nw], [0.0, size]))
+                .chain(translate(&corners[ne], [size, size]))
+                .chain(translate(&horizontal[sw * c + se], [0.0, 0.0]))
+                .chain(translate(&horizontal[nw * c + ne], [0.0, size]))
+                .chain(translate(&vertical[sw * c + nw], [0.0, 0.0]))
+                .chain(translate(&vertical[se * c + ne], [size, 0.0]))
+                .collect();
+
+            let mut interior = Region::new([0.0, 0.0], [size, size]);
+            for [x, y] in [[0.0, 0.0], [size, 0.0], [0.0, size], [size, size]] {
+                interior
+                    .holes
+                    .push([[x - corner, y - corner], [x + corner, y + corner]]);
+            }
+            for y in [0.0, size] {
+                interior
+                    .holes
+                    .push([[corner, y - edge], [size - corner, y + edge]]);
+            }
+            for x in [0.0, size] {
+                interior
+                    .holes
+                    .push([[x - edge, corner], [x + edge, size - corner]]);
+            }
+
+            let mut points = fill(interior, &boundary);
+            // Each tile owns only those boundary points that lie within its own square
+            points.extend(
+                boundary
+                    .into_iter()
+                    .filter(|&p| in_box(p, [0.0, 0.0], [size, size])),
+            );
+            tiles.push(points);
+        }
+
+        Self {
+            size,
+            colors,
+            seed,
+            tiles,
+        }
+    }
+
+    /// Corner colors, as `[sw, se, nw, ne]`, of the tile at `index`
+    fn corners_of(index: usize, colors: usize) -> [usize; 4] {
+        [
+            index / colors.pow(3),
+            index / colors.pow(2) % colors,
+            index / colors % colors,
+            index % colors,
+        ]
+    }
+
+    /// The width and height of each tile
+    #[must_use]
+    pub fn size(&self) -> Float {
+        self.size
+    }
+
+    /// The number of corner colors
+    #[must_use]
+    pub fn colors(&self) -> u32 {
+        self.colors
+    }
+
+    /// The points within the tile with the given corner colors, as `[sw, se, nw, ne]`
+    ///
+    /// Points are relative to the tile's lower corner, and lie within `[0, size)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any color is not less than [`colors`][Self::colors].
+    #[must_use]
+    pub fn tile(&self, [sw, se, nw, ne]: [u32; 4]) -> &[Point<2>] {
+        let c = self.colors;
+        assert!(
+            sw < c && se < c && nw < c && ne < c,
+            "Corner color out of range"
+        );
+
+        &self.tiles[(((sw * c + se) * c + nw) * c + ne) as usize]
+    }
+
+    /// The color of the lattice vertex at the lower corner of tile `[x, y]`
+    ///
+    /// Colors are derived deterministically from the tile set's seed.
+    #[must_use]
+    pub fn corner_color(&self, [x, y]: [i64; 2]) -> u32 {
+        let hash = seed::derive(seed::derive(self.seed, x as u64), y as u64);
+
+        (hash % self.colors as u64) as u32
+    }
+
+    /// The points of the tile at lattice position `[x, y]`, in world coordinates
+    pub fn tile_at(&self, [x, y]: [i64; 2]) -> impl Iterator<Item = Point<2>> + '_ {
+        let corners = [
+            self.corner_color([x, y]),
+            self.corner_color([x + 1, y]),
+            self.corner_color([x, y + 1]),
+            self.corner_color([x + 1, y + 1]),
+        ];
+        let offset = [x as Float * self.size, y as Float * self.size];
+
+        translate(self.tile(corners), offset)
+    }
+
+    /// Cover the box `[min, max)` with tiles, returning all of the points within it
+    #[must_use]
+    pub fn points_in(&self, min: Point<2>, max: Point<2>) -> Vec<Point<2>> {
+        let first = [
+            (min[0] / self.size).floor() as i64,
+            (min[1] / self.size).floor() as i64,
+        ];
+        let last = [
+            (max[0] / self.size).ceil() as i64,
+            (max[1] / self.size).ceil() as i64,
+        ];
+
+        let mut points = Vec::new();
+        for y in first[1]..last[1] {
+            for x in first[0]..last[0] {
+                points.extend(self.tile_at([x, y]).filter(|&p| in_box(p, min, max)));
+            }
+        }
+
+        points
+    }
+}
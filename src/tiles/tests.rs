@@ -0,0 +1,45 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::Poisson2D;
+
+#[test]
+fn tiles_respect_radius_across_borders() {
+    let tiles = Poisson2D::new().with_seed(0xBADBEEF).generate_tiles(1.0, 2);
+    let points = tiles.points_in([-2.0, -2.0], [2.0, 2.0]);
+
+    assert!(!points.is_empty());
+    for (i, a) in points.iter().enumerate() {
+        for b in points[(i + 1)..].iter() {
+            let dist = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+            assert!(dist >= 0.1, "{:?} and {:?} are too close", a, b);
+        }
+    }
+}
+
+#[test]
+fn tiles_cover_the_plane() {
+    let tiles = Poisson2D::new().with_seed(0xBADBEEF).generate_tiles(1.0, 2);
+
+    // Every tile should be reasonably full, with no tile left empty
+    for index in 0..16 {
+        let corners = TileSet::corners_of(index, 2).map(|c| c as u32);
+        assert!(tiles.tile(corners).len() > 30);
+    }
+}
+
+#[test]
+fn tiles_are_deterministic() {
+    let a = Poisson2D::new().with_seed(0xBADBEEF).generate_tiles(1.0, 2);
+    let b = Poisson2D::new().with_seed(0xBADBEEF).generate_tiles(1.0, 2);
+
+    assert_eq!(
+        a.points_in([5.0, 5.0], [8.0, 8.0]),
+        b.points_in([5.0, 5.0], [8.0, 8.0])
+    );
+}
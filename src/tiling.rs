@@ -0,0 +1,218 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Generation of a bounded distribution tile by tile
+//!
+//! The reseeding box is divided into a grid of tiles, each of which is generated on its own around
+//! the points of any adjacent tiles generated before it. Tiles only depend on their neighbors, so
+//! they can be generated in parallel, or in a sweep that forgets tiles once they are behind it.
+
+use crate::{seed, Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Width of each tile, in multiples of the radius
+///
+/// This depends only on the distribution, never on how tiles are scheduled, so that the output is
+/// the same however they are generated.
+const TILE_RADII: Float = 16.0;
+
+/// Minimum number of attempts to find a starting point within each tile
+const TILE_RESEED_ATTEMPTS: u32 = 100;
+
+/// The space within a single tile: the distribution's own space, limited to the tile's box
+#[derive(Clone)]
+struct TileSpace<const N: usize, U> {
+    validate: Option<fn(Point<N>, &U) -> bool>,
+    user_data: U,
+    min: Point<N>,
+    max: Point<N>,
+}
+
+impl<const N: usize, U: Default> Default for TileSpace<N, U> {
+    fn default() -> Self {
+        Self {
+            validate: None,
+            user_data: U::default(),
+            min: [0.0; N],
+            max: [0.0; N],
+        }
+    }
+}
+
+impl<const N: usize, U> TileSpace<N, U> {
+    fn contains(point: Point<N>, tile: &TileSpace<N, U>) -> bool {
+        (0..N).all(|i| (tile.min[i]..tile.max[i]).contains(&point[i]))
+            && tile
+                .validate
+                .map_or(true, |validate| validate(point, &tile.user_data))
+    }
+}
+
+/// A distribution divided into tiles
+pub(crate) struct Tiling<'a, const N: usize, U> {
+    radius: Float,
+    samples: u32,
+    attempts: u32,
+    validate: fn(Point<N>, &U) -> bool,
+    user_data: &'a U,
+    /// Seed from which each tile's seed is derived
+    seed: u64,
+    /// Width of each tile along every axis
+    size: Float,
+    min: Point<N>,
+    max: Point<N>,
+}
+
+impl<'a, const N: usize, U> Tiling<'a, N, U>
+where
+    U: Default + Clone,
+{
+    /// Divide the distribution's reseeding box into tiles
+    pub(crate) fn new<R>(distribution: &'a Poisson<N, U, R>) -> Self
+    where
+        R: Rng + SeedableRng,
+    {
+        let [min, max] = distribution.reseed_bounds;
+
+        Self {
+            radius: distribution.radius,
+            samples: distribution.num_samples,
+            attempts: distribution.reseed_attempts.max(TILE_RESEED_ATTEMPTS),
+            validate: distribution.validate,
+            user_data: &distribution.validate_user_data,
+            seed: distribution.seed.unwrap_or_else(rand::random),
+            size: distribution.radius * TILE_RADII,
+            min,
+            max,
+        }
+    }
+
+    /// Every tile, in lexicographic order, such that the first axis varies the slowest
+    pub(crate) fn tiles(&self) -> Vec<[i64; N]> {
+        let mut tiles = vec![[0_i64; N]];
+        for axis in 0..N {
+            let count = ((self.max[axis] - self.min[axis]) / self.size)
+                .ceil()
+                .max(1.0) as i64;
+            tiles = tiles
+                .into_iter()
+                .flat_map(|tile| {
+                    (0..count).map(move |c| {
+                        let mut tile = tile;
+                        tile[axis] = c;
+                        tile
+                    })
+                })
+                .collect();
+        }
+
+        tiles
+    }
+
+    /// Generate the points within a tile, around the existing points of its neighbors
+    pub(crate) fn generate<R>(&self, coord: [i64; N], existing: &[Point<N>]) -> Vec<Point<N>>
+    where
+        R: Rng + SeedableRng,
+    {
+        let mut tile = TileSpace {
+            validate: Some(self.validate),
+            user_data: self.user_data.clone(),
+            min: self.min,
+            max: self.max,
+        };
+        let mut center = [0.0; N];
+        for i in 0..N {
+            tile.min[i] = self.min[i] + coord[i] as Float * self.size;
+            tile.max[i] = (tile.min[i] + self.size).min(self.max[i]);
+            center[i] = (tile.min[i] + tile.max[i]) / 2.0;
+        }
+        let (min, max) = (tile.min, tile.max);
+        let seed = coord
+            .iter()
+            .fold(self.seed, |seed, &c| seed::derive(seed, c as u64));
+
+        Poisson::<N, TileSpace<N, U>, R>::new()
+            .with_radius(self.radius)
+            .with_samples(self.samples)
+            .with_seed(seed)
+            .with_validate(TileSpace::contains, tile)
+            .with_first_point(center)
+            .with_reseeding(self.attempts, min, max)
+            .merge(existing, self.radius)
+            .split_off(existing.len())
+    }
+
+    /// Generate every tile in a sweep along the first axis, handing each tile's points to `sink`
+    ///
+    /// Tiles are generated in lexicographic order, each around all of its neighbors generated
+    /// before it. Once the sweep moves on to the next column of tiles, those two or more columns
+    /// behind can no longer neighbor any tile still to come, and are forgotten; only about two
+    /// columns of tiles are ever kept in memory.
+    ///
+    /// Returns the number of points generated, or the first error returned by `sink`.
+    pub(crate) fn sweep<R, F, E>(&self, mut sink: F) -> Result<usize, E>
+    where
+        R: Rng + SeedableRng,
+        F: FnMut(&[Point<N>]) -> Result<(), E>,
+    {
+        let mut generated: HashMap<[i64; N], Vec<Point<N>>> = HashMap::new();
+        let mut column = 0;
+        let mut count = 0;
+
+        for coord in self.tiles() {
+            let current = coord.first().copied().unwrap_or_default();
+            if current != column {
+                column = current;
+                generated.retain(|tile, _| tile[0] >= column - 1);
+            }
+
+            let existing: Vec<_> = neighbors(coord)
+                .filter_map(|neighbor| generated.get(&neighbor))
+                .flatten()
+                .copied()
+                .collect();
+            let points = self.generate::<R>(coord, &existing);
+
+            sink(&points)?;
+            count += points.len();
+            generated.insert(coord, points);
+        }
+
+        Ok(count)
+    }
+}
+
+/// The generation phase of a tile, determined by the parity of its coordinates
+///
+/// No two adjacent tiles share a phase, and tiles of the same phase are at least a whole tile
+/// apart, so every tile within a phase can be generated independently.
+#[cfg(feature = "rayon")]
+pub(crate) fn phase<const N: usize>(coord: [i64; N]) -> usize {
+    coord
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.rem_euclid(2) as usize) << i)
+        .sum()
+}
+
+/// All tiles adjacent to `coord`, including diagonally
+pub(crate) fn neighbors<const N: usize>(coord: [i64; N]) -> impl Iterator<Item = [i64; N]> {
+    (0..3_usize.pow(N as u32)).filter_map(move |n| {
+        let mut neighbor = coord;
+        let mut n = n;
+        for c in neighbor.iter_mut() {
+            *c += (n % 3) as i64 - 1;
+            n /= 3;
+        }
+
+        (neighbor != coord).then_some(neighbor)
+    })
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{stats, Poisson2D, Poisson3D};
+use std::convert::Infallible;
+
+#[test]
+fn tiles_are_lexicographic() {
+    let poisson = Poisson3D::new()
+        .with_radius(0.02)
+        .with_reseeding(0, [0.0; 3], [1.0, 0.5, 0.1]);
+    let tiles = Tiling::new(&poisson).tiles();
+
+    // 0.32 wide tiles
+    assert_eq!(tiles.len(), 4 * 2);
+    assert_eq!(tiles[0], [0, 0, 0]);
+    assert_eq!(tiles[1], [0, 1, 0]);
+    assert_eq!(tiles[2], [1, 0, 0]);
+    assert_eq!(tiles[7], [3, 1, 0]);
+}
+
+#[test]
+fn sweep_maintains_radius() {
+    let poisson = Poisson2D::new().with_radius(0.02).with_seed(0xBADBEEF);
+
+    let mut batches = Vec::new();
+    let count = poisson
+        .generate_out_of_core(|points| {
+            batches.push(points.to_vec());
+            Ok::<_, Infallible>(())
+        })
+        .unwrap();
+
+    // One batch per tile
+    assert_eq!(batches.len(), 16);
+    let points: Vec<_> = batches.into_iter().flatten().collect();
+    assert_eq!(points.len(), count);
+    assert!(stats::verify_min_distance(&points, 0.02));
+    assert!(points
+        .iter()
+        .all(|p| (0.0..1.0).contains(&p[0]) && (0.0..1.0).contains(&p[1])));
+
+    // About as dense as serial generation, and reproducible
+    let serial = poisson.generate().len() as Float;
+    assert!((count as Float / serial - 1.0).abs() < 0.1);
+    let mut again = Vec::new();
+    poisson
+        .generate_out_of_core(|points| {
+            again.extend_from_slice(points);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap();
+    assert_eq!(points, again);
+}
+
+#[test]
+fn sink_errors_stop_generation() {
+    let mut calls = 0;
+    let result = Poisson2D::new()
+        .with_radius(0.02)
+        .generate_out_of_core(|_| {
+            calls += 1;
+            if calls == 3 {
+                Err("full")
+            } else {
+                Ok(())
+            }
+        });
+
+    assert_eq!(result, Err("full"));
+    assert_eq!(calls, 3);
+}
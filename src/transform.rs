@@ -0,0 +1,44 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Affine transforms mapping generated points into world space
+
+use crate::{Float, Point};
+
+#[cfg(test)]
+mod tests;
+
+/// An affine transform, multiplying points by a matrix then translating them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Affine<const N: usize> {
+    /// The linear part of the transform, as rows
+    matrix: [[Float; N]; N],
+    /// Translation applied after the matrix
+    translation: Point<N>,
+}
+
+impl<const N: usize> Affine<N> {
+    /// Create a transform from its matrix, given as rows, and translation
+    pub(crate) fn new(matrix: [[Float; N]; N], translation: Point<N>) -> Self {
+        Self {
+            matrix,
+            translation,
+        }
+    }
+
+    /// Map a point through the transform
+    pub(crate) fn apply(&self, point: Point<N>) -> Point<N> {
+        std::array::from_fn(|i| {
+            self.matrix[i]
+                .iter()
+                .zip(point)
+                .map(|(m, p)| m * p)
+                .sum::<Float>()
+                + self.translation[i]
+        })
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+
+#[test]
+fn applies_matrix_then_translation() {
+    // Rotate a quarter turn counterclockwise, then shift right
+    let affine = Affine::new([[0.0, -1.0], [1.0, 0.0]], [10.0, 0.0]);
+
+    assert_eq!(affine.apply([1.0, 0.0]), [10.0, 1.0]);
+    assert_eq!(affine.apply([0.0, 2.0]), [8.0, 0.0]);
+}
+
+#[test]
+fn shears_and_scales() {
+    let affine = Affine::new(
+        [[2.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.5]],
+        [0.0; 3],
+    );
+
+    assert_eq!(affine.apply([1.0, 1.0, 4.0]), [3.0, 1.0, 2.0]);
+}
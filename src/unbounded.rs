@@ -0,0 +1,202 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::iter::random_point_around;
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{Float, Point, Poisson, Rand};
+use rand::{Rng, SeedableRng};
+use std::{cmp::Ordering, collections::BinaryHeap, iter::FusedIterator};
+
+#[cfg(test)]
+mod tests;
+
+/// A point ordered by its distance from the center, nearest first
+#[derive(Debug, Clone, Copy)]
+struct Nearest<const N: usize> {
+    distance: Float,
+    point: Point<N>,
+}
+
+impl<const N: usize> PartialEq for Nearest<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<const N: usize> Eq for Nearest<N> {}
+
+impl<const N: usize> PartialOrd for Nearest<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Nearest<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so that `BinaryHeap` gives us the nearest point first
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+/// An endless iterator over a Poisson disk distribution growing outward from a center
+///
+/// Points are returned in order of increasing distance from the center, so adapters like
+/// [`take_while`](Iterator::take_while) can be used to stop once generation leaves the area of
+/// interest. Only the growing front of the distribution is kept in memory, so memory use depends
+/// on the distance from the center rather than on the number of points returned.
+///
+/// See [`Poisson::iter_unbounded`].
+pub struct Unbounded<const N: usize, U = (), R = Rand>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// The distribution from which this iterator was built
+    distribution: Poisson<N, U, R>,
+    /// The RNG
+    rng: R,
+    /// The point the distribution grows outward from
+    center: Point<N>,
+    /// Samples that may still conflict with new ones
+    sampled: DefaultIndex<N>,
+    /// Samples in `sampled`, nearest first, so they can be evicted once they no longer matter
+    retained: BinaryHeap<Nearest<N>>,
+    /// Points around which we have yet to finish sampling, nearest first
+    active: BinaryHeap<Nearest<N>>,
+    /// Points generated but not yet returned, nearest first
+    ready: BinaryHeap<Nearest<N>>,
+    /// Distance from the center of the last point returned
+    released: Float,
+}
+
+impl<const N: usize, U, R> Unbounded<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    /// Create an endless iterator over the specified distribution
+    pub(crate) fn new(distribution: Poisson<N, U, R>, center: Point<N>) -> Self {
+        let rng = distribution.rng();
+
+        let radius = distribution.radius;
+        let mut unbounded = Self {
+            distribution,
+            rng,
+            center,
+            sampled: SpatialIndex::with_spacing(radius),
+            retained: BinaryHeap::new(),
+            active: BinaryHeap::new(),
+            ready: BinaryHeap::new(),
+            released: Float::NEG_INFINITY,
+        };
+        // With no bounds to leave a void against, the center itself is our first point
+        unbounded.add_point(center);
+
+        unbounded
+    }
+
+    /// Add a point to our pattern
+    fn add_point(&mut self, point: Point<N>) {
+        let nearest = Nearest {
+            distance: self.distance(point),
+            point,
+        };
+
+        self.sampled.insert(point, 0);
+        self.retained.push(nearest);
+        self.active.push(nearest);
+        self.ready.push(nearest);
+    }
+
+    /// Distance of `point` from the center
+    fn distance(&self, point: Point<N>) -> Float {
+        point
+            .iter()
+            .zip(self.center.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<Float>()
+            .sqrt()
+    }
+
+    /// Returns true if there is at least one other sample point within `radius` of this point
+    fn in_neighborhood(&self, point: Point<N>) -> bool {
+        self.sampled
+            .any_within(point, self.distribution.radius.powi(2))
+    }
+}
+
+impl<const N: usize, U, R> Iterator for Unbounded<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Point<N>> {
+        let radius = self.distribution.radius;
+        let reach = self.distribution.annulus[1] * radius;
+
+        loop {
+            // New points are generated within `reach` of an active point, so once a point is that
+            // much nearer than every active point, nothing nearer can follow it
+            let front = self.active.peek().map_or(Float::INFINITY, |a| a.distance);
+            if let Some(&ready) = self.ready.peek() {
+                if ready.distance <= front - reach {
+                    self.ready.pop();
+                    self.released = ready.distance;
+
+                    // Points further than `radius` inside of the released area can no longer
+                    // conflict with anything we generate, so stop tracking them
+                    while let Some(&old) = self.retained.peek() {
+                        if old.distance >= self.released - radius {
+                            break;
+                        }
+                        self.retained.pop();
+                        self.sampled.delete(old.point, 0);
+                    }
+
+                    return Some(self.distribution.transformed(ready.point));
+                }
+            }
+
+            let Some(&parent) = self.active.peek() else {
+                // Nothing left to grow from, so all that remains are the points already generated
+                let ready = self.ready.pop()?;
+                self.released = ready.distance;
+
+                return Some(self.distribution.transformed(ready.point));
+            };
+
+            let mut found = false;
+            for _ in 0..self.distribution.num_samples {
+                let radius = self.distribution.radius_at(parent.point);
+                let point =
+                    random_point_around(&self.distribution, radius, &mut self.rng, parent.point);
+
+                // Points nearer than one we've already returned would break our ordering; the
+                // space there has already been filled as well as it's going to be
+                if self.distance(point) >= self.released && !self.in_neighborhood(point) {
+                    self.add_point(point);
+                    found = true;
+                    break;
+                }
+            }
+
+            if !found {
+                // The nearest active point is always the one we sampled around
+                self.active.pop();
+            }
+        }
+    }
+}
+
+impl<const N: usize, U, R> FusedIterator for Unbounded<N, U, R>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+}
@@ -0,0 +1,47 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{Float, Poisson2D};
+
+fn distance(a: [Float; 2], b: [Float; 2]) -> Float {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+}
+
+#[test]
+fn unbounded_grows_outward_in_order() {
+    let center = [100.0, -100.0];
+    let points: Vec<_> = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .iter_unbounded(center)
+        .take_while(|&p| distance(p, center) < 2.0)
+        .collect();
+
+    assert_eq!(points[0], center);
+    // A disc of radius 2 should hold many points
+    assert!(points.len() > 500);
+    assert!(points
+        .windows(2)
+        .all(|w| distance(w[0], center) <= distance(w[1], center)));
+
+    for (i, a) in points.iter().enumerate() {
+        for b in points[(i + 1)..].iter() {
+            assert!(distance(*a, *b) >= 0.1);
+        }
+    }
+}
+
+#[test]
+fn unbounded_evicts_interior_points() {
+    let mut iter = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .iter_unbounded([0.0, 0.0]);
+    let taken = iter.by_ref().take(5000).count();
+
+    assert_eq!(taken, 5000);
+    // Only the shell around the front should still be retained
+    assert!(iter.retained.len() < 2500);
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Estimate the volume of space each point of a distribution represents
+
+use crate::spatial::{DefaultIndex, SpatialIndex};
+use crate::{seed, Float, Point, Poisson};
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of probes used to estimate how much of each neighborhood lies within the space
+const PROBES: usize = 64;
+
+/// Estimate the volume each point represents
+///
+/// Each point's weight is the volume of the part of its neighborhood, a ball of twice the radius,
+/// that lies within the space, shared equally among all points in that neighborhood. How much of
+/// the ball lies within the space is estimated by checking a fixed number of random probes
+/// against the distribution's validation function, so that points near the edges of the space are
+/// not over-weighted.
+pub(crate) fn weigh<const N: usize, U, R>(
+    distribution: &Poisson<N, U, R>,
+    points: &[Point<N>],
+) -> Vec<Float>
+where
+    U: Default + Clone,
+    R: Rng + SeedableRng,
+{
+    let radius = 2.0 * distribution.radius;
+    let volume = ball_volume(N, radius);
+
+    let mut tree = DefaultIndex::with_spacing(distribution.radius);
+    for point in points {
+        tree.insert(*point, 0);
+    }
+
+    let mut rng = R::seed_from_u64(seed::derive(
+        distribution.seed.unwrap_or_else(rand::random),
+        points.len() as u64,
+    ));
+
+    points
+        .iter()
+        .map(|point| {
+            let neighbors = tree.within_squared(*point, radius.powi(2)).len();
+
+            let inside = (0..PROBES)
+                .filter(|_| {
+                    let probe = random_point_in_ball(&mut rng, *point, radius);
+                    distribution.in_space(probe)
+                })
+                .count();
+
+            volume * inside as Float / PROBES as Float / neighbors.max(1) as Float
+        })
+        .collect()
+}
+
+/// The volume of an `n`-dimensional ball
+pub(crate) fn ball_volume(n: usize, radius: Float) -> Float {
+    // V(n) = V(n - 2) * 2π / n, starting from V(0) = 1 and V(1) = 2
+    let mut volume: Float = if n % 2 == 0 { 1.0 } else { 2.0 };
+    for d in (2 + n % 2..=n).step_by(2) {
+        volume *= 2.0 * std::f64::consts::PI as Float / d as Float;
+    }
+
+    volume * radius.powi(n as i32)
+}
+
+/// Generate a point uniformly distributed within `radius` of `center`
+fn random_point_in_ball<const N: usize, R: Rng>(
+    rng: &mut R,
+    center: Point<N>,
+    radius: Float,
+) -> Point<N> {
+    let mut vector: [Float; N] = [0.0; N];
+    for i in vector.iter_mut() {
+        *i = rng.sample(StandardNormal);
+    }
+    let mag = vector.iter().map(|&x| x.powi(2)).sum::<Float>().sqrt();
+
+    // Scaling the distance by the Nth root of a uniform sample spreads points evenly by volume
+    let dist = radius * rng.gen::<Float>().powf(1.0 / N as Float);
+
+    let mut point = center;
+    for i in 0..N {
+        point[i] += vector[i] * dist / mag;
+    }
+
+    point
+}
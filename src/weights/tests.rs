@@ -0,0 +1,47 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::*;
+use crate::{Poisson2D, Poisson3D};
+
+#[test]
+fn ball_volumes() {
+    let pi = std::f64::consts::PI as Float;
+
+    assert!((ball_volume(1, 1.0) - 2.0).abs() < 1e-6);
+    assert!((ball_volume(2, 1.0) - pi).abs() < 1e-6);
+    assert!((ball_volume(3, 2.0) - 4.0 / 3.0 * pi * 8.0).abs() < 1e-4);
+    assert!((ball_volume(4, 1.0) - pi * pi / 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn weights_cover_the_space() {
+    let total: Float = Poisson2D::new()
+        .with_seed(0xBADBEEF)
+        .generate_weighted()
+        .iter()
+        .map(|(_, weight)| weight)
+        .sum();
+    assert!(
+        (total - 1.0).abs() < 0.1,
+        "Total weight {} is not ~1",
+        total
+    );
+
+    let total: Float = Poisson3D::new()
+        .with_radius(0.2)
+        .with_seed(0xBADBEEF)
+        .generate_weighted()
+        .iter()
+        .map(|(_, weight)| weight)
+        .sum();
+    assert!(
+        (total - 1.0).abs() < 0.1,
+        "Total weight {} is not ~1",
+        total
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn fast_poisson(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fast-poisson"))
+        .args(args)
+        .output()
+        .expect("Failed to run fast-poisson")
+}
+
+#[test]
+fn csv_is_reproducible() {
+    let args = ["--seed", "42", "--dimensions", "3", "--radius", "0.2"];
+    let first = fast_poisson(&args);
+    let second = fast_poisson(&args);
+
+    assert!(first.status.success());
+    assert_eq!(first.stdout, second.stdout);
+
+    let output = String::from_utf8(first.stdout).unwrap();
+    assert!(output.starts_with("# seed: 42\n# radius: 0.2\n# dimensions: 3\nx,y,z\n"));
+}
+
+#[test]
+fn limit_caps_count() {
+    let output = fast_poisson(&["--seed", "42", "--limit", "5", "--format", "json"]);
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["points"].as_array().unwrap().len(), 5);
+}
+
+#[test]
+fn formats_check_dimensions() {
+    let output = fast_poisson(&["--format", "ply", "--dimensions", "3", "--limit", "3"]);
+    assert!(output.status.success());
+    assert!(output.stdout.starts_with(b"ply\n"));
+
+    let output = fast_poisson(&["--format", "svg", "--limit", "3"]);
+    assert!(output.status.success());
+    assert!(output.stdout.starts_with(b"<svg"));
+
+    let output = fast_poisson(&["--format", "svg", "--dimensions", "3"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("SVG output requires 2 dimensions"));
+}